@@ -0,0 +1,373 @@
+//! This module contains the algorithms on little endian slices of limbs
+//! that the big integer types are built on
+//!
+//! Unless stated otherwise inputs must be normalised (no most significant zero limbs)
+//! and outputs are normalised
+
+use std::cmp::Ordering;
+
+/// Limbs with fewer than this many limbs are multiplied with the schoolbook method
+pub(crate) const KARATSUBA_THRESHOLD: usize = 32;
+
+const LIMB_BITS: usize = 64;
+
+/// Removes any most significant zero limbs
+#[inline]
+pub(crate) fn normalise(v: &mut Vec<u64>) {
+	while let Some(&0) = v.last() {
+		v.pop();
+	}
+}
+
+/// Compares two magnitudes
+#[inline]
+pub(crate) fn cmp(a: &[u64], b: &[u64]) -> Ordering {
+	a.len()
+		.cmp(&b.len())
+		.then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+/// Returns a + b
+pub(crate) fn add(a: &[u64], b: &[u64]) -> Vec<u64> {
+	let (a, b) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+	let mut out = Vec::with_capacity(a.len() + 1);
+	let mut carry = false;
+	for (i, &x) in a.iter().enumerate() {
+		let (s, c1) = x.overflowing_add(*b.get(i).unwrap_or(&0));
+		let (s, c2) = s.overflowing_add(carry as u64);
+		out.push(s);
+		carry = c1 || c2;
+	}
+	if carry {
+		out.push(1);
+	}
+	out
+}
+
+/// Adds b into acc in place, acc must be long enough to hold the result
+fn add_assign(acc: &mut [u64], b: &[u64]) {
+	let mut carry = false;
+	for (i, x) in acc.iter_mut().enumerate() {
+		if i >= b.len() && !carry {
+			break;
+		}
+		let (s, c1) = x.overflowing_add(*b.get(i).unwrap_or(&0));
+		let (s, c2) = s.overflowing_add(carry as u64);
+		*x = s;
+		carry = c1 || c2;
+	}
+	debug_assert!(!carry);
+}
+
+/// Subtracts b from acc in place, acc must be at least b
+fn sub_assign(acc: &mut [u64], b: &[u64]) {
+	let mut borrow = false;
+	for (i, x) in acc.iter_mut().enumerate() {
+		if i >= b.len() && !borrow {
+			break;
+		}
+		let (s, b1) = x.overflowing_sub(*b.get(i).unwrap_or(&0));
+		let (s, b2) = s.overflowing_sub(borrow as u64);
+		*x = s;
+		borrow = b1 || b2;
+	}
+	debug_assert!(!borrow);
+}
+
+/// Returns a - b, a must be at least b
+pub(crate) fn sub(a: &[u64], b: &[u64]) -> Vec<u64> {
+	let mut out = a.to_vec();
+	sub_assign(&mut out, b);
+	normalise(&mut out);
+	out
+}
+
+/// Returns a * b, switching to Karatsuba multiplication for large inputs
+pub(crate) fn mul(a: &[u64], b: &[u64]) -> Vec<u64> {
+	if a.is_empty() || b.is_empty() {
+		Vec::new()
+	} else if a.len().min(b.len()) < KARATSUBA_THRESHOLD {
+		schoolbook(a, b)
+	} else {
+		karatsuba(a, b)
+	}
+}
+
+fn schoolbook(a: &[u64], b: &[u64]) -> Vec<u64> {
+	let mut out = vec![0u64; a.len() + b.len()];
+	for (i, &x) in a.iter().enumerate() {
+		let mut carry = 0u128;
+		for (j, &y) in b.iter().enumerate() {
+			let t = x as u128 * y as u128 + out[i + j] as u128 + carry;
+			out[i + j] = t as u64;
+			carry = t >> LIMB_BITS;
+		}
+		out[i + b.len()] = carry as u64;
+	}
+	normalise(&mut out);
+	out
+}
+
+/// Returns the slice without its most significant zero limbs
+#[inline]
+fn trim(a: &[u64]) -> &[u64] {
+	let len = a.iter().rposition(|&x| x != 0).map_or(0, |i| i + 1);
+	&a[..len]
+}
+
+fn karatsuba(a: &[u64], b: &[u64]) -> Vec<u64> {
+	let (a, b) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+	let m = a.len() / 2;
+	let mut out = vec![0u64; a.len() + b.len() + 1];
+
+	if b.len() <= m {
+		// Too unbalanced to split both, multiply b by each half of a
+		let (a0, a1) = (trim(&a[..m]), &a[m..]);
+		add_assign(&mut out, &mul(a0, b));
+		add_assign(&mut out[m..], &mul(a1, b));
+	} else {
+		// a*b = z2*B^2m + (z1 - z2 - z0)*B^m + z0
+		let (a0, a1) = (trim(&a[..m]), &a[m..]);
+		let (b0, b1) = (trim(&b[..m]), &b[m..]);
+		let z0 = mul(a0, b0);
+		let z2 = mul(a1, b1);
+		let mut z1 = mul(&add(a0, a1), &add(b0, b1));
+		sub_assign(&mut z1, &z0);
+		sub_assign(&mut z1, &z2);
+		normalise(&mut z1);
+
+		add_assign(&mut out, &z0);
+		add_assign(&mut out[m..], &z1);
+		add_assign(&mut out[2 * m..], &z2);
+	}
+	normalise(&mut out);
+	out
+}
+
+/// Returns a << bits
+pub(crate) fn shl(a: &[u64], bits: usize) -> Vec<u64> {
+	if a.is_empty() {
+		return Vec::new();
+	}
+	let limbs = bits / LIMB_BITS;
+	let bits = bits % LIMB_BITS;
+	let mut out = vec![0u64; limbs];
+	if bits == 0 {
+		out.extend_from_slice(a);
+	} else {
+		let mut carry = 0;
+		for &x in a {
+			out.push((x << bits) | carry);
+			carry = x >> (LIMB_BITS - bits);
+		}
+		if carry != 0 {
+			out.push(carry);
+		}
+	}
+	out
+}
+
+/// Returns a >> bits, discarding the bits shifted out
+pub(crate) fn shr(a: &[u64], bits: usize) -> Vec<u64> {
+	let limbs = bits / LIMB_BITS;
+	if limbs >= a.len() {
+		return Vec::new();
+	}
+	let bits = bits % LIMB_BITS;
+	let a = &a[limbs..];
+	let mut out: Vec<u64> = if bits == 0 {
+		a.to_vec()
+	} else {
+		(0..a.len())
+			.map(|i| (a[i] >> bits) | a.get(i + 1).map_or(0, |&x| x << (LIMB_BITS - bits)))
+			.collect()
+	};
+	normalise(&mut out);
+	out
+}
+
+/// Returns true if any of the lowest bits of a are set
+pub(crate) fn any_low_bits(a: &[u64], bits: usize) -> bool {
+	let limbs = bits / LIMB_BITS;
+	let bits = bits % LIMB_BITS;
+	a.iter().take(limbs).any(|&x| x != 0)
+		|| (bits != 0 && a.get(limbs).is_some_and(|&x| x & ((1 << bits) - 1) != 0))
+}
+
+/// Multiplies a by m and adds c in place
+pub(crate) fn mul_add_limb(a: &mut Vec<u64>, m: u64, c: u64) {
+	let mut carry = c as u128;
+	for x in a.iter_mut() {
+		let t = *x as u128 * m as u128 + carry;
+		*x = t as u64;
+		carry = t >> LIMB_BITS;
+	}
+	if carry != 0 {
+		a.push(carry as u64);
+	}
+}
+
+/// Divides a by the limb d in place, returning the remainder
+pub(crate) fn div_rem_limb(a: &mut Vec<u64>, d: u64) -> u64 {
+	let mut rem = 0u128;
+	for x in a.iter_mut().rev() {
+		let t = (rem << LIMB_BITS) | *x as u128;
+		*x = (t / d as u128) as u64;
+		rem = t % d as u128;
+	}
+	normalise(a);
+	rem as u64
+}
+
+/// Returns (a / b, a % b), b must be non zero
+///
+/// Uses Knuth's Algorithm D for multi limb divisors
+pub(crate) fn div_rem(a: &[u64], b: &[u64]) -> (Vec<u64>, Vec<u64>) {
+	assert!(!b.is_empty(), "attempt to divide by zero");
+	if cmp(a, b) == Ordering::Less {
+		return (Vec::new(), a.to_vec());
+	}
+	if b.len() == 1 {
+		let mut q = a.to_vec();
+		let r = div_rem_limb(&mut q, b[0]);
+		let r = if r == 0 { Vec::new() } else { vec![r] };
+		return (q, r);
+	}
+
+	// Normalise so the top bit of the divisor is set
+	let s = b[b.len() - 1].leading_zeros() as usize;
+	let v = shl(b, s);
+	let mut u = shl(a, s);
+	u.resize(a.len() + 1, 0);
+	let n = v.len();
+	let m = a.len() - n;
+	let mut q = vec![0u64; m + 1];
+
+	let base = 1u128 << LIMB_BITS;
+	for j in (0..=m).rev() {
+		let num = ((u[j + n] as u128) << LIMB_BITS) | u[j + n - 1] as u128;
+		let mut qhat = num / v[n - 1] as u128;
+		let mut rhat = num % v[n - 1] as u128;
+		while qhat >= base || qhat * v[n - 2] as u128 > ((rhat << LIMB_BITS) | u[j + n - 2] as u128)
+		{
+			qhat -= 1;
+			rhat += v[n - 1] as u128;
+			if rhat >= base {
+				break;
+			}
+		}
+
+		// Multiply and subtract
+		let mut borrow = 0i128;
+		let mut carry = 0u128;
+		for i in 0..n {
+			let p = qhat * v[i] as u128 + carry;
+			carry = p >> LIMB_BITS;
+			let t = u[i + j] as i128 - borrow - (p as u64) as i128;
+			u[i + j] = t as u64;
+			borrow = (t < 0) as i128;
+		}
+		let t = u[j + n] as i128 - borrow - carry as i128;
+		u[j + n] = t as u64;
+
+		// qhat was one too large, add the divisor back
+		if t < 0 {
+			qhat -= 1;
+			let mut c = 0u128;
+			for i in 0..n {
+				let s = u[i + j] as u128 + v[i] as u128 + c;
+				u[i + j] = s as u64;
+				c = s >> LIMB_BITS;
+			}
+			u[j + n] = u[j + n].wrapping_add(c as u64);
+		}
+		q[j] = qhat as u64;
+	}
+
+	normalise(&mut q);
+	u.truncate(n);
+	normalise(&mut u);
+	(q, shr(&u, s))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn from_u128(x: u128) -> Vec<u64> {
+		let mut v = vec![x as u64, (x >> 64) as u64];
+		normalise(&mut v);
+		v
+	}
+
+	#[test]
+	fn karatsuba_matches_schoolbook() {
+		// Deterministic pseudo random limbs
+		let mut state = 0x9e3779b97f4a7c15u64;
+		let mut next = || {
+			state ^= state << 13;
+			state ^= state >> 7;
+			state ^= state << 17;
+			state
+		};
+		for (la, lb) in [(32, 32), (40, 33), (100, 70), (200, 35), (150, 150)] {
+			let a: Vec<u64> = (0..la).map(|_| next()).collect();
+			let b: Vec<u64> = (0..lb).map(|_| next()).collect();
+			assert_eq!(karatsuba(&a, &b), schoolbook(&a, &b));
+		}
+
+		let a = vec![u64::MAX; 80];
+		let b = vec![u64::MAX; 64];
+		assert_eq!(karatsuba(&a, &b), schoolbook(&a, &b));
+	}
+
+	#[test]
+	fn div_rem_small() {
+		let cases = [
+			(u128::MAX, 3u128),
+			(u128::MAX, u64::MAX as u128 + 2),
+			(1 << 100, (1 << 64) + 1),
+			(12345678901234567890123456789, 98765432109876543210),
+			(5, 7),
+		];
+		for (a, b) in cases {
+			let (q, r) = div_rem(&from_u128(a), &from_u128(b));
+			assert_eq!(q, from_u128(a / b), "{} / {}", a, b);
+			assert_eq!(r, from_u128(a % b), "{} % {}", a, b);
+		}
+	}
+
+	#[test]
+	fn div_rem_large() {
+		let mut state = 0x2545f4914f6cdd1du64;
+		let mut next = || {
+			state ^= state << 13;
+			state ^= state >> 7;
+			state ^= state << 17;
+			state
+		};
+		for (la, lb) in [(10, 3), (20, 19), (50, 2), (64, 40)] {
+			let a: Vec<u64> = (0..la).map(|_| next()).collect();
+			let mut b: Vec<u64> = (0..lb).map(|_| next()).collect();
+			// Exercise the add back step with a divisor that has a small top limb
+			b[lb - 1] >>= 40;
+			let (q, r) = div_rem(&a, &b);
+			assert_eq!(cmp(&r, &b), Ordering::Less);
+			assert_eq!(add(&mul(&q, &b), &r), a);
+		}
+	}
+
+	#[test]
+	fn shifts() {
+		let a = from_u128(0x1234_5678_9abc_def0_1122_3344_5566_7788);
+		for bits in [0, 1, 17, 63, 64, 65, 130] {
+			assert_eq!(shr(&shl(&a, bits), bits), a);
+		}
+		assert_eq!(
+			shr(&a, 4),
+			from_u128(0x1234_5678_9abc_def0_1122_3344_5566_7788 >> 4)
+		);
+		assert!(any_low_bits(&a, 4));
+		assert!(!any_low_bits(&a, 3));
+	}
+}
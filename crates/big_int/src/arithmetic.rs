@@ -0,0 +1,477 @@
+//! This module contains implementations of mathematical operations on big integers
+
+use std::{
+	cmp::Ordering,
+	ops::{
+		Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Shl, ShlAssign, Shr,
+		ShrAssign, Sub, SubAssign,
+	},
+};
+
+use num_traits::{CheckedNeg, Num, One, Signed, Unsigned, Zero};
+
+use crate::{algorithms, BigInt, BigUint, ParseBigIntError};
+
+impl BigUint {
+	#[inline]
+	fn add_ref(&self, rhs: &Self) -> Self {
+		Self {
+			limbs: algorithms::add(&self.limbs, &rhs.limbs),
+		}
+	}
+
+	#[inline]
+	fn sub_ref(&self, rhs: &Self) -> Self {
+		if algorithms::cmp(&self.limbs, &rhs.limbs) == Ordering::Less {
+			panic!("attempt to subtract with overflow");
+		}
+		Self {
+			limbs: algorithms::sub(&self.limbs, &rhs.limbs),
+		}
+	}
+
+	#[inline]
+	fn mul_ref(&self, rhs: &Self) -> Self {
+		Self {
+			limbs: algorithms::mul(&self.limbs, &rhs.limbs),
+		}
+	}
+
+	#[inline]
+	fn div_ref(&self, rhs: &Self) -> Self {
+		self.div_rem(rhs).0
+	}
+
+	#[inline]
+	fn rem_ref(&self, rhs: &Self) -> Self {
+		self.div_rem(rhs).1
+	}
+}
+
+impl BigInt {
+	#[inline]
+	fn add_ref(&self, rhs: &Self) -> Self {
+		if self.negative == rhs.negative {
+			return Self::from_parts(self.negative, &self.magnitude + &rhs.magnitude);
+		}
+		match self.magnitude.cmp(&rhs.magnitude) {
+			Ordering::Less => Self::from_parts(rhs.negative, &rhs.magnitude - &self.magnitude),
+			_ => Self::from_parts(self.negative, &self.magnitude - &rhs.magnitude),
+		}
+	}
+
+	#[inline]
+	fn sub_ref(&self, rhs: &Self) -> Self {
+		self.add_ref(&-rhs)
+	}
+
+	#[inline]
+	fn mul_ref(&self, rhs: &Self) -> Self {
+		Self::from_parts(
+			self.negative != rhs.negative,
+			&self.magnitude * &rhs.magnitude,
+		)
+	}
+
+	#[inline]
+	fn div_ref(&self, rhs: &Self) -> Self {
+		self.div_rem(rhs).0
+	}
+
+	#[inline]
+	fn rem_ref(&self, rhs: &Self) -> Self {
+		self.div_rem(rhs).1
+	}
+}
+
+macro_rules! impl_op {
+	($lhs:ty,$rhs:ty,$out:ty,$func:ident,$bound:ident,$ref_func:ident) => {
+		impl $bound<$rhs> for $lhs {
+			type Output = $out;
+
+			#[inline]
+			fn $func(self, rhs: $rhs) -> Self::Output {
+				<$out>::$ref_func(&self, &rhs)
+			}
+		}
+	};
+}
+
+macro_rules! impl_op_vers {
+	($tt:ty,$func:ident,$bound:ident,$ref_func:ident) => {
+		impl_op!($tt, $tt, $tt, $func, $bound, $ref_func);
+		impl_op!($tt, &$tt, $tt, $func, $bound, $ref_func);
+		impl_op!(&$tt, &$tt, $tt, $func, $bound, $ref_func);
+		impl_op!(&$tt, $tt, $tt, $func, $bound, $ref_func);
+	};
+}
+
+macro_rules! impl_op_assign {
+	($tt:ty,$func:ident,$bound:ident,$ref_func:ident) => {
+		impl $bound<$tt> for $tt {
+			#[inline]
+			fn $func(&mut self, rhs: $tt) {
+				*self = self.$ref_func(&rhs);
+			}
+		}
+
+		impl $bound<&$tt> for $tt {
+			#[inline]
+			fn $func(&mut self, rhs: &$tt) {
+				*self = self.$ref_func(rhs);
+			}
+		}
+	};
+}
+
+macro_rules! impl_all_ops {
+	($tt:ty) => {
+		impl_op_vers!($tt, add, Add, add_ref);
+		impl_op_assign!($tt, add_assign, AddAssign, add_ref);
+
+		impl_op_vers!($tt, sub, Sub, sub_ref);
+		impl_op_assign!($tt, sub_assign, SubAssign, sub_ref);
+
+		impl_op_vers!($tt, mul, Mul, mul_ref);
+		impl_op_assign!($tt, mul_assign, MulAssign, mul_ref);
+
+		impl_op_vers!($tt, div, Div, div_ref);
+		impl_op_assign!($tt, div_assign, DivAssign, div_ref);
+
+		impl_op_vers!($tt, rem, Rem, rem_ref);
+		impl_op_assign!($tt, rem_assign, RemAssign, rem_ref);
+	};
+}
+
+impl_all_ops!(BigUint);
+impl_all_ops!(BigInt);
+
+// Neg Implmentation
+impl Neg for BigInt {
+	type Output = BigInt;
+
+	#[inline]
+	fn neg(self) -> Self::Output {
+		BigInt::from_parts(!self.negative, self.magnitude)
+	}
+}
+
+impl Neg for &BigInt {
+	type Output = BigInt;
+
+	#[inline]
+	fn neg(self) -> Self::Output {
+		BigInt::from_parts(!self.negative, self.magnitude.clone())
+	}
+}
+
+impl CheckedNeg for BigInt {
+	#[inline]
+	fn checked_neg(&self) -> Option<Self> {
+		Some(-self)
+	}
+}
+
+// Shift Implmentations
+impl Shl<usize> for &BigUint {
+	type Output = BigUint;
+
+	#[inline]
+	fn shl(self, rhs: usize) -> Self::Output {
+		BigUint {
+			limbs: algorithms::shl(&self.limbs, rhs),
+		}
+	}
+}
+
+impl Shr<usize> for &BigUint {
+	type Output = BigUint;
+
+	#[inline]
+	fn shr(self, rhs: usize) -> Self::Output {
+		BigUint {
+			limbs: algorithms::shr(&self.limbs, rhs),
+		}
+	}
+}
+
+impl Shl<usize> for &BigInt {
+	type Output = BigInt;
+
+	#[inline]
+	fn shl(self, rhs: usize) -> Self::Output {
+		BigInt::from_parts(self.negative, &self.magnitude << rhs)
+	}
+}
+
+impl Shr<usize> for &BigInt {
+	type Output = BigInt;
+
+	/// Arithmetic shift right, rounding towards negative infinity like the primitive integers
+	#[inline]
+	fn shr(self, rhs: usize) -> Self::Output {
+		let magnitude = &self.magnitude >> rhs;
+		if self.negative && algorithms::any_low_bits(&self.magnitude.limbs, rhs) {
+			BigInt::from_parts(true, magnitude + BigUint::one())
+		} else {
+			BigInt::from_parts(self.negative, magnitude)
+		}
+	}
+}
+
+macro_rules! impl_shift_owned {
+	($tt:ty,$func:ident,$bound:ident,$func_assign:ident,$bound_assign:ident) => {
+		impl $bound<usize> for $tt {
+			type Output = $tt;
+
+			#[inline]
+			fn $func(self, rhs: usize) -> Self::Output {
+				(&self).$func(rhs)
+			}
+		}
+
+		impl $bound_assign<usize> for $tt {
+			#[inline]
+			fn $func_assign(&mut self, rhs: usize) {
+				*self = (&*self).$func(rhs);
+			}
+		}
+	};
+}
+
+impl_shift_owned!(BigUint, shl, Shl, shl_assign, ShlAssign);
+impl_shift_owned!(BigUint, shr, Shr, shr_assign, ShrAssign);
+impl_shift_owned!(BigInt, shl, Shl, shl_assign, ShlAssign);
+impl_shift_owned!(BigInt, shr, Shr, shr_assign, ShrAssign);
+
+impl Zero for BigUint {
+	#[inline]
+	fn zero() -> Self {
+		Self::default()
+	}
+
+	#[inline]
+	fn is_zero(&self) -> bool {
+		self.limbs.is_empty()
+	}
+}
+
+impl One for BigUint {
+	#[inline]
+	fn one() -> Self {
+		Self { limbs: vec![1] }
+	}
+}
+
+impl Num for BigUint {
+	type FromStrRadixErr = ParseBigIntError;
+
+	#[inline]
+	fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+		BigUint::parse_radix(str, radix)
+	}
+}
+
+impl Unsigned for BigUint {}
+
+impl Zero for BigInt {
+	#[inline]
+	fn zero() -> Self {
+		Self::default()
+	}
+
+	#[inline]
+	fn is_zero(&self) -> bool {
+		self.magnitude.is_zero()
+	}
+}
+
+impl One for BigInt {
+	#[inline]
+	fn one() -> Self {
+		Self::from_parts(false, BigUint::one())
+	}
+}
+
+impl Num for BigInt {
+	type FromStrRadixErr = ParseBigIntError;
+
+	#[inline]
+	fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+		BigInt::parse_radix(str, radix)
+	}
+}
+
+impl Signed for BigInt {
+	#[inline]
+	fn abs(&self) -> Self {
+		Self::from_parts(false, self.magnitude.clone())
+	}
+
+	#[inline]
+	fn abs_sub(&self, other: &Self) -> Self {
+		if self <= other {
+			Self::zero()
+		} else {
+			self - other
+		}
+	}
+
+	#[inline]
+	fn signum(&self) -> Self {
+		if self.is_zero() {
+			Self::zero()
+		} else {
+			Self::from_parts(self.negative, BigUint::one())
+		}
+	}
+
+	#[inline]
+	fn is_positive(&self) -> bool {
+		!self.negative && !self.is_zero()
+	}
+
+	#[inline]
+	fn is_negative(&self) -> bool {
+		self.negative
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use num_traits::{One, Signed, ToPrimitive, Zero};
+
+	use crate::{BigInt, BigUint};
+
+	fn big(x: i128) -> BigInt {
+		BigInt::from(x)
+	}
+
+	const VALUES: [i128; 9] = [
+		0,
+		1,
+		-1,
+		7,
+		-13,
+		u64::MAX as i128,
+		-(u64::MAX as i128) - 5,
+		i64::MAX as i128 * 3,
+		i64::MIN as i128 * 5,
+	];
+
+	#[test]
+	fn add_sub() {
+		for a in VALUES {
+			for b in VALUES {
+				assert_eq!((big(a) + big(b)).to_i128(), Some(a + b), "{} + {}", a, b);
+				assert_eq!((&big(a) - &big(b)).to_i128(), Some(a - b), "{} - {}", a, b);
+			}
+		}
+	}
+
+	#[test]
+	fn add_sub_assign() {
+		let mut a = big(5);
+		a += big(-7);
+		assert_eq!(a, big(-2));
+		a -= &big(-7);
+		assert_eq!(a, big(5));
+	}
+
+	#[test]
+	fn mul() {
+		for a in VALUES {
+			for b in VALUES {
+				assert_eq!(
+					(big(a) * big(b)).to_i128(),
+					a.checked_mul(b),
+					"{} * {}",
+					a,
+					b
+				);
+			}
+		}
+		let mut a = big(3);
+		a *= big(-4);
+		assert_eq!(a, big(-12));
+	}
+
+	#[test]
+	fn div_rem() {
+		for a in VALUES {
+			for b in VALUES {
+				if b == 0 {
+					continue;
+				}
+				assert_eq!((big(a) / big(b)).to_i128(), Some(a / b), "{} / {}", a, b);
+				assert_eq!((big(a) % big(b)).to_i128(), Some(a % b), "{} % {}", a, b);
+			}
+		}
+		let mut a = big(-17);
+		a /= big(5);
+		assert_eq!(a, big(-3));
+		a %= big(2);
+		assert_eq!(a, big(-1));
+	}
+
+	#[test]
+	#[should_panic]
+	fn div_zero() {
+		let _ = big(1) / big(0);
+	}
+
+	#[test]
+	#[should_panic]
+	fn sub_overflow() {
+		let _ = BigUint::from(1u8) - BigUint::from(2u8);
+	}
+
+	#[test]
+	fn large_mul_div() {
+		// (3^500 * 7^300 + 12345) / 7^300 = 3^500 rem 12345
+		let a = BigUint::from(3u8).pow(500);
+		let b = BigUint::from(7u8).pow(300);
+		let c = &a * &b + BigUint::from(12345u16);
+		assert_eq!(c.div_rem(&b), (a.clone(), BigUint::from(12345u16)));
+
+		// Large enough to use Karatsuba, (x + 1)^2 = x^2 + 2x + 1
+		let x = BigUint::from(5u8).pow(3000);
+		let x1 = &x + BigUint::one();
+		assert_eq!(&x1 * &x1, &x * &x + (&x << 1) + BigUint::one(), "karatsuba");
+	}
+
+	#[test]
+	fn neg() {
+		assert_eq!(-big(5), big(-5));
+		assert_eq!(-&big(-5), big(5));
+		assert_eq!(-big(0), big(0));
+	}
+
+	#[test]
+	fn shifts() {
+		for a in VALUES {
+			for s in [0, 1, 3, 64, 65] {
+				assert_eq!((&big(a) >> s).to_i128(), Some(a >> s), "{} >> {}", a, s);
+			}
+			assert_eq!((big(a) << 3).to_i128(), Some(a << 3), "{} << 3", a);
+		}
+		let mut a = BigUint::one();
+		a <<= 200;
+		a >>= 199;
+		assert_eq!(a, BigUint::from(2u8));
+	}
+
+	#[test]
+	fn signed() {
+		assert_eq!(big(-5).abs(), big(5));
+		assert_eq!(big(-5).signum(), big(-1));
+		assert_eq!(big(0).signum(), big(0));
+		assert!(big(3).is_positive());
+		assert!(!big(0).is_positive());
+		assert!(!big(0).is_negative());
+		assert_eq!(big(3).abs_sub(&big(5)), big(0));
+		assert_eq!(big(5).abs_sub(&big(3)), big(2));
+		assert!(BigInt::zero().is_zero());
+		assert!(BigInt::one().is_one());
+	}
+}
@@ -0,0 +1,419 @@
+#![warn(missing_docs)]
+#![allow(dead_code)]
+
+//! Big Integers
+//!
+//! This module contains arbitrary precision integers, the unsigned [`BigUint`] and the
+//! sign-magnitude [`BigInt`], stored as little endian u64 limbs
+
+use std::fmt::Display;
+
+mod algorithms;
+pub mod arithmetic;
+pub mod ordering;
+pub mod radix;
+
+/// An arbitrary precision unsigned integer
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct BigUint {
+	// Little endian, never has a most significant zero limb so zero is empty
+	limbs: Vec<u64>,
+}
+
+/// An arbitrary precision signed integer stored as a sign and a magnitude
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct BigInt {
+	// Zero is never negative
+	negative: bool,
+	magnitude: BigUint,
+}
+
+/// The errors that can occur when parsing a big integer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseBigIntError {
+	/// The string had no digits
+	Empty,
+	/// The string contained a character that isn't a digit in the radix
+	InvalidDigit,
+	/// The radix wasn't in the range 2..=36
+	InvalidRadix,
+}
+
+impl Display for ParseBigIntError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			ParseBigIntError::Empty => write!(f, "cannot parse integer from empty string"),
+			ParseBigIntError::InvalidDigit => write!(f, "invalid digit found in string"),
+			ParseBigIntError::InvalidRadix => write!(f, "radix must be in the range 2..=36"),
+		}
+	}
+}
+
+impl std::error::Error for ParseBigIntError {}
+
+impl BigUint {
+	/// Creates a new BigUint from little endian u64 limbs
+	#[inline]
+	pub fn from_limbs(limbs: Vec<u64>) -> Self {
+		let mut limbs = limbs;
+		algorithms::normalise(&mut limbs);
+		Self { limbs }
+	}
+
+	/// Returns the little endian u64 limbs of the BigUint
+	#[inline]
+	pub fn limbs(&self) -> &[u64] {
+		&self.limbs
+	}
+
+	/// Returns the number of bits needed to represent the BigUint
+	#[inline]
+	pub fn bits(&self) -> usize {
+		match self.limbs.last() {
+			Some(top) => self.limbs.len() * 64 - top.leading_zeros() as usize,
+			None => 0,
+		}
+	}
+
+	/// Returns true if the BigUint is even
+	#[inline]
+	pub fn is_even(&self) -> bool {
+		self.limbs.first().copied().unwrap_or(0) & 1 == 0
+	}
+
+	/// Returns self raised to the power exp, using exponentiation by squaring
+	pub fn pow(&self, exp: u32) -> Self {
+		let mut base = self.clone();
+		let mut exp = exp;
+		let mut acc = BigUint::from(1u8);
+		while exp > 0 {
+			if exp & 1 == 1 {
+				acc = &acc * &base;
+			}
+			exp >>= 1;
+			if exp > 0 {
+				base = &base * &base;
+			}
+		}
+		acc
+	}
+
+	/// Returns the greatest common divisor of self and other
+	pub fn gcd(&self, other: &Self) -> Self {
+		let mut a = self.clone();
+		let mut b = other.clone();
+		while !b.limbs.is_empty() {
+			let r = &a % &b;
+			a = b;
+			b = r;
+		}
+		a
+	}
+
+	/// Returns (self / rhs, self % rhs)
+	///
+	/// # Panics
+	/// Panics if rhs is zero
+	#[inline]
+	pub fn div_rem(&self, rhs: &Self) -> (Self, Self) {
+		let (q, r) = algorithms::div_rem(&self.limbs, &rhs.limbs);
+		(Self { limbs: q }, Self { limbs: r })
+	}
+}
+
+impl BigInt {
+	/// Creates a new BigInt from a sign and a magnitude
+	#[inline]
+	pub fn from_parts(negative: bool, magnitude: BigUint) -> Self {
+		Self {
+			negative: negative && !magnitude.limbs.is_empty(),
+			magnitude,
+		}
+	}
+
+	/// Returns the (is negative, magnitude) pair
+	#[inline]
+	pub fn into_parts(self) -> (bool, BigUint) {
+		(self.negative, self.magnitude)
+	}
+
+	/// Returns a reference to the magnitude
+	#[inline]
+	pub fn magnitude(&self) -> &BigUint {
+		&self.magnitude
+	}
+
+	/// Returns the number of bits needed to represent the magnitude
+	#[inline]
+	pub fn bits(&self) -> usize {
+		self.magnitude.bits()
+	}
+
+	/// Returns true if the BigInt is even
+	#[inline]
+	pub fn is_even(&self) -> bool {
+		self.magnitude.is_even()
+	}
+
+	/// Returns self raised to the power exp
+	#[inline]
+	pub fn pow(&self, exp: u32) -> Self {
+		Self::from_parts(self.negative && exp & 1 == 1, self.magnitude.pow(exp))
+	}
+
+	/// Returns the greatest common divisor of self and other, always non-negative
+	#[inline]
+	pub fn gcd(&self, other: &Self) -> Self {
+		Self::from_parts(false, self.magnitude.gcd(&other.magnitude))
+	}
+
+	/// Returns (self / rhs, self % rhs), the quotient rounds towards zero like the primitive integers
+	///
+	/// # Panics
+	/// Panics if rhs is zero
+	#[inline]
+	pub fn div_rem(&self, rhs: &Self) -> (Self, Self) {
+		let (q, r) = self.magnitude.div_rem(&rhs.magnitude);
+		(
+			Self::from_parts(self.negative != rhs.negative, q),
+			Self::from_parts(self.negative, r),
+		)
+	}
+}
+
+macro_rules! impl_from_unsigned {
+	($tt:ty) => {
+		impl From<$tt> for BigUint {
+			#[inline]
+			fn from(value: $tt) -> Self {
+				let value = value as u128;
+				Self::from_limbs(vec![value as u64, (value >> 64) as u64])
+			}
+		}
+
+		impl From<$tt> for BigInt {
+			#[inline]
+			fn from(value: $tt) -> Self {
+				Self::from_parts(false, value.into())
+			}
+		}
+	};
+}
+
+macro_rules! impl_from_signed {
+	($tt:ty) => {
+		impl From<$tt> for BigInt {
+			#[inline]
+			fn from(value: $tt) -> Self {
+				Self::from_parts(value < 0, (value as i128).unsigned_abs().into())
+			}
+		}
+	};
+}
+
+impl_from_unsigned!(u8);
+impl_from_unsigned!(u16);
+impl_from_unsigned!(u32);
+impl_from_unsigned!(u64);
+impl_from_unsigned!(u128);
+impl_from_unsigned!(usize);
+
+impl_from_signed!(i8);
+impl_from_signed!(i16);
+impl_from_signed!(i32);
+impl_from_signed!(i64);
+impl_from_signed!(i128);
+impl_from_signed!(isize);
+
+impl From<BigUint> for BigInt {
+	#[inline]
+	fn from(value: BigUint) -> Self {
+		Self::from_parts(false, value)
+	}
+}
+
+impl TryFrom<BigInt> for BigUint {
+	type Error = BigInt;
+
+	/// Returns the magnitude, or the BigInt back if it's negative
+	#[inline]
+	fn try_from(value: BigInt) -> Result<Self, Self::Error> {
+		if value.negative {
+			Err(value)
+		} else {
+			Ok(value.magnitude)
+		}
+	}
+}
+
+impl num_traits::ToPrimitive for BigUint {
+	#[inline]
+	fn to_i64(&self) -> Option<i64> {
+		self.to_u64().and_then(|x| x.to_i64())
+	}
+
+	#[inline]
+	fn to_u64(&self) -> Option<u64> {
+		match self.limbs.len() {
+			0 => Some(0),
+			1 => Some(self.limbs[0]),
+			_ => None,
+		}
+	}
+
+	#[inline]
+	fn to_i128(&self) -> Option<i128> {
+		self.to_u128().and_then(|x| x.to_i128())
+	}
+
+	#[inline]
+	fn to_u128(&self) -> Option<u128> {
+		match self.limbs.len() {
+			0..=2 => Some(
+				self.limbs
+					.iter()
+					.rev()
+					.fold(0u128, |acc, &x| (acc << 64) | x as u128),
+			),
+			_ => None,
+		}
+	}
+
+	/// Rounds to the nearest f64, ties to even, and gives infinity past f64::MAX
+	fn to_f64(&self) -> Option<f64> {
+		let bits = self.bits();
+		if bits <= 64 {
+			return Some(self.to_u64()? as f64);
+		}
+		// The top 64 bits with everything below folded into a sticky bit, well under the 53
+		// bits an f64 keeps, so converting them is the only rounding
+		let shift = bits - 64;
+		let (limb, offset) = (shift / 64, shift % 64);
+		let mut top = self.limbs[limb] >> offset;
+		let mut sticky = self.limbs[..limb].iter().any(|&x| x != 0);
+		if offset > 0 {
+			top |= self.limbs[limb + 1] << (64 - offset);
+			sticky |= self.limbs[limb] << (64 - offset) != 0;
+		}
+		let top = (top | u64::from(sticky)) as f64;
+		// Scaling by a power of two is exact, and past 2^1024 it is infinite either way
+		Some(top * 2f64.powi(shift.min(2048) as i32))
+	}
+}
+
+impl num_traits::ToPrimitive for BigInt {
+	#[inline]
+	fn to_i64(&self) -> Option<i64> {
+		self.to_i128().and_then(|x| x.to_i64())
+	}
+
+	#[inline]
+	fn to_u64(&self) -> Option<u64> {
+		self.to_i128().and_then(|x| x.to_u64())
+	}
+
+	#[inline]
+	fn to_i128(&self) -> Option<i128> {
+		let m = self.magnitude.to_u128()?;
+		if self.negative {
+			0i128.checked_sub_unsigned(m)
+		} else {
+			m.to_i128()
+		}
+	}
+
+	#[inline]
+	fn to_u128(&self) -> Option<u128> {
+		if self.negative {
+			None
+		} else {
+			self.magnitude.to_u128()
+		}
+	}
+
+	#[inline]
+	fn to_f64(&self) -> Option<f64> {
+		let m = self.magnitude.to_f64()?;
+		Some(if self.negative { -m } else { m })
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use num_traits::ToPrimitive;
+
+	use super::*;
+
+	#[test]
+	fn from() {
+		assert_eq!(BigUint::from(0u8).limbs(), &[] as &[u64]);
+		assert_eq!(BigUint::from(u128::MAX).limbs(), &[u64::MAX, u64::MAX]);
+		assert_eq!(BigInt::from(i128::MIN).to_i128(), Some(i128::MIN));
+		assert_eq!(BigInt::from(-5).to_i64(), Some(-5));
+		assert_eq!(BigInt::from(-5).to_u64(), None);
+		assert_eq!(BigInt::from(u128::MAX).to_i128(), None);
+		assert_eq!(
+			BigInt::from_parts(true, BigUint::default()),
+			BigInt::default()
+		);
+	}
+
+	#[test]
+	fn bits() {
+		assert_eq!(BigUint::from(0u8).bits(), 0);
+		assert_eq!(BigUint::from(1u8).bits(), 1);
+		assert_eq!(BigUint::from(u64::MAX).bits(), 64);
+		assert_eq!(BigUint::from(1u128 << 64).bits(), 65);
+	}
+
+	#[test]
+	fn to_f64() {
+		// Just over half an ulp below the top 53 bits, which rounding each limb would lose
+		let x = BigUint::from_limbs(vec![(1 << 63) + 1, 1 << 52]);
+		assert_eq!(x.to_f64(), Some(2f64.powi(116) + 2f64.powi(64)));
+		// Exactly half an ulp ties to even
+		let x = BigUint::from_limbs(vec![1 << 63, 1 << 52]);
+		assert_eq!(x.to_f64(), Some(2f64.powi(116)));
+		let x = BigUint::from_limbs(vec![1 << 63, (1 << 52) + 1]);
+		assert_eq!(x.to_f64(), Some(2f64.powi(116) + 2f64.powi(65)));
+		// A sticky bit in a lower limb breaks the tie
+		let x = BigUint::from_limbs(vec![1, 1 << 63, 1 << 52]);
+		assert_eq!(x.to_f64(), Some(2f64.powi(180) + 2f64.powi(128)));
+
+		assert_eq!(BigUint::from(u64::MAX).to_f64(), Some(u64::MAX as f64));
+		assert_eq!(BigUint::from(2u8).pow(1023).to_f64(), Some(2f64.powi(1023)));
+		assert_eq!(
+			BigUint::from_limbs(vec![u64::MAX; 16]).to_f64(),
+			Some(f64::INFINITY)
+		);
+		// One below the midpoint between f64::MAX and 2^1024
+		let max = BigUint::from_limbs(vec![u64::MAX; 16]) - BigUint::from(2u8).pow(970);
+		assert_eq!(max.to_f64(), Some(f64::MAX));
+		assert_eq!(BigInt::from(-3).pow(40).to_f64(), Some(3f64.powi(40)));
+	}
+
+	#[test]
+	fn pow() {
+		assert_eq!(BigUint::from(3u8).pow(5), BigUint::from(243u8));
+		assert_eq!(BigUint::from(2u8).pow(127), BigUint::from(1u128 << 127));
+		assert_eq!(BigInt::from(-2).pow(3), BigInt::from(-8));
+		assert_eq!(BigInt::from(-2).pow(0), BigInt::from(1));
+	}
+
+	#[test]
+	fn gcd() {
+		let a = BigUint::from(2u8).pow(100) * BigUint::from(3u8).pow(40);
+		let b = BigUint::from(2u8).pow(70) * BigUint::from(5u8).pow(30);
+		assert_eq!(a.gcd(&b), BigUint::from(2u8).pow(70));
+		assert_eq!(BigInt::from(-12).gcd(&BigInt::from(18)), BigInt::from(6));
+		assert_eq!(BigInt::from(0).gcd(&BigInt::from(-7)), BigInt::from(7));
+	}
+
+	#[test]
+	fn div_rem() {
+		for (a, b) in [(7, 2), (-7, 2), (7, -2), (-7, -2)] {
+			let (q, r) = BigInt::from(a).div_rem(&BigInt::from(b));
+			assert_eq!((q.to_i64(), r.to_i64()), (Some(a / b), Some(a % b)));
+		}
+	}
+}
@@ -0,0 +1,66 @@
+//! This module containts implementations for ordering big integers
+
+use std::cmp::Ordering;
+
+use crate::{algorithms, BigInt, BigUint};
+
+impl PartialOrd for BigUint {
+	#[inline]
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+impl Ord for BigUint {
+	#[inline]
+	fn cmp(&self, other: &Self) -> Ordering {
+		algorithms::cmp(&self.limbs, &other.limbs)
+	}
+}
+
+impl PartialOrd for BigInt {
+	#[inline]
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+impl Ord for BigInt {
+	#[inline]
+	fn cmp(&self, other: &Self) -> Ordering {
+		match (self.negative, other.negative) {
+			(false, true) => Ordering::Greater,
+			(true, false) => Ordering::Less,
+			(false, false) => self.magnitude.cmp(&other.magnitude),
+			(true, true) => other.magnitude.cmp(&self.magnitude),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{BigInt, BigUint};
+
+	#[test]
+	fn ord() {
+		let values: [i128; 7] = [i128::MIN, -(1 << 64), -1, 0, 1, 1 << 64, i128::MAX];
+
+		for i in 0..values.len() {
+			for j in 0..values.len() {
+				let a = BigInt::from(values[i]);
+				let b = BigInt::from(values[j]);
+				assert_eq!(
+					a.cmp(&b),
+					i.cmp(&j),
+					"\n\ti: {} j: {}",
+					values[i],
+					values[j]
+				);
+				assert_eq!(a == b, i == j);
+			}
+		}
+
+		assert!(BigUint::from(u128::MAX) > BigUint::from(u64::MAX));
+		assert!(BigUint::from(0u8) < BigUint::from(1u8));
+	}
+}
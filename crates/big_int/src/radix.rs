@@ -0,0 +1,215 @@
+//! This module contains conversions between big integers and strings in any radix
+
+use std::{
+	fmt::{Binary, Display, LowerHex, Octal, UpperHex},
+	str::FromStr,
+};
+
+use crate::{algorithms, BigInt, BigUint, ParseBigIntError};
+
+/// Returns (radix^k, k) for the largest k such that radix^k fits in a u64
+#[inline]
+fn chunk(radix: u32) -> (u64, usize) {
+	let mut base = radix as u64;
+	let mut k = 1;
+	while let Some(next) = base.checked_mul(radix as u64) {
+		base = next;
+		k += 1;
+	}
+	(base, k)
+}
+
+impl BigUint {
+	/// Parses a BigUint from a string of digits in the given radix, with an optional leading '+'
+	pub(crate) fn parse_radix(s: &str, radix: u32) -> Result<Self, ParseBigIntError> {
+		if !(2..=36).contains(&radix) {
+			return Err(ParseBigIntError::InvalidRadix);
+		}
+		let s = s.strip_prefix('+').unwrap_or(s);
+		if s.is_empty() {
+			return Err(ParseBigIntError::Empty);
+		}
+
+		let digits = s
+			.chars()
+			.map(|c| c.to_digit(radix).ok_or(ParseBigIntError::InvalidDigit))
+			.collect::<Result<Vec<_>, _>>()?;
+
+		// Consume the digits k at a time so most of the work is done with u64s
+		let (base, k) = chunk(radix);
+		let mut limbs = Vec::new();
+		let first = digits.len() % k;
+		let (head, tail) = digits.split_at(first);
+		let to_limb = |ds: &[u32]| {
+			ds.iter()
+				.fold(0u64, |acc, &d| acc * radix as u64 + d as u64)
+		};
+		if !head.is_empty() {
+			algorithms::mul_add_limb(&mut limbs, 1, to_limb(head));
+		}
+		for c in tail.chunks(k) {
+			algorithms::mul_add_limb(&mut limbs, base, to_limb(c));
+		}
+		Ok(Self::from_limbs(limbs))
+	}
+
+	/// Returns the BigUint as a string of lowercase digits in the given radix
+	///
+	/// # Panics
+	/// Panics if radix is not in the range 2..=36
+	pub fn to_str_radix(&self, radix: u32) -> String {
+		assert!(
+			(2..=36).contains(&radix),
+			"radix must be in the range 2..=36"
+		);
+		if self.limbs.is_empty() {
+			return "0".to_string();
+		}
+
+		// Peel off k digits at a time, least significant first
+		let (base, k) = chunk(radix);
+		let mut limbs = self.limbs.clone();
+		let mut digits = Vec::new();
+		while !limbs.is_empty() {
+			let mut r = algorithms::div_rem_limb(&mut limbs, base);
+			for _ in 0..k {
+				digits.push(std::char::from_digit((r % radix as u64) as u32, radix).unwrap());
+				r /= radix as u64;
+				if limbs.is_empty() && r == 0 {
+					break;
+				}
+			}
+		}
+		digits.iter().rev().collect()
+	}
+}
+
+impl BigInt {
+	/// Parses a BigInt from a string of digits in the given radix, with an optional leading '+' or '-'
+	pub(crate) fn parse_radix(s: &str, radix: u32) -> Result<Self, ParseBigIntError> {
+		match s.strip_prefix('-') {
+			Some(rest) if !rest.starts_with('+') => {
+				Ok(Self::from_parts(true, BigUint::parse_radix(rest, radix)?))
+			}
+			Some(_) => Err(ParseBigIntError::InvalidDigit),
+			None => Ok(Self::from_parts(false, BigUint::parse_radix(s, radix)?)),
+		}
+	}
+
+	/// Returns the BigInt as a string of lowercase digits in the given radix
+	///
+	/// # Panics
+	/// Panics if radix is not in the range 2..=36
+	pub fn to_str_radix(&self, radix: u32) -> String {
+		let s = self.magnitude.to_str_radix(radix);
+		if self.negative {
+			format!("-{}", s)
+		} else {
+			s
+		}
+	}
+}
+
+impl FromStr for BigUint {
+	type Err = ParseBigIntError;
+
+	#[inline]
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		Self::parse_radix(s, 10)
+	}
+}
+
+impl FromStr for BigInt {
+	type Err = ParseBigIntError;
+
+	#[inline]
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		Self::parse_radix(s, 10)
+	}
+}
+
+// DISPLAY
+macro_rules! impl_display {
+	($bound:ident,$radix:expr,$prefix:expr,$upper:expr) => {
+		impl $bound for BigUint {
+			fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				let s = self.to_str_radix($radix);
+				let s = if $upper { s.to_uppercase() } else { s };
+				f.pad_integral(true, $prefix, &s)
+			}
+		}
+
+		impl $bound for BigInt {
+			fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				let s = self.magnitude.to_str_radix($radix);
+				let s = if $upper { s.to_uppercase() } else { s };
+				f.pad_integral(!self.negative, $prefix, &s)
+			}
+		}
+	};
+}
+
+impl_display!(Display, 10, "", false);
+impl_display!(Binary, 2, "0b", false);
+impl_display!(Octal, 8, "0o", false);
+impl_display!(LowerHex, 16, "0x", false);
+impl_display!(UpperHex, 16, "0x", true);
+
+#[cfg(test)]
+mod tests {
+	use num_traits::Num;
+
+	use crate::{BigInt, BigUint, ParseBigIntError};
+
+	#[test]
+	fn display() {
+		assert_eq!(BigInt::from(0).to_string(), "0");
+		assert_eq!(BigInt::from(-12345).to_string(), "-12345");
+		assert_eq!(BigUint::from(u128::MAX).to_string(), u128::MAX.to_string());
+		assert_eq!(BigInt::from(i128::MIN).to_string(), i128::MIN.to_string());
+		assert_eq!(format!("{:x}", BigInt::from(-255)), "-ff");
+		assert_eq!(format!("{:#X}", BigUint::from(255u8)), "0xFF");
+		assert_eq!(format!("{:b}", BigUint::from(5u8)), "101");
+		assert_eq!(format!("{:o}", BigUint::from(8u8)), "10");
+		assert_eq!(format!("{:>6}", BigInt::from(-42)), "   -42");
+	}
+
+	#[test]
+	fn from_str() {
+		assert_eq!("12345".parse::<BigInt>(), Ok(BigInt::from(12345)));
+		assert_eq!("-12345".parse::<BigInt>(), Ok(BigInt::from(-12345)));
+		assert_eq!("+7".parse::<BigInt>(), Ok(BigInt::from(7)));
+		assert_eq!("-0".parse::<BigInt>(), Ok(BigInt::from(0)));
+		assert_eq!(
+			u128::MAX.to_string().parse::<BigUint>(),
+			Ok(BigUint::from(u128::MAX))
+		);
+		assert_eq!("".parse::<BigInt>(), Err(ParseBigIntError::Empty));
+		assert_eq!("-".parse::<BigInt>(), Err(ParseBigIntError::Empty));
+		assert_eq!("-+1".parse::<BigInt>(), Err(ParseBigIntError::InvalidDigit));
+		assert_eq!("12a".parse::<BigInt>(), Err(ParseBigIntError::InvalidDigit));
+		assert_eq!("-1".parse::<BigUint>(), Err(ParseBigIntError::InvalidDigit));
+	}
+
+	#[test]
+	fn radix_round_trip() {
+		let x = BigInt::from(-3).pow(301);
+		for radix in 2..=36 {
+			let s = x.to_str_radix(radix);
+			assert_eq!(
+				BigInt::from_str_radix(&s, radix),
+				Ok(x.clone()),
+				"radix {}",
+				radix
+			);
+		}
+		assert_eq!(
+			BigInt::from_str_radix("-zz", 36),
+			Ok(BigInt::from(-(35 * 36 + 35)))
+		);
+		assert_eq!(
+			BigInt::from_str_radix("1", 37),
+			Err(ParseBigIntError::InvalidRadix)
+		);
+	}
+}
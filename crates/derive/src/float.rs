@@ -0,0 +1,247 @@
+//! This module contains the expansion of `#[derive(OrmlFloat)]`
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{parse_quote, DeriveInput, Result};
+
+use crate::newtype::Newtype;
+
+/// The traits with a single `fn(&self) -> Self`, as (module, trait, method)
+const BY_REF: &[(&str, &str, &str)] = &[
+	("trig", "Acos", "acos"),
+	("trig", "Acosh", "acosh"),
+	("trig", "Asin", "asin"),
+	("trig", "Asinh", "asinh"),
+	("trig", "Atan", "atan"),
+	("trig", "Atanh", "atanh"),
+	("trig", "Cos", "cos"),
+	("trig", "Cosh", "cosh"),
+	("trig", "Sin", "sin"),
+	("trig", "Sinh", "sinh"),
+	("trig", "Tan", "tan"),
+	("trig", "Tanh", "tanh"),
+	("", "SquareRoot", "sqrt"),
+];
+
+/// The traits with a single `fn(self) -> Self`, as (module, trait, method)
+const BY_VALUE: &[(&str, &str, &str)] = &[
+	("log", "Ln", "ln"),
+	("log", "Ln1P", "ln_1p"),
+	("log", "Log10", "log10"),
+	("log", "Log2", "log2"),
+	("exp", "Exp", "exp"),
+	("exp", "Exp2", "exp2"),
+	("exp", "ExpM1", "exp_m1"),
+	("abs", "Abs", "abs"),
+	("", "CubeRoot", "cbrt"),
+	("", "Ceil", "ceil"),
+	("", "Floor", "floor"),
+	("", "Fract", "fract"),
+	("", "Recip", "recip"),
+	("", "Round", "round"),
+	("", "Trunc", "trunc"),
+];
+
+/// The traits with a single `fn(self, Self) -> Self`, as (module, trait, method)
+const BINARY: &[(&str, &str, &str)] = &[
+	("log", "Log", "log"),
+	("abs", "AbsSub", "abs_sub"),
+	("", "DivEuclid", "div_euclid"),
+	("", "Hypot", "hypot"),
+	("", "Max", "max"),
+	("", "Min", "min"),
+	("", "RemEuclid", "rem_euclid"),
+];
+
+/// The bundle traits with no items, as (module, trait)
+const BUNDLES: &[(&str, &str)] = &[
+	("trig", "TrigFns"),
+	("log", "LogFns"),
+	("float", "FloatFns"),
+	("exp", "ExpFns"),
+	("abs", "AbsFns"),
+	("", "Ops"),
+	("", "Float"),
+];
+
+/// The `std::ops` operators that also have an assigning form, as (trait, method)
+const OPS: &[(&str, &str)] = &[
+	("Add", "add"),
+	("Div", "div"),
+	("Mul", "mul"),
+	("Rem", "rem"),
+	("Sub", "sub"),
+];
+
+pub(crate) fn expand(input: &DeriveInput) -> Result<TokenStream> {
+	let nt = Newtype::parse(input, parse_quote!(Float))?;
+	let inner = nt.inner;
+	let m = &nt.member;
+	let krate = &nt.krate;
+
+	// Traits at the crate root are found in `fns` apart from the bundles
+	let path = |module: &str, tr: &str| {
+		let tr = format_ident!("{}", tr);
+		match module {
+			"" if tr == "Ops" || tr == "Float" => quote!(#krate::#tr),
+			"" => quote!(#krate::fns::#tr),
+			module => {
+				let module = format_ident!("{}", module);
+				quote!(#krate::fns::#module::#tr)
+			}
+		}
+	};
+
+	let mut out = TokenStream::new();
+
+	for &(module, tr, f) in BY_REF {
+		let tr = path(module, tr);
+		let f = format_ident!("{}", f);
+		let value = nt.wrap(quote!(<#inner as #tr>::#f(&self.#m)));
+		out.extend(nt.impl_trait(tr, quote!(fn #f(&self) -> Self { #value })));
+	}
+
+	for &(module, tr, f) in BY_VALUE {
+		let tr = path(module, tr);
+		let f = format_ident!("{}", f);
+		let value = nt.wrap(quote!(<#inner as #tr>::#f(self.#m)));
+		out.extend(nt.impl_trait(tr, quote!(fn #f(self) -> Self { #value })));
+	}
+
+	for &(module, tr, f) in BINARY {
+		let tr = path(module, tr);
+		let f = format_ident!("{}", f);
+		let value = nt.wrap(quote!(<#inner as #tr>::#f(self.#m, other.#m)));
+		out.extend(nt.impl_trait(tr, quote!(fn #f(self, other: Self) -> Self { #value })));
+	}
+
+	for &(module, tr) in BUNDLES {
+		out.extend(nt.impl_trait(path(module, tr), TokenStream::new()));
+	}
+
+	let tr = path("trig", "Atan2");
+	let value = nt.wrap(quote!(<#inner as #tr>::atan2(&self.#m, &other.#m)));
+	out.extend(nt.impl_trait(tr, quote!(fn atan2(&self, other: &Self) -> Self { #value })));
+
+	let tr = path("trig", "SinCos");
+	let (sin, cos) = (nt.wrap(quote!(sin)), nt.wrap(quote!(cos)));
+	out.extend(nt.impl_trait(
+		tr.clone(),
+		quote! {
+			fn sin_cos(&self) -> (Self, Self) {
+				let (sin, cos) = <#inner as #tr>::sin_cos(&self.#m);
+				(#sin, #cos)
+			}
+		},
+	));
+
+	let tr = path("", "Clamp");
+	let value = nt.wrap(quote!(<#inner as #tr>::clamp(self.#m, min.#m, max.#m)));
+	out.extend(nt.impl_trait(
+		tr,
+		quote!(fn clamp(self, min: Self, max: Self) -> Self { #value }),
+	));
+
+	let tr = path("", "MulAdd");
+	let value = nt.wrap(quote!(<#inner as #tr>::mul_add(self.#m, a.#m, b.#m)));
+	out.extend(nt.impl_trait(
+		tr,
+		quote!(fn mul_add(self, a: Self, b: Self) -> Self { #value }),
+	));
+
+	let tr = path("", "Degrees");
+	let degrees = nt.wrap(quote!(<#inner as #tr>::to_degrees(self.#m)));
+	let radians = nt.wrap(quote!(<#inner as #tr>::to_radians(self.#m)));
+	out.extend(nt.impl_trait(
+		tr,
+		quote! {
+			fn to_degrees(self) -> Self { #degrees }
+			fn to_radians(self) -> Self { #radians }
+		},
+	));
+
+	let tr = path("pow", "Pow");
+	let pow = nt.wrap(quote!(<#inner as #tr>::pow(&self.#m, &n.#m)));
+	let powi = nt.wrap(quote!(<#inner as #tr>::powi(&self.#m, n)));
+	out.extend(nt.impl_trait(
+		tr,
+		quote! {
+			fn pow(&self, n: &Self) -> Self { #pow }
+			fn powi(&self, n: usize) -> Self { #powi }
+		},
+	));
+
+	let tr = path("", "Signed");
+	let signum = nt.wrap(quote!(<#inner as #tr>::signum(&self.#m)));
+	let copysign = nt.wrap(quote!(<#inner as #tr>::copysign(&self.#m, &sign.#m)));
+	out.extend(nt.impl_trait(
+		tr.clone(),
+		quote! {
+			fn is_sign_positive(&self) -> bool { <#inner as #tr>::is_sign_positive(&self.#m) }
+			fn is_sign_negative(&self) -> bool { <#inner as #tr>::is_sign_negative(&self.#m) }
+			fn signum(&self) -> Self { #signum }
+			fn copysign(&self, sign: &Self) -> Self { #copysign }
+		},
+	));
+
+	let tr = path("float", "Infinite");
+	out.extend(nt.impl_trait(
+		tr.clone(),
+		quote! {
+			fn is_finite(&self) -> bool { <#inner as #tr>::is_finite(&self.#m) }
+			fn is_infinite(&self) -> bool { <#inner as #tr>::is_infinite(&self.#m) }
+		},
+	));
+
+	let tr = path("float", "Nan");
+	out.extend(nt.impl_trait(
+		tr.clone(),
+		quote!(fn is_nan(&self) -> bool { <#inner as #tr>::is_nan(&self.#m) }),
+	));
+
+	let tr = path("float", "Normal");
+	out.extend(nt.impl_trait(
+		tr.clone(),
+		quote! {
+			fn is_normal(&self) -> bool { <#inner as #tr>::is_normal(&self.#m) }
+			fn is_subnormal(&self) -> bool { <#inner as #tr>::is_subnormal(&self.#m) }
+		},
+	));
+
+	let tr = path("float", "Classify");
+	out.extend(nt.impl_trait(
+		tr.clone(),
+		quote! {
+			fn classify(self) -> ::core::num::FpCategory { <#inner as #tr>::classify(self.#m) }
+		},
+	));
+
+	for &(tr, f) in OPS {
+		let (tr, f) = (format_ident!("{}", tr), format_ident!("{}", f));
+		let value = nt.wrap(quote!(::core::ops::#tr::#f(self.#m, rhs.#m)));
+		out.extend(nt.impl_trait(
+			quote!(::core::ops::#tr),
+			quote! {
+				type Output = Self;
+				fn #f(self, rhs: Self) -> Self { #value }
+			},
+		));
+
+		let (tr, f) = (format_ident!("{}Assign", tr), format_ident!("{}_assign", f));
+		out.extend(nt.impl_trait(
+			quote!(::core::ops::#tr),
+			quote!(fn #f(&mut self, rhs: Self) { ::core::ops::#tr::#f(&mut self.#m, rhs.#m) }),
+		));
+	}
+
+	let value = nt.wrap(quote!(-self.#m));
+	out.extend(nt.impl_trait(
+		quote!(::core::ops::Neg),
+		quote! {
+			type Output = Self;
+			fn neg(self) -> Self { #value }
+		},
+	));
+
+	Ok(out)
+}
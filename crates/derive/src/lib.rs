@@ -0,0 +1,27 @@
+#![warn(missing_docs)]
+
+//! Derive macros for ORML
+//!
+//! This crate contains derives that implement the `orml_traits` function traits on newtypes
+//! by forwarding every method to the wrapped field
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+mod float;
+mod newtype;
+
+/// Implements `orml_traits::Float`, every trait it bundles and the `Ops` arithmetic set for a
+/// single field newtype such as `struct Meters(f64)`
+///
+/// Every method forwards to the wrapped field, so the field type must implement `Float`
+///
+/// The traits are found at `::orml_traits` by default, use `#[orml(crate = "path")]` to
+/// point somewhere else, e.g. `#[orml(crate = "orml::traits")]`
+#[proc_macro_derive(OrmlFloat, attributes(orml))]
+pub fn derive_orml_float(input: TokenStream) -> TokenStream {
+	let input = parse_macro_input!(input as DeriveInput);
+	float::expand(&input)
+		.unwrap_or_else(syn::Error::into_compile_error)
+		.into()
+}
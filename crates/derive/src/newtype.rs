@@ -0,0 +1,100 @@
+//! This module contains the parsing of single field newtypes shared by the derives
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+	parse_quote, Data, DeriveInput, Error, Fields, Generics, Ident, LitStr, Member, Path, Result,
+	Type,
+};
+
+/// A single field struct that forwards its behaviour to the field
+pub(crate) struct Newtype<'a> {
+	name: &'a Ident,
+	/// The struct generics with the derive's bound on the field type added
+	generics: Generics,
+	/// The type of the wrapped field
+	pub inner: &'a Type,
+	/// The wrapped field, `0` for tuple structs
+	pub member: Member,
+	/// The path to `orml_traits`
+	pub krate: Path,
+}
+
+impl<'a> Newtype<'a> {
+	/// Parses a newtype from the derive input, bounding the field type by `bound` which is
+	/// relative to the `orml_traits` path
+	pub fn parse(input: &'a DeriveInput, bound: Path) -> Result<Self> {
+		let fields = match &input.data {
+			Data::Struct(data) => &data.fields,
+			_ => {
+				return Err(Error::new_spanned(
+					input,
+					"can only be derived for single field structs",
+				))
+			}
+		};
+		let field = match fields {
+			Fields::Named(f) if f.named.len() == 1 => &f.named[0],
+			Fields::Unnamed(f) if f.unnamed.len() == 1 => &f.unnamed[0],
+			_ => {
+				return Err(Error::new_spanned(
+					fields,
+					"can only be derived for structs with exactly one field",
+				))
+			}
+		};
+		let member = match &field.ident {
+			Some(ident) => Member::Named(ident.clone()),
+			None => Member::Unnamed(0.into()),
+		};
+
+		let krate = parse_crate(input)?;
+		let inner = &field.ty;
+		let mut generics = input.generics.clone();
+		generics
+			.make_where_clause()
+			.predicates
+			.push(parse_quote!(#inner: #krate::#bound));
+
+		Ok(Self {
+			name: &input.ident,
+			generics,
+			inner,
+			member,
+			krate,
+		})
+	}
+
+	/// Wraps a value of the field type back into the newtype
+	pub fn wrap(&self, value: TokenStream) -> TokenStream {
+		let member = &self.member;
+		quote!(Self { #member: #value })
+	}
+
+	/// Implements `tr` for the newtype with the given items
+	pub fn impl_trait(&self, tr: TokenStream, items: TokenStream) -> TokenStream {
+		let name = self.name;
+		let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+		quote! {
+			impl #impl_generics #tr for #name #ty_generics #where_clause {
+				#items
+			}
+		}
+	}
+}
+
+/// Reads the `orml_traits` path from `#[orml(crate = "...")]`, defaulting to `::orml_traits`
+fn parse_crate(input: &DeriveInput) -> Result<Path> {
+	let mut krate = parse_quote!(::orml_traits);
+	for attr in input.attrs.iter().filter(|a| a.path().is_ident("orml")) {
+		attr.parse_nested_meta(|meta| {
+			if meta.path.is_ident("crate") {
+				krate = meta.value()?.parse::<LitStr>()?.parse()?;
+				Ok(())
+			} else {
+				Err(meta.error("unsupported orml attribute"))
+			}
+		})?;
+	}
+	Ok(krate)
+}
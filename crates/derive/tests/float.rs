@@ -0,0 +1,89 @@
+use std::num::FpCategory;
+
+use orml_derive::OrmlFloat;
+use orml_traits::fns::{
+	abs::{Abs, AbsSub},
+	float::{Classify, Infinite, Nan},
+	log::Log,
+	pow::Pow,
+	Clamp, Degrees, Signed,
+};
+use orml_traits::Float;
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, OrmlFloat)]
+struct Meters(f64);
+
+#[derive(Debug, Clone, Copy, PartialEq, OrmlFloat)]
+struct Seconds {
+	value: f32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, OrmlFloat)]
+struct Unit<T>(T);
+
+mod renamed {
+	use orml_traits as traits;
+
+	#[derive(Debug, Clone, Copy, PartialEq, orml_derive::OrmlFloat)]
+	#[orml(crate = "traits")]
+	pub struct Radians(pub f64);
+}
+
+fn to_polar<T: Float + Copy>(x: T, y: T) -> (T, T) {
+	(x.hypot(y), y.atan2(&x))
+}
+
+fn from_polar<T: Float + Copy>(r: T, theta: T) -> (T, T) {
+	let (s, c) = theta.sin_cos();
+	(r * c, r * s)
+}
+
+#[test]
+fn generic_float() {
+	let (r, theta) = to_polar(Meters(3.0), Meters(-4.0));
+	assert_eq!(r, Meters(5.0));
+	assert_eq!(theta, Meters((-4.0f64).atan2(3.0)));
+	let (x, y) = from_polar(r, theta);
+	assert!(Abs::abs(x - Meters(3.0)) < Meters(1e-12));
+	assert!(Abs::abs(y + Meters(4.0)) < Meters(1e-12));
+
+	let (r, _) = to_polar(Seconds { value: 5.0 }, Seconds { value: 12.0 });
+	assert_eq!(r, Seconds { value: 13.0 });
+
+	let (r, _) = to_polar(Unit(Unit(8.0f64)), Unit(Unit(15.0)));
+	assert_eq!(r, Unit(Unit(17.0)));
+
+	let (r, _) = to_polar(renamed::Radians(0.0), renamed::Radians(2.0));
+	assert_eq!(r, renamed::Radians(2.0));
+}
+
+#[test]
+fn forwarding() {
+	let mut m = Meters(2.0);
+	m += Meters(1.0);
+	m *= Meters(4.0);
+	m -= Meters(2.0);
+	m /= Meters(5.0);
+	m %= Meters(1.5);
+	assert_eq!(m, Meters(0.5));
+	assert_eq!(-m, Meters(-0.5));
+	assert_eq!(Meters(7.0) % Meters(4.0), Meters(3.0));
+
+	assert_eq!(Pow::pow(&Meters(2.0), &Meters(10.0)), Meters(1024.0));
+	assert_eq!(Pow::powi(&Meters(3.0), 3), Meters(27.0));
+	assert_eq!(Log::log(Meters(8.0), Meters(2.0)), Meters(3.0));
+	assert_eq!(AbsSub::abs_sub(Meters(1.0), Meters(3.0)), Meters(0.0));
+	assert_eq!(
+		Clamp::clamp(Meters(5.0), Meters(0.0), Meters(1.0)),
+		Meters(1.0)
+	);
+	assert_eq!(Signed::copysign(&Meters(2.0), &Meters(-1.0)), Meters(-2.0));
+	assert!(Signed::is_sign_negative(&Meters(-0.0)));
+	assert_eq!(
+		Classify::classify(Seconds { value: 1e-40 }),
+		FpCategory::Subnormal
+	);
+	assert!(Meters(f64::NAN).is_nan());
+	assert!(!Meters(f64::INFINITY).is_finite());
+	assert_eq!(Meters(180.0).to_radians(), Meters(std::f64::consts::PI));
+}
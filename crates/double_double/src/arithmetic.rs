@@ -0,0 +1,295 @@
+//! This module contains implementations of mathematical operations on DoubleDoubles
+
+use std::ops::{
+	Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+
+use num_traits::{One, Zero};
+use orml_traits::fns::{SquareRoot, Trunc};
+
+use crate::{add2, div, mul, sqrt, sub2, DoubleDouble};
+
+impl From<&DoubleDouble> for (f64, f64) {
+	#[inline]
+	fn from(value: &DoubleDouble) -> Self {
+		(value.0, value.1)
+	}
+}
+
+/// Returns the remainder x - y trunc(x / y), which has the sign of x like f64
+fn rem(x: (f64, f64), y: (f64, f64)) -> (f64, f64) {
+	let (x, y) = (DoubleDouble::from(x), DoubleDouble::from(y));
+	(x - y * Trunc::trunc(x / y)).into()
+}
+
+impl_op_vers!(DoubleDouble, add, Add, add2);
+impl_op_vers!(DoubleDouble, sub, Sub, sub2);
+impl_op_vers!(DoubleDouble, mul, Mul, mul);
+impl_op_vers!(DoubleDouble, div, Div, div);
+impl_op_vers!(DoubleDouble, rem, Rem, rem);
+
+impl_op_assign_vers!(DoubleDouble, add_assign, AddAssign, add2);
+impl_op_assign_vers!(DoubleDouble, sub_assign, SubAssign, sub2);
+impl_op_assign_vers!(DoubleDouble, mul_assign, MulAssign, mul);
+impl_op_assign_vers!(DoubleDouble, div_assign, DivAssign, div);
+impl_op_assign_vers!(DoubleDouble, rem_assign, RemAssign, rem);
+
+impl Neg for DoubleDouble {
+	type Output = DoubleDouble;
+
+	#[inline]
+	fn neg(self) -> Self::Output {
+		DoubleDouble(-self.0, -self.1)
+	}
+}
+
+impl Neg for &DoubleDouble {
+	type Output = DoubleDouble;
+
+	#[inline]
+	fn neg(self) -> Self::Output {
+		-*self
+	}
+}
+
+impl Zero for DoubleDouble {
+	#[inline]
+	fn zero() -> Self {
+		DoubleDouble(0.0, 0.0)
+	}
+
+	#[inline]
+	fn is_zero(&self) -> bool {
+		self.0 == 0.0
+	}
+}
+
+impl One for DoubleDouble {
+	#[inline]
+	fn one() -> Self {
+		DoubleDouble(1.0, 0.0)
+	}
+}
+
+impl DoubleDouble {
+	/// Returns the absolute value
+	#[inline]
+	pub fn abs(self) -> Self {
+		if self.0 < 0.0 {
+			-self
+		} else {
+			self
+		}
+	}
+
+	/// Returns the square root, NaN for negative numbers
+	#[inline]
+	pub fn sqrt(self) -> Self {
+		sqrt(self.into()).into()
+	}
+
+	/// Returns self raised to the integer power n by repeated squaring
+	pub fn powi(self, n: i32) -> Self {
+		let result = self.powu(n.unsigned_abs() as usize);
+		if n < 0 {
+			DoubleDouble::one() / result
+		} else {
+			result
+		}
+	}
+
+	/// Returns self raised to the unsigned power n by repeated squaring
+	pub(crate) fn powu(self, n: usize) -> Self {
+		let mut result = DoubleDouble::one();
+		let mut base = self;
+		let mut e = n;
+		while e > 0 {
+			if e & 1 == 1 {
+				result *= base;
+			}
+			base *= base;
+			e >>= 1;
+		}
+		result
+	}
+}
+
+impl SquareRoot for DoubleDouble {
+	#[inline]
+	fn sqrt(&self) -> Self {
+		DoubleDouble::sqrt(*self)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	// The operators are tested on references too
+	#![allow(clippy::op_ref)]
+
+	use num_traits::{One, Zero};
+	use orml_quaternion::Quaternion;
+	use orml_traits::fns::abs::Abs;
+	use orml_traits::fns::float::Infinite;
+	use orml_vector::Vector;
+	use proptest::prelude::*;
+
+	use crate::test_util::{exact, within, BF};
+	use crate::DoubleDouble;
+
+	fn dd() -> impl Strategy<Value = DoubleDouble> {
+		(-1e6..1e6f64, -1.0..1.0f64).prop_map(|(hi, lo)| DoubleDouble::new(hi, hi * lo * 1e-17))
+	}
+
+	#[test]
+	fn operators() {
+		let a = DoubleDouble::from(3.0);
+		let b = DoubleDouble::from(0.5);
+		assert_eq!(a + b, DoubleDouble::from(3.5));
+		assert_eq!(&a - &b, DoubleDouble::from(2.5));
+		assert_eq!(a * &b, DoubleDouble::from(1.5));
+		assert_eq!(&a / b, DoubleDouble::from(6.0));
+		assert_eq!(-a, DoubleDouble::from(-3.0));
+
+		let mut c = a;
+		c += b;
+		c -= &a;
+		c *= DoubleDouble::from(4.0);
+		c /= &b;
+		assert_eq!(c, DoubleDouble::from(4.0));
+
+		assert!(DoubleDouble::zero().is_zero());
+		assert_eq!(DoubleDouble::one() * a, a);
+		assert_eq!(DoubleDouble::from(-2.0).abs(), DoubleDouble::from(2.0));
+		assert_eq!(DoubleDouble::from(2.0).powi(10), DoubleDouble::from(1024.0));
+		assert_eq!(DoubleDouble::from(2.0).powi(-2), DoubleDouble::from(0.25));
+		assert!(DoubleDouble::from(-1.0).sqrt().hi().is_nan());
+		assert!(DoubleDouble::from(f64::NAN).sqrt().hi().is_nan());
+	}
+
+	#[test]
+	fn non_finite() {
+		let one = DoubleDouble::one();
+		let inf = DoubleDouble::from(f64::INFINITY);
+		for x in [
+			one / DoubleDouble::zero(),
+			inf + one,
+			inf * DoubleDouble::from(2.0),
+			inf.sqrt(),
+		] {
+			assert!(x.is_infinite() && x.hi() > 0.0 && x.lo() == 0.0, "{x:?}");
+		}
+		let x = -one / DoubleDouble::zero();
+		assert!(x.is_infinite() && x.hi() < 0.0 && x.lo() == 0.0);
+		let x = DoubleDouble::from(1e300) * DoubleDouble::from(1e10);
+		assert!(x.is_infinite() && x.lo() == 0.0);
+		let x = DoubleDouble::from(-1e300) - DoubleDouble::from(f64::MAX);
+		assert!(x.is_infinite() && x.hi() < 0.0 && x.lo() == 0.0);
+
+		let x = DoubleDouble::from(2.0) / inf;
+		assert_eq!((x.hi(), x.lo()), (0.0, 0.0));
+
+		// The split of f64::MAX rounds up to infinity unless it is scaled
+		let max = DoubleDouble::from(f64::MAX);
+		assert_eq!(
+			max * DoubleDouble::from(0.5),
+			DoubleDouble::from(f64::MAX / 2.0)
+		);
+		assert_eq!(
+			max / DoubleDouble::from(2.0),
+			DoubleDouble::from(f64::MAX / 2.0)
+		);
+		let third = max / DoubleDouble::from(3.0);
+		assert!(within(
+			third,
+			BF::from(f64::MAX) / BF::from(3.0),
+			BF::from(f64::MAX),
+			104
+		));
+		assert_eq!(third * DoubleDouble::from(3.0), max);
+	}
+
+	#[test]
+	fn ordering() {
+		let one = DoubleDouble::one();
+		let above = DoubleDouble::new(1.0, 1e-20);
+		let below = DoubleDouble::new(1.0, -1e-20);
+		assert!(below < one && one < above);
+		assert!(DoubleDouble::from(-2.0) < below);
+		assert_eq!(above, DoubleDouble::new(1.0, 1e-20));
+		assert_ne!(above, one);
+		// The high parts are the same f64
+		assert_eq!(f64::from(above), f64::from(below));
+	}
+
+	#[test]
+	fn third() {
+		// 1/3 to 106 bits, which no f64 can hold
+		let third = DoubleDouble::one() / DoubleDouble::from(3.0);
+		assert!(within(
+			third,
+			BF::from(1.0) / BF::from(3.0),
+			BF::from(1.0),
+			105
+		));
+		assert!(third.lo() != 0.0);
+		let one = third * DoubleDouble::from(3.0);
+		assert!((one - DoubleDouble::one()).abs() < DoubleDouble::from(1e-31));
+	}
+
+	#[test]
+	fn mul_cross_terms() {
+		// Both low parts matter, so dropping either cross term shows up
+		let a = DoubleDouble::new(1.0, 2f64.powi(-60));
+		let b = DoubleDouble::new(3.0, -(2f64.powi(-58)));
+		let expected = exact(a) * exact(b);
+		assert!(within(a * b, expected.clone(), expected.clone(), 104));
+		assert!(within(b * a, expected.clone(), expected, 104));
+	}
+
+	#[test]
+	fn generic() {
+		let v = Vector::from_array([3.0, 4.0, 12.0].map(DoubleDouble::from));
+		assert_eq!(
+			Vector::<DoubleDouble, 3>::length(&v),
+			DoubleDouble::from(13.0)
+		);
+
+		let third = DoubleDouble::one() / DoubleDouble::from(3.0);
+		let q = Quaternion {
+			x: third,
+			y: third,
+			z: third,
+			w: DoubleDouble::zero(),
+		};
+		let n = q.normalise();
+		let one = Quaternion::<DoubleDouble>::length(&n);
+		assert!((one - DoubleDouble::one()).abs() < DoubleDouble::from(1e-31));
+	}
+
+	proptest! {
+		#[test]
+		fn add_sub(a in dd(), b in dd()) {
+			let (ea, eb) = (exact(a), exact(b));
+			let scale = ea.clone().abs() + eb.clone().abs();
+			prop_assert!(within(a + b, ea.clone() + eb.clone(), scale.clone(), 104));
+			prop_assert!(within(a - b, ea - eb, scale, 104));
+		}
+
+		#[test]
+		fn mul_div(a in dd(), b in dd()) {
+			prop_assume!(b.hi() != 0.0);
+			let (ea, eb) = (exact(a), exact(b));
+			let product = ea.clone() * eb.clone();
+			prop_assert!(within(a * b, product.clone(), product, 103));
+			let quotient = ea / eb;
+			prop_assert!(within(a / b, quotient.clone(), quotient, 103));
+		}
+
+		#[test]
+		fn square_root(a in dd()) {
+			let a = a.abs();
+			let root = exact(a).sqrt();
+			prop_assert!(within(a.sqrt(), root.clone(), root, 103));
+		}
+	}
+}
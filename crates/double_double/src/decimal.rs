@@ -0,0 +1,366 @@
+//! This module contains conversions between DoubleDoubles and decimal strings
+
+use std::fmt::Display;
+use std::str::FromStr;
+
+use num_traits::Zero;
+
+use crate::quad::QuadDouble;
+use crate::{DoubleDouble, ParseDoubleDoubleError};
+
+/// The significant digits written by Display, about as many as a DoubleDouble holds
+const DIGITS: usize = 32;
+
+/// Parsed digits past this many can't change the value
+const MAX_PARSED_DIGITS: usize = 40;
+
+/// Returns x * 10^k, in steps small enough that the power never overflows
+///
+/// The scaling is done in quad-double, so it is exact to well past the precision of a
+/// DoubleDouble
+fn scale10(x: QuadDouble, k: i32) -> QuadDouble {
+	let ten = QuadDouble::from(10.0);
+	let mut x = x;
+	let mut k = k;
+	while k != 0 {
+		let step = k.clamp(-280, 280);
+		x = if step > 0 {
+			x * ten.powi(step)
+		} else {
+			x / ten.powi(-step)
+		};
+		k -= step;
+	}
+	x
+}
+
+/// Returns x * 10^k like [`scale10`], for x or the result up to f64::MAX
+///
+/// Quad-double products that round up past f64::MAX overflow before their error terms can bring
+/// them back, so large values are scaled by 2^-64 on the way and back up exactly afterwards
+fn scale10_near_max(x: QuadDouble, k: i32) -> QuadDouble {
+	let magnitude = f64::from(x).abs().log10();
+	if magnitude.max(magnitude + k as f64) > 290.0 {
+		scale10(x.ldexp(-64), k).ldexp(64)
+	} else {
+		scale10(x, k)
+	}
+}
+
+impl DoubleDouble {
+	/// Returns the first n significant decimal digits of a finite positive DoubleDouble,
+	/// rounded to nearest, and the power of ten of the first one
+	fn decimal_digits(self, n: usize) -> (Vec<u8>, i32) {
+		let mut e = self.0.log10().floor() as i32;
+		let mut x = DoubleDouble::from(scale10_near_max(QuadDouble::from(self), -e));
+		assert!(x.0.is_finite(), "scaled {self:?} to {x:?}");
+		// log10 of the high part can be off by one either way
+		if x.0 >= 10.0 {
+			x /= DoubleDouble::from(10.0);
+			e += 1;
+		} else if x.0 < 1.0 {
+			x *= DoubleDouble::from(10.0);
+			e -= 1;
+		}
+
+		// The digits are taken from the high part, so rounding can leave one out of [0, 9] that is
+		// fixed by carrying. Two spare digits cover a lost leading digit and the rounding
+		let mut digits = Vec::with_capacity(n + 2);
+		for _ in 0..n + 2 {
+			let d = x.0.floor();
+			digits.push(d as i32);
+			x = (x - DoubleDouble::from(d)) * DoubleDouble::from(10.0);
+		}
+		for i in (1..digits.len()).rev() {
+			if digits[i] < 0 {
+				digits[i] += 10;
+				digits[i - 1] -= 1;
+			} else if digits[i] > 9 {
+				digits[i] -= 10;
+				digits[i - 1] += 1;
+			}
+		}
+		if digits[0] == 0 {
+			digits.remove(0);
+			e -= 1;
+		}
+
+		if digits[n] >= 5 {
+			let mut i = n - 1;
+			digits[i] += 1;
+			while digits[i] == 10 && i > 0 {
+				digits[i] = 0;
+				i -= 1;
+				digits[i] += 1;
+			}
+			if digits[0] == 10 {
+				digits[0] = 1;
+				e += 1;
+			}
+		}
+		digits.truncate(n);
+		(digits.into_iter().map(|d| d as u8).collect(), e)
+	}
+}
+
+/// Writes the 32 significant digits of the DoubleDouble without trailing zeros, positionally
+/// for powers of ten from -5 up to 31 and in scientific notation otherwise
+impl Display for DoubleDouble {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		if !self.0.is_finite() || self.0 == 0.0 {
+			return Display::fmt(&self.0, f);
+		}
+		if self.0 < 0.0 {
+			write!(f, "-")?;
+		}
+		let (digits, e) = self.abs().decimal_digits(DIGITS);
+		let len = digits.iter().rposition(|&d| d != 0).map_or(1, |i| i + 1);
+		let digits: String = digits[..len].iter().map(|d| char::from(b'0' + d)).collect();
+
+		if (-5..DIGITS as i32).contains(&e) {
+			if e < 0 {
+				write!(f, "0.{}{digits}", "0".repeat((-e - 1) as usize))
+			} else {
+				let point = e as usize + 1;
+				if point >= len {
+					write!(f, "{digits}{}", "0".repeat(point - len))
+				} else {
+					write!(f, "{}.{}", &digits[..point], &digits[point..])
+				}
+			}
+		} else if len == 1 {
+			write!(f, "{digits}e{e}")
+		} else {
+			write!(f, "{}.{}e{e}", &digits[..1], &digits[1..])
+		}
+	}
+}
+
+/// Parses decimal literals like 12, -0.5, 1.25e-7 and 3E+10, as well as inf, infinity and NaN
+impl FromStr for DoubleDouble {
+	type Err = ParseDoubleDoubleError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		if s.is_empty() {
+			return Err(ParseDoubleDoubleError::Empty);
+		}
+		let (negative, rest) = match s.as_bytes()[0] {
+			b'-' => (true, &s[1..]),
+			b'+' => (false, &s[1..]),
+			_ => (false, s),
+		};
+		let sign = |x: DoubleDouble| if negative { -x } else { x };
+		if rest.eq_ignore_ascii_case("inf") || rest.eq_ignore_ascii_case("infinity") {
+			return Ok(sign(DoubleDouble::from(f64::INFINITY)));
+		}
+		if rest.eq_ignore_ascii_case("nan") {
+			return Ok(DoubleDouble::from(f64::NAN));
+		}
+
+		let (mantissa, exponent) = match rest.find(['e', 'E']) {
+			Some(i) => {
+				let exponent = rest[i + 1..]
+					.parse::<i32>()
+					.map_err(|_| ParseDoubleDoubleError::Invalid)?;
+				(&rest[..i], exponent)
+			}
+			None => (rest, 0),
+		};
+
+		// 40 digits fit in a QuadDouble, so the value is only rounded once, at the end
+		let ten = QuadDouble::from(10.0);
+		let mut x = QuadDouble::zero();
+		let mut shift = 0i32;
+		let mut significant = 0;
+		let mut seen_digit = false;
+		let mut seen_point = false;
+		for c in mantissa.chars() {
+			match c {
+				'.' if !seen_point => seen_point = true,
+				'0'..='9' => {
+					seen_digit = true;
+					let d = f64::from(c as u8 - b'0');
+					if significant < MAX_PARSED_DIGITS {
+						x = x * ten + QuadDouble::from(d);
+						if !x.is_zero() {
+							significant += 1;
+						}
+						if seen_point {
+							shift -= 1;
+						}
+					} else if !seen_point {
+						shift += 1;
+					}
+				}
+				_ => return Err(ParseDoubleDoubleError::Invalid),
+			}
+		}
+		if !seen_digit {
+			return Err(if rest.is_empty() {
+				ParseDoubleDoubleError::Empty
+			} else {
+				ParseDoubleDoubleError::Invalid
+			});
+		}
+		if x.is_zero() {
+			return Ok(sign(DoubleDouble::zero()));
+		}
+		let exponent = exponent.saturating_add(shift);
+		// Far outside the range of f64 the scaling would only produce infinity or zero slowly
+		if exponent > 400 {
+			return Ok(sign(DoubleDouble::from(f64::INFINITY)));
+		}
+		if exponent < -400 - significant as i32 {
+			return Ok(sign(DoubleDouble::zero()));
+		}
+		Ok(sign(DoubleDouble::from(scale10_near_max(x, exponent))))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use num_traits::One;
+	use orml_traits::fns::abs::Abs;
+
+	use crate::test_util::{exact, BF};
+	use crate::{DoubleDouble, ParseDoubleDoubleError};
+
+	#[test]
+	fn display() {
+		let pi = DoubleDouble::new(std::f64::consts::PI, 1.2246467991473532e-16);
+		assert_eq!(pi.to_string(), "3.1415926535897932384626433832795");
+		assert_eq!((-pi).to_string(), "-3.1415926535897932384626433832795");
+		let third = DoubleDouble::one() / DoubleDouble::from(3.0);
+		assert_eq!(third.to_string(), "0.33333333333333333333333333333333");
+		assert_eq!(DoubleDouble::from(1.5).to_string(), "1.5");
+		assert_eq!(
+			DoubleDouble::from(1e20).to_string(),
+			"100000000000000000000"
+		);
+		assert_eq!(
+			"0.001".parse::<DoubleDouble>().unwrap().to_string(),
+			"0.001"
+		);
+		assert_eq!("1e40".parse::<DoubleDouble>().unwrap().to_string(), "1e40");
+		// The f64 nearest to 0.1 is 0.1000000000000000055511151231257827...
+		assert_eq!(
+			DoubleDouble::from(0.1).to_string(),
+			"0.10000000000000000555111512312578"
+		);
+		assert_eq!(
+			(third * "1e-290".parse::<DoubleDouble>().unwrap()).to_string(),
+			"3.3333333333333333333333333333333e-291"
+		);
+		assert_eq!(
+			DoubleDouble::from(f64::MAX).to_string(),
+			"1.797693134862315708145274237317e308"
+		);
+		assert_eq!(
+			DoubleDouble::from(-f64::MAX).to_string(),
+			"-1.797693134862315708145274237317e308"
+		);
+		assert_eq!(DoubleDouble::from(0.0).to_string(), "0");
+		assert_eq!(DoubleDouble::from(f64::NAN).to_string(), "NaN");
+		assert_eq!(DoubleDouble::from(f64::NEG_INFINITY).to_string(), "-inf");
+	}
+
+	#[test]
+	fn parse() {
+		let pi: DoubleDouble = "3.14159265358979323846264338327950288".parse().unwrap();
+		assert_eq!(
+			pi,
+			DoubleDouble::new(std::f64::consts::PI, 1.2246467991473532e-16)
+		);
+		assert_eq!("-1.5".parse(), Ok(DoubleDouble::from(-1.5)));
+		assert_eq!("+25e-1".parse(), Ok(DoubleDouble::from(2.5)));
+		assert_eq!("1E3".parse(), Ok(DoubleDouble::from(1000.0)));
+		assert_eq!(".5".parse(), Ok(DoubleDouble::from(0.5)));
+		assert_eq!("7.".parse(), Ok(DoubleDouble::from(7.0)));
+		assert_eq!("0.000".parse(), Ok(DoubleDouble::from(0.0)));
+		assert_eq!("1e999".parse(), Ok(DoubleDouble::from(f64::INFINITY)));
+		assert_eq!("1.8e308".parse(), Ok(DoubleDouble::from(f64::INFINITY)));
+		let max = DoubleDouble::from(f64::MAX);
+		assert_eq!(
+			max.to_string().parse::<DoubleDouble>().unwrap().hi(),
+			f64::MAX
+		);
+		assert_eq!("-inf".parse(), Ok(DoubleDouble::from(f64::NEG_INFINITY)));
+		assert!("NaN".parse::<DoubleDouble>().unwrap().hi().is_nan());
+
+		assert_eq!(
+			"".parse::<DoubleDouble>(),
+			Err(ParseDoubleDoubleError::Empty)
+		);
+		assert_eq!(
+			"-".parse::<DoubleDouble>(),
+			Err(ParseDoubleDoubleError::Empty)
+		);
+		for s in [".", "1.2.3", "1e", "e5", "12a", " 1", "--1"] {
+			assert_eq!(
+				s.parse::<DoubleDouble>(),
+				Err(ParseDoubleDoubleError::Invalid),
+				"{s}"
+			);
+		}
+	}
+
+	/// Parses a decimal literal exactly enough to check against
+	fn big(s: &str) -> BF {
+		let (mantissa, exponent) = s.split_once('e').unwrap_or((s, "0"));
+		let (negative, mantissa) = match mantissa.strip_prefix('-') {
+			Some(m) => (true, m),
+			None => (false, mantissa),
+		};
+		let mut exponent: i32 = exponent.parse().unwrap();
+		let mut x = BF::from(0.0);
+		let mut seen_point = false;
+		for c in mantissa.chars() {
+			if c == '.' {
+				seen_point = true;
+				continue;
+			}
+			x = x * BF::from(10.0) + BF::from(f64::from(c as u8 - b'0'));
+			if seen_point {
+				exponent -= 1;
+			}
+		}
+		let scale = BF::from(10.0).powi(exponent.unsigned_abs() as usize);
+		let x = if exponent < 0 { x / scale } else { x * scale };
+		if negative {
+			-x
+		} else {
+			x
+		}
+	}
+
+	#[test]
+	fn parse_accuracy() {
+		let cases = [
+			"0.1",
+			"2.718281828459045235360287471352662497757",
+			"-6.02214076e23",
+			"1.602176634e-19",
+			"123456789012345678901234567890.123456789",
+			"9.999999999999999999999999999999999e299",
+			"1.7976931348623157e308",
+			"1.797693134862315708145274237317043567980705675258449965989e308",
+		];
+		for s in cases {
+			let x: DoubleDouble = s.parse().unwrap();
+			let expected = big(s);
+			let err = (exact(x) - expected.clone()).abs();
+			assert!(err <= expected.abs() * BF::from(2f64.powi(-103)), "{s}");
+		}
+	}
+
+	#[test]
+	fn round_trip() {
+		let mut x = DoubleDouble::one() / DoubleDouble::from(7.0);
+		for _ in 0..40 {
+			let back: DoubleDouble = x.to_string().parse().unwrap();
+			let err = (exact(back) - exact(x)).abs();
+			assert!(err <= exact(x).abs() * BF::from(1e-31), "{x}");
+			x *= DoubleDouble::from(-123.456);
+		}
+	}
+}
@@ -0,0 +1,457 @@
+//! This module contains the implementation of [`Float`] for DoubleDouble
+//!
+//! Classification and signs are those of the high part, and the min/max family follows f64, so
+//! a NaN argument is ignored where f64 would ignore it
+
+use std::num::FpCategory;
+
+use num_traits::{One, Zero};
+use orml_traits::fns::pow::Pow;
+use orml_traits::fns::trig::*;
+use orml_traits::fns::{
+	abs::*, exp::*, float::*, log::*, Ceil, Clamp, CubeRoot, Degrees, DivEuclid, Floor, Fract,
+	Hypot, Max, Min, MulAdd, Recip, RemEuclid, Round, Signed, Trunc,
+};
+use orml_traits::{Float, Ops};
+
+use crate::{quick_two_sum, DoubleDouble};
+
+macro_rules! impl_unary {
+	($bound:ident,$fn:ident) => {
+		impl $bound for DoubleDouble {
+			#[inline]
+			fn $fn(self) -> Self {
+				DoubleDouble::$fn(self)
+			}
+		}
+	};
+}
+
+macro_rules! impl_trig {
+	($bound:ident,$fn:ident) => {
+		impl $bound for DoubleDouble {
+			#[inline]
+			fn $fn(&self) -> Self {
+				DoubleDouble::$fn(*self)
+			}
+		}
+	};
+}
+
+impl_unary!(Exp, exp);
+impl_unary!(Exp2, exp2);
+impl_unary!(ExpM1, exp_m1);
+impl_unary!(Ln, ln);
+impl_unary!(Ln1P, ln_1p);
+impl_unary!(Log10, log10);
+impl_unary!(Log2, log2);
+impl_unary!(Abs, abs);
+
+orml_traits::impl_all_trig!(impl_trig);
+
+impl Atan2 for DoubleDouble {
+	#[inline]
+	fn atan2(&self, other: &Self) -> Self {
+		DoubleDouble::atan2(*self, *other)
+	}
+}
+
+impl SinCos for DoubleDouble {
+	#[inline]
+	fn sin_cos(&self) -> (Self, Self) {
+		DoubleDouble::sin_cos(*self)
+	}
+}
+
+impl TrigFns for DoubleDouble {}
+
+impl Log for DoubleDouble {
+	#[inline]
+	fn log(self, base: Self) -> Self {
+		self.ln() / base.ln()
+	}
+}
+
+impl LogFns for DoubleDouble {}
+
+impl ExpFns for DoubleDouble {}
+
+impl Pow for DoubleDouble {
+	#[inline]
+	fn pow(&self, n: &Self) -> Self {
+		DoubleDouble::pow(*self, *n)
+	}
+
+	#[inline]
+	fn powi(&self, n: usize) -> Self {
+		self.powu(n)
+	}
+}
+
+impl AbsSub for DoubleDouble {
+	/// The positive difference max(self - other, 0)
+	#[inline]
+	fn abs_sub(self, other: Self) -> Self {
+		if self <= other {
+			Self::zero()
+		} else {
+			self - other
+		}
+	}
+}
+
+impl AbsFns for DoubleDouble {}
+
+impl Infinite for DoubleDouble {
+	#[inline]
+	fn is_finite(&self) -> bool {
+		self.0.is_finite()
+	}
+
+	#[inline]
+	fn is_infinite(&self) -> bool {
+		self.0.is_infinite()
+	}
+}
+
+impl Nan for DoubleDouble {
+	#[inline]
+	fn is_nan(&self) -> bool {
+		self.0.is_nan()
+	}
+}
+
+impl Normal for DoubleDouble {
+	#[inline]
+	fn is_normal(&self) -> bool {
+		self.0.is_normal()
+	}
+
+	#[inline]
+	fn is_subnormal(&self) -> bool {
+		self.0.is_subnormal()
+	}
+}
+
+impl Classify for DoubleDouble {
+	#[inline]
+	fn classify(self) -> FpCategory {
+		self.0.classify()
+	}
+}
+
+impl FloatFns for DoubleDouble {}
+
+impl Floor for DoubleDouble {
+	#[inline]
+	fn floor(self) -> Self {
+		let hi = self.0.floor();
+		if hi == self.0 {
+			// An integer high part leaves the low part to decide
+			quick_two_sum(hi, self.1.floor()).into()
+		} else {
+			// The low part is too small to reach the next integer
+			DoubleDouble(hi, 0.0)
+		}
+	}
+}
+
+impl Ceil for DoubleDouble {
+	#[inline]
+	fn ceil(self) -> Self {
+		let hi = self.0.ceil();
+		if hi == self.0 {
+			quick_two_sum(hi, self.1.ceil()).into()
+		} else {
+			DoubleDouble(hi, 0.0)
+		}
+	}
+}
+
+impl Trunc for DoubleDouble {
+	#[inline]
+	fn trunc(self) -> Self {
+		if self.0 < 0.0 {
+			Ceil::ceil(self)
+		} else {
+			Floor::floor(self)
+		}
+	}
+}
+
+impl Fract for DoubleDouble {
+	#[inline]
+	fn fract(self) -> Self {
+		self - Trunc::trunc(self)
+	}
+}
+
+impl Round for DoubleDouble {
+	/// Rounds half away from zero like f64
+	#[inline]
+	fn round(self) -> Self {
+		let t = Trunc::trunc(self);
+		if (self - t).abs() >= DoubleDouble::from(0.5) {
+			t + Signed::signum(&self)
+		} else {
+			t
+		}
+	}
+}
+
+impl Clamp for DoubleDouble {
+	#[inline]
+	fn clamp(self, min: Self, max: Self) -> Self {
+		if self < min {
+			min
+		} else if self > max {
+			max
+		} else {
+			self
+		}
+	}
+}
+
+impl DivEuclid for DoubleDouble {
+	#[inline]
+	fn div_euclid(self, rhs: Self) -> Self {
+		let q = Trunc::trunc(self / rhs);
+		if (self % rhs).0 < 0.0 {
+			if rhs.0 > 0.0 {
+				q - Self::one()
+			} else {
+				q + Self::one()
+			}
+		} else {
+			q
+		}
+	}
+}
+
+impl RemEuclid for DoubleDouble {
+	#[inline]
+	fn rem_euclid(self, rhs: Self) -> Self {
+		let r = self % rhs;
+		if r.0 < 0.0 {
+			r + rhs.abs()
+		} else {
+			r
+		}
+	}
+}
+
+impl Hypot for DoubleDouble {
+	#[inline]
+	fn hypot(self, other: Self) -> Self {
+		let (a, b) = (self.abs(), other.abs());
+		let m = a.0.max(b.0);
+		if !m.is_finite() || m == 0.0 {
+			return DoubleDouble::from(a.0.hypot(b.0));
+		}
+		// The squares are scaled by a power of two where they would overflow or underflow
+		let k = if m > 2f64.powi(500) {
+			600
+		} else if m < 2f64.powi(-500) {
+			-600
+		} else {
+			0
+		};
+		let (down, up) = (
+			DoubleDouble::from(2f64.powi(-k)),
+			DoubleDouble::from(2f64.powi(k)),
+		);
+		let (a, b) = (a * down, b * down);
+		(a * a + b * b).sqrt() * up
+	}
+}
+
+impl CubeRoot for DoubleDouble {
+	#[inline]
+	fn cbrt(self) -> Self {
+		let y = self.0.cbrt();
+		if !y.is_normal() {
+			return DoubleDouble::from(y);
+		}
+		// One Newton step from the f64 root doubles its precision
+		let y = DoubleDouble::from(y);
+		y + (self - y * y * y) / (DoubleDouble::from(3.0) * y * y)
+	}
+}
+
+impl Signed for DoubleDouble {
+	#[inline]
+	fn is_sign_positive(&self) -> bool {
+		self.0.is_sign_positive()
+	}
+
+	#[inline]
+	fn is_sign_negative(&self) -> bool {
+		self.0.is_sign_negative()
+	}
+
+	#[inline]
+	fn signum(&self) -> Self {
+		DoubleDouble::from(self.0.signum())
+	}
+
+	#[inline]
+	fn copysign(&self, sign: &Self) -> Self {
+		if self.0.is_sign_negative() == sign.0.is_sign_negative() {
+			*self
+		} else {
+			-*self
+		}
+	}
+}
+
+impl Max for DoubleDouble {
+	#[inline]
+	fn max(self, other: Self) -> Self {
+		if other.0.is_nan() || self >= other {
+			self
+		} else {
+			other
+		}
+	}
+}
+
+impl Min for DoubleDouble {
+	#[inline]
+	fn min(self, other: Self) -> Self {
+		if other.0.is_nan() || self <= other {
+			self
+		} else {
+			other
+		}
+	}
+}
+
+impl MulAdd for DoubleDouble {
+	/// Computes self * a + b, rounding the product and the sum
+	#[inline]
+	fn mul_add(self, a: Self, b: Self) -> Self {
+		self * a + b
+	}
+}
+
+impl Recip for DoubleDouble {
+	#[inline]
+	fn recip(self) -> Self {
+		DoubleDouble::one() / self
+	}
+}
+
+impl Degrees for DoubleDouble {
+	#[inline]
+	fn to_degrees(self) -> Self {
+		self * DoubleDouble::from(180.0) / DoubleDouble::PI
+	}
+
+	#[inline]
+	fn to_radians(self) -> Self {
+		self * DoubleDouble::PI / DoubleDouble::from(180.0)
+	}
+}
+
+impl Ops for DoubleDouble {}
+
+impl Float for DoubleDouble {}
+
+#[cfg(test)]
+mod tests {
+	use num_traits::{One, Zero};
+	use orml_quaternion::Quaternion;
+
+	use super::*;
+
+	fn dd(f: f64) -> DoubleDouble {
+		DoubleDouble::from(f)
+	}
+
+	fn to_polar<T: Float + Clone>(x: T, y: T) -> (T, T) {
+		(x.clone().hypot(y.clone()), y.atan2(&x))
+	}
+
+	fn from_polar<T: Float + Clone>(r: T, theta: T) -> (T, T) {
+		let (s, c) = theta.sin_cos();
+		(r.clone() * c, r * s)
+	}
+
+	#[test]
+	fn generic_float() {
+		let (r, theta) = to_polar(dd(3.0), dd(-4.0));
+		assert_eq!(r, dd(5.0));
+		let expected = to_polar(3.0f64, -4.0).1;
+		assert!((theta - dd(expected)).abs() < dd(1e-15));
+		let (x, y) = from_polar(r, theta);
+		assert!((x - dd(3.0)).abs() < dd(1e-30));
+		assert!((y + dd(4.0)).abs() < dd(1e-30));
+
+		// The quaternion functions only need a Float with references that multiply
+		let axis = [dd(0.0), dd(3.0) / dd(5.0), dd(4.0) / dd(5.0)];
+		let q = Quaternion::from_axis_angle(axis, dd(1.2));
+		let half = Quaternion::from_axis_angle(axis, dd(0.6));
+		let id = Quaternion::<DoubleDouble>::identity();
+		let d = Quaternion::<DoubleDouble>::slerp(&id, &q, dd(0.5)) - half;
+		assert!(Quaternion::<DoubleDouble>::length(&d) < dd(1e-30));
+		let d = q.pow(dd(0.5)) - half;
+		assert!(Quaternion::<DoubleDouble>::length(&d) < dd(1e-30));
+	}
+
+	#[test]
+	fn rounding() {
+		assert_eq!(Round::round(dd(2.5)), dd(3.0));
+		assert_eq!(Round::round(dd(-2.5)), dd(-3.0));
+		assert_eq!(Round::round(dd(2.4)), dd(2.0));
+		assert_eq!(Floor::floor(dd(-2.5)), dd(-3.0));
+		assert_eq!(Ceil::ceil(dd(-2.5)), dd(-2.0));
+		assert_eq!(Trunc::trunc(dd(-2.5)), dd(-2.0));
+		assert_eq!(Fract::fract(dd(-2.5)), dd(-0.5));
+		assert_eq!(DivEuclid::div_euclid(dd(-7.0), dd(4.0)), dd(-2.0));
+		assert_eq!(RemEuclid::rem_euclid(dd(-7.0), dd(4.0)), dd(1.0));
+		assert_eq!(DivEuclid::div_euclid(dd(7.0), dd(-4.0)), dd(-1.0));
+		assert_eq!(RemEuclid::rem_euclid(dd(7.0), dd(-4.0)), dd(3.0));
+		assert_eq!(dd(7.5) % dd(2.0), dd(1.5));
+
+		// Just above and below an integer only the low part tells which side it is on
+		let above = DoubleDouble::new(4.0, 1e-20);
+		let below = DoubleDouble::new(4.0, -1e-20);
+		assert_eq!(Floor::floor(above), dd(4.0));
+		assert_eq!(Floor::floor(below), dd(3.0));
+		assert_eq!(Ceil::ceil(above), dd(5.0));
+		assert_eq!(Ceil::ceil(below), dd(4.0));
+		assert_eq!(Fract::fract(above).hi(), 1e-20);
+	}
+
+	#[test]
+	fn roots() {
+		let third = DoubleDouble::one() / dd(3.0);
+		let c = CubeRoot::cbrt(dd(27.0) * third);
+		assert!((c * c * c - dd(9.0)).abs() < dd(1e-30));
+		assert_eq!(CubeRoot::cbrt(dd(-8.0)), dd(-2.0));
+		let big = 2f64.powi(1000);
+		assert_eq!(dd(3.0 * big).hypot(dd(4.0 * big)), dd(5.0 * big));
+		let small = 2f64.powi(-1000);
+		assert_eq!(dd(3.0 * small).hypot(dd(4.0 * small)), dd(5.0 * small));
+		assert_eq!(Pow::powi(&dd(2.0), 100), dd(2f64.powi(100)));
+		// Not exact: with fused products ln 81 / ln 3 rounds to 4 - 8.975e-32
+		assert!((Log::log(dd(81.0), dd(3.0)) - dd(4.0)).abs() < dd(1e-30));
+	}
+
+	#[test]
+	fn classify() {
+		assert_eq!(Classify::classify(DoubleDouble::zero()), FpCategory::Zero);
+		assert_eq!(Classify::classify(DoubleDouble::one()), FpCategory::Normal);
+		let nan = DoubleDouble::zero() / DoubleDouble::zero();
+		assert!(nan.is_nan());
+		assert_eq!(Max::max(dd(1.0), nan), dd(1.0));
+		assert_eq!(Min::min(nan, dd(1.0)), dd(1.0));
+		assert_eq!(Clamp::clamp(dd(5.0), dd(0.0), dd(1.0)), dd(1.0));
+		assert!((DoubleDouble::one() / DoubleDouble::zero()).is_infinite());
+		assert_eq!(dd(-2.0).copysign(&dd(1.0)), dd(2.0));
+		assert_eq!(AbsSub::abs_sub(dd(1.0), dd(3.0)), dd(0.0));
+		let degrees = dd(180.0).to_radians().to_degrees();
+		assert!((degrees - dd(180.0)).abs() < dd(1e-29));
+		assert_eq!(dd(90.0).to_radians(), DoubleDouble::FRAC_PI_2);
+	}
+}
@@ -1,7 +1,121 @@
-use std::ops::{Add, Sub};
+#![warn(missing_docs)]
 
+//! Double-double floats
+//!
+//! This module contains [`DoubleDouble`], a float of about 106 bits stored as the unevaluated
+//! sum of two f64, its elementary functions and the error-free transforms it is built on, as
+//! well as the quad-double [`quad::QuadDouble`] of about 212 bits
+
+use std::fmt::Display;
+
+/// Implements the binary operator $bound for $t and its references with $op, a function of two
+/// values that convert from $t and back
+///
+/// DoubleDouble's operations work on (f64, f64) and QuadDouble's on QuadDouble itself, so for
+/// the latter the conversions do nothing
+macro_rules! impl_op {
+	($t:ident,$lhs:ty,$rhs:ty,$func:ident,$bound:ident,$op:ident) => {
+		impl $bound<$rhs> for $lhs {
+			type Output = $t;
+
+			#[inline]
+			#[allow(clippy::useless_conversion)]
+			fn $func(self, rhs: $rhs) -> Self::Output {
+				$op(self.into(), rhs.into()).into()
+			}
+		}
+	};
+}
+
+macro_rules! impl_op_vers {
+	($t:ident,$func:ident,$bound:ident,$op:ident) => {
+		impl_op!($t, $t, $t, $func, $bound, $op);
+		impl_op!($t, $t, &$t, $func, $bound, $op);
+		impl_op!($t, &$t, $t, $func, $bound, $op);
+		impl_op!($t, &$t, &$t, $func, $bound, $op);
+	};
+}
+
+macro_rules! impl_op_assign {
+	($t:ident,$rhs:ty,$func:ident,$bound:ident,$op:ident) => {
+		impl $bound<$rhs> for $t {
+			#[inline]
+			#[allow(clippy::useless_conversion)]
+			fn $func(&mut self, rhs: $rhs) {
+				*self = $op((*self).into(), rhs.into()).into();
+			}
+		}
+	};
+}
+
+macro_rules! impl_op_assign_vers {
+	($t:ident,$func:ident,$bound:ident,$op:ident) => {
+		impl_op_assign!($t, $t, $func, $bound, $op);
+		impl_op_assign!($t, &$t, $func, $bound, $op);
+	};
+}
+
+pub mod arithmetic;
+pub mod decimal;
+pub mod float;
+pub mod quad;
+pub mod transcendental;
+
+#[cfg(test)]
+mod test_util;
+
+/// A double-double float, the unevaluated sum hi + lo of two f64
+///
+/// lo is never more than half an ulp of hi, so hi is the f64 nearest to the value and the pair
+/// compares like the value it represents
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct DoubleDouble(f64, f64);
 
+/// The errors that can occur when parsing a DoubleDouble
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseDoubleDoubleError {
+	/// The string had no digits
+	Empty,
+	/// The string wasn't a decimal float literal
+	Invalid,
+}
+
+impl Display for ParseDoubleDoubleError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			ParseDoubleDoubleError::Empty => write!(f, "cannot parse float from empty string"),
+			ParseDoubleDoubleError::Invalid => write!(f, "invalid float literal"),
+		}
+	}
+}
+
+impl std::error::Error for ParseDoubleDoubleError {}
+
+impl DoubleDouble {
+	/// Creates a new DoubleDouble equal to hi + lo
+	#[inline]
+	pub fn new(hi: f64, lo: f64) -> Self {
+		let (hi, lo) = two_sum(hi, lo);
+		if hi.is_finite() {
+			Self(hi, lo)
+		} else {
+			Self(hi, 0.0)
+		}
+	}
+
+	/// Returns the high part, the f64 nearest to the DoubleDouble
+	#[inline]
+	pub fn hi(self) -> f64 {
+		self.0
+	}
+
+	/// Returns the low part, the rounding error of the high part
+	#[inline]
+	pub fn lo(self) -> f64 {
+		self.1
+	}
+}
+
 impl From<(f64, f64)> for DoubleDouble {
 	fn from(value: (f64, f64)) -> Self {
 		Self(value.0, value.1)
@@ -14,26 +128,50 @@ impl From<DoubleDouble> for (f64, f64) {
 	}
 }
 
-impl Add for DoubleDouble {
-	type Output = DoubleDouble;
-	fn add(self, rhs: Self) -> Self::Output {
-		add2(self.into(), rhs.into()).into()
+impl From<f64> for DoubleDouble {
+	#[inline]
+	fn from(value: f64) -> Self {
+		Self(value, 0.0)
 	}
 }
 
-impl Sub for DoubleDouble {
-	type Output = DoubleDouble;
-	fn sub(self, rhs: Self) -> Self::Output {
-		sub2(self.into(), rhs.into()).into()
+/// Rounds to the nearest f64, which is the high part
+impl From<DoubleDouble> for f64 {
+	#[inline]
+	fn from(value: DoubleDouble) -> Self {
+		value.0
 	}
 }
 
 fn zzz(r: f64, s: f64) -> (f64, f64) {
+	// The error terms of infinities are NaN, so an infinite result is returned on its own
+	if !r.is_finite() {
+		return (r, 0.0);
+	}
 	let z = r + s;
+	if !z.is_finite() {
+		return (z, 0.0);
+	}
 	let zz = r - z + s;
 	(z, zz)
 }
 
+/// Whether a is finite but large enough that products near it can round up to infinity
+fn near_max(a: f64) -> bool {
+	a.is_finite() && a.abs() > SPLIT_THRESHOLD
+}
+
+/// Returns x 2^e, as infinity if the high part overflows
+fn scale(x: (f64, f64), e: i32) -> (f64, f64) {
+	let hi = x.0 * 2f64.powi(e);
+	if hi.is_finite() {
+		(hi, x.1 * 2f64.powi(e))
+	} else {
+		(hi, 0.0)
+	}
+}
+
+/// Returns the double-double sum x + y by Dekker's method
 pub fn add2(x: (f64, f64), y: (f64, f64)) -> (f64, f64) {
 	let r = x.0 + y.0;
 	let s = if x.0.abs() > y.0.abs() {
@@ -44,6 +182,7 @@ pub fn add2(x: (f64, f64), y: (f64, f64)) -> (f64, f64) {
 	zzz(r, s)
 }
 
+/// Returns the double-double difference x - y by Dekker's method
 pub fn sub2(x: (f64, f64), y: (f64, f64)) -> (f64, f64) {
 	let r = x.0 - y.0;
 	let s = if x.0.abs() > y.0.abs() {
@@ -54,8 +193,26 @@ pub fn sub2(x: (f64, f64), y: (f64, f64)) -> (f64, f64) {
 	zzz(r, s)
 }
 
+/// Whether products are split with a fused multiply-add, set by the `fma` feature or by
+/// compiling for a target with FMA
+///
+/// f64::mul_add is correct either way, but without hardware FMA it is much slower than Dekker's
+/// method
+pub const FMA: bool = cfg!(any(feature = "fma", target_feature = "fma"));
+
+/// Returns the exact product x y as a double-double, by [`two_prod_fma`] when [`FMA`] is set
+/// and by Dekker's method otherwise
 #[allow(non_snake_case)]
 pub fn mul12(x: f64, y: f64) -> (f64, f64) {
+	if FMA {
+		return two_prod_fma(x, y);
+	}
+	if !(x * y).is_finite() {
+		return (x * y, 0.0);
+	}
+	if x.abs() > SPLIT_THRESHOLD || y.abs() > SPLIT_THRESHOLD {
+		return scaled_product(x, y, mul12);
+	}
 	let A = split(x);
 	let B = split(y);
 
@@ -63,19 +220,37 @@ pub fn mul12(x: f64, y: f64) -> (f64, f64) {
 	let q = A.0 * B.1 + A.1 * B.0;
 
 	let r = p + q;
-	(r, p - r + q + A.1 * B.0)
+	(r, p - r + q + A.1 * B.1)
 }
 
+/// Returns the double-double product x y
 pub fn mul(x: (f64, f64), y: (f64, f64)) -> (f64, f64) {
+	// A product near f64::MAX can round up to infinity before its error term brings it back, so
+	// it is found scaled down by 2^64
+	if near_max(x.0) {
+		return scale(mul(scale(x, -64), y), 64);
+	}
+	if near_max(y.0) {
+		return scale(mul(x, scale(y, -64)), 64);
+	}
 	let t = mul12(x.0, y.0);
 
-	let c = x.0 * y.1 + x.1 * y.1 + t.1;
+	let c = x.0 * y.1 + x.1 * y.0 + t.1;
 
 	zzz(t.0, c)
 }
 
+/// Returns the double-double quotient x / y
 pub fn div(x: (f64, f64), y: (f64, f64)) -> (f64, f64) {
 	let u = x.0 / y.0;
+	if u == 0.0 {
+		// Includes division by infinity, whose error term would be NaN
+		return (u, 0.0);
+	}
+	if near_max(x.0) && u.is_finite() {
+		// u y can round up to infinity
+		return scale(div(scale(x, -64), y), 64);
+	}
 
 	let t = mul12(u, y.0);
 
@@ -83,31 +258,61 @@ pub fn div(x: (f64, f64), y: (f64, f64)) -> (f64, f64) {
 	zzz(u, l)
 }
 
+/// Returns the double-double square root of x, NaN if x is negative or NaN
 pub fn sqrt(x: (f64, f64)) -> (f64, f64) {
 	if x.0 > 0.0 {
 		let c = x.0.sqrt();
 		let u = mul12(c, c);
 		let cc = (x.0 - u.0 - u.1 + x.1) * 0.5 / c;
 		zzz(c, cc)
-	} else {
+	} else if x.0 == 0.0 {
 		(0.0, 0.0)
+	} else {
+		(f64::NAN, f64::NAN)
 	}
 }
 
-const SCALE: f64 = (2usize.pow(53 / 2) + 1) as f64;
+// Veltkamp's splitter 2^ceil(53 / 2) + 1
+const SCALE: f64 = (2usize.pow(53 - 53 / 2) + 1) as f64;
 
+// Above 2^996 the product with the splitter can overflow
+const SPLIT_THRESHOLD: f64 = 6.696928794914171e299;
+
+/// Splits a into a high part of 26 bits and a low part of 26 bits plus a sign, whose products
+/// are exact
+///
+/// Within 2^970 of f64::MAX the high part rounds up to infinity
 pub fn split(a: f64) -> (f64, f64) {
+	if a.abs() > SPLIT_THRESHOLD {
+		// a * SCALE would overflow, so a is split scaled down by 2^28 instead
+		let a = a * 2f64.powi(-28);
+		let t = a * SCALE;
+		let hi = a - t + t;
+		return (hi * 2f64.powi(28), (a - hi) * 2f64.powi(28));
+	}
 	let t = a * SCALE;
 	let hi = a - t + t;
 	(hi, a - hi)
 }
 
+/// Returns the product of a and b by the Dekker product f, with the larger operand scaled down
+/// by 2^64 so that its high part can't round up to infinity
+///
+/// Scaling by a power of two is exact, and a finite product scales back exactly
+fn scaled_product(a: f64, b: f64, f: fn(f64, f64) -> (f64, f64)) -> (f64, f64) {
+	let (a, b) = if a.abs() >= b.abs() { (a, b) } else { (b, a) };
+	let (p, e) = f(a * 2f64.powi(-64), b);
+	(p * 2f64.powi(64), e * 2f64.powi(64))
+}
+
+/// Returns the sum a + b and its rounding error, when |a| >= |b|
 pub fn quick_two_sum(a: f64, b: f64) -> (f64, f64) {
 	let s = a + b;
 	let e = b - (s - a);
 	(s, e)
 }
 
+/// Returns the sum a + b and its rounding error
 pub fn two_sum(a: f64, b: f64) -> (f64, f64) {
 	let s = a + b;
 	let v = s - a;
@@ -115,28 +320,56 @@ pub fn two_sum(a: f64, b: f64) -> (f64, f64) {
 	(s, e)
 }
 
+/// Returns the product a b and its rounding error, by [`two_prod_fma`] when [`FMA`] is set and
+/// by [`two_prod_dekker`] otherwise
+#[inline]
 pub fn two_prod(a: f64, b: f64) -> (f64, f64) {
+	if FMA {
+		two_prod_fma(a, b)
+	} else {
+		two_prod_dekker(a, b)
+	}
+}
+
+/// Returns the product a b and its rounding error from the products of the halves of a and b
+pub fn two_prod_dekker(a: f64, b: f64) -> (f64, f64) {
 	let p = a * b;
+	if !p.is_finite() {
+		return (p, 0.0);
+	}
+	if a.abs() > SPLIT_THRESHOLD || b.abs() > SPLIT_THRESHOLD {
+		return scaled_product(a, b, two_prod_dekker);
+	}
 	let a = split(a);
 	let b = split(b);
 	let e = ((a.0 * b.0 - p) + a.0 * b.1 + a.1 * b.0) + a.1 * b.1;
 	(p, e)
 }
 
+/// Returns the product a b and its rounding error, which a fused multiply-add finds exactly as
+/// a b - p
+#[inline]
 pub fn two_prod_fma(a: f64, b: f64) -> (f64, f64) {
 	let p = a * b;
-	let e = a * b - p;
+	let e = a.mul_add(b, -p);
 	(p, e)
 }
 
+/// Returns the four term expansion of the sum of a five term expansion
+///
+/// The terms are given largest first and may overlap. The result doesn't overlap, and its
+/// first term is the sum rounded to an f64
 pub fn renormalise(a: (f64, f64, f64, f64, f64)) -> (f64, f64, f64, f64) {
+	if !a.0.is_finite() {
+		return (a.0, 0.0, 0.0, 0.0);
+	}
 	let mut t: [f64; 5] = Default::default();
 
 	let x = quick_two_sum(a.3, a.4);
 	t[4] = x.1;
 	let x = quick_two_sum(a.2, x.0);
 	t[3] = x.1;
-	let x = quick_two_sum(a.2, x.0);
+	let x = quick_two_sum(a.1, x.0);
 	t[2] = x.1;
 	let x = quick_two_sum(a.0, x.0);
 	t[1] = x.1;
@@ -145,14 +378,134 @@ pub fn renormalise(a: (f64, f64, f64, f64, f64)) -> (f64, f64, f64, f64) {
 	let mut s = t[0];
 	let mut k = 0;
 	let mut b: [f64; 4] = Default::default();
-	for i in 1..=4 {
-		let x = quick_two_sum(s, t[i]);
+	for &ti in &t[1..] {
+		let x = quick_two_sum(s, ti);
 		if x.1 != 0.0 {
 			b[k] = x.0;
 			s = x.1;
 			k += 1;
+		} else {
+			s = x.0;
 		}
 	}
+	// What is left joins the result unless all four terms are already taken
+	if k < 4 {
+		b[k] = s;
+	}
 
 	(b[0], b[1], b[2], b[3])
 }
+
+#[cfg(test)]
+mod tests {
+	use proptest::prelude::*;
+
+	use super::{mul12, renormalise, two_prod, two_prod_dekker, two_prod_fma, two_sum};
+	use crate::test_util::BF;
+
+	/// Every product of two f64 fits in the 256 bits
+	fn exact_product(a: f64, b: f64) -> BF {
+		BF::from(a) * BF::from(b)
+	}
+
+	/// Whether the pair sums exactly to a b
+	fn is_exact(pair: (f64, f64), a: f64, b: f64) -> bool {
+		BF::from(pair.0) + BF::from(pair.1) == exact_product(a, b)
+	}
+
+	/// f64 of every mantissa and sign, with exponents that keep products and their errors normal
+	fn factor() -> impl Strategy<Value = f64> {
+		(any::<u64>(), -450..450i32, any::<bool>()).prop_map(|(m, e, negative)| {
+			let x = f64::from_bits(1f64.to_bits() | (m >> 12)) * 2f64.powi(e);
+			if negative {
+				-x
+			} else {
+				x
+			}
+		})
+	}
+
+	#[test]
+	fn exact_products() {
+		let pairs = [
+			(1.0 + f64::EPSILON, 1.0 - f64::EPSILON),
+			(0.1, 0.3),
+			(-1234.5678e10, 9.87654321e-7),
+			(std::f64::consts::PI, std::f64::consts::E),
+		];
+		for (a, b) in pairs {
+			// The low part holds exactly what the rounded product lost. mul12 may round the other
+			// way, so it agrees once it is normalised
+			let (p, e) = two_prod(a, b);
+			assert_eq!(p, a * b);
+			let (hi, lo) = mul12(a, b);
+			assert_eq!(two_sum(hi, lo), (p, e));
+		}
+		let (p, e) = mul12(1.0 + f64::EPSILON, 1.0 - f64::EPSILON);
+		assert_eq!(p, 1.0);
+		assert_eq!(e, -f64::EPSILON * f64::EPSILON);
+	}
+
+	#[test]
+	fn error_free_products() {
+		let pairs = [
+			(1.0 + f64::EPSILON, 1.0 - f64::EPSILON),
+			// Large enough that the split has to scale them down
+			(1.5e300, -3.3e-280),
+			(7.0e299, 1.0 + f64::EPSILON),
+			(0.1, 0.1),
+		];
+		for (a, b) in pairs {
+			for f in [two_prod, two_prod_dekker, two_prod_fma] {
+				let (p, e) = f(a, b);
+				assert_eq!(p, a * b);
+				assert!(is_exact((p, e), a, b), "{a} {b}");
+			}
+			assert!(is_exact(mul12(a, b), a, b), "{a} {b}");
+		}
+		// Without the fused multiply-add a b - p is always zero
+		assert_eq!(two_prod_fma(0.1, 0.1).1, two_prod_dekker(0.1, 0.1).1);
+		assert_ne!(two_prod_fma(0.1, 0.1).1, 0.0);
+	}
+
+	proptest! {
+		#![proptest_config(ProptestConfig::with_cases(2000))]
+
+		#[test]
+		fn exact_two_prod(a in factor(), b in factor()) {
+			let fma = two_prod_fma(a, b);
+			let dekker = two_prod_dekker(a, b);
+			prop_assert!(is_exact(fma, a, b));
+			prop_assert!(is_exact(dekker, a, b));
+			// The rounding error of a b is unique, so both methods agree bit for bit
+			prop_assert_eq!(fma, dekker);
+			prop_assert_eq!(two_prod(a, b), fma);
+			prop_assert!(is_exact(mul12(a, b), a, b));
+		}
+	}
+
+	#[test]
+	fn renormalise_terms() {
+		let p = |n: i32| 2f64.powi(n);
+		// Terms that don't overlap are kept as they are, and the last one is dropped
+		assert_eq!(
+			renormalise((1.0, p(-60), p(-120), p(-180), p(-240))),
+			(1.0, p(-60), p(-120), p(-180))
+		);
+		// The second term counts
+		assert_eq!(
+			renormalise((1.0, p(-60), 0.0, 0.0, 0.0)),
+			(1.0, p(-60), 0.0, 0.0)
+		);
+		// Overlapping terms are carried up and the zeros left at the end
+		assert_eq!(
+			renormalise((1.0, 1.0, p(-70), p(-70), 0.0)),
+			(2.0, p(-69), 0.0, 0.0)
+		);
+		// Ties with the next term round to even, so these stay apart
+		assert_eq!(
+			renormalise((1.0, p(-53), p(-106), p(-159), 0.0)),
+			(1.0, p(-53), p(-106), p(-159))
+		);
+	}
+}
@@ -0,0 +1,882 @@
+//! This module contains [`QuadDouble`], a float of about 212 bits stored as the unevaluated sum
+//! of four f64
+//!
+//! The arithmetic follows the QD library of Hida, Li and Bailey: each operation gathers its
+//! result into an expansion of five f64 with [`two_sum`] and [`two_prod`], then [`renormalise`]s
+//! it to four. The elementary functions reduce their arguments like those of [`DoubleDouble`],
+//! and start their Newton steps from its results
+
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use num_traits::{One, Zero};
+use orml_traits::fns::abs::Abs;
+use orml_traits::fns::exp::Exp;
+use orml_traits::fns::log::{Ln, Log10};
+use orml_traits::fns::pow::Pow;
+use orml_traits::fns::trig::*;
+use orml_traits::fns::SquareRoot;
+
+use crate::transcendental::rem_frac_pi_2;
+use crate::{quick_two_sum, renormalise, two_prod, two_sum, DoubleDouble};
+
+/// A quad-double float, the unevaluated sum of four f64 that don't overlap, largest first
+///
+/// Like [`DoubleDouble`], the first term is the f64 nearest to the value, so the terms compare
+/// like the value they represent
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct QuadDouble(f64, f64, f64, f64);
+
+/// ln(2) to about 240 bits in five f64, with the short first one of [`DoubleDouble`]'s split so
+/// that exp can still subtract its multiples exactly
+const LN_2_PARTS: [f64; 5] = [
+	0.6931471803691238,
+	1.9082149292705877e-10,
+	1.1612227229362532e-26,
+	-5.71177979575743e-43,
+	6.25483832099138e-60,
+];
+
+/// 2^-216, a few bits past the last term of a QuadDouble, where series are cut off
+const EPS: f64 = 1.0 / (1u128 << 106) as f64 / (1u128 << 110) as f64;
+
+/// Halving the reduced argument more often than for a DoubleDouble shortens the longer series
+const EXP_HALVINGS: i32 = 12;
+
+/// Returns the sum of a, b and c as a three term expansion, largest first
+fn three_sum(a: f64, b: f64, c: f64) -> (f64, f64, f64) {
+	let (t1, t2) = two_sum(a, b);
+	let (s, t3) = two_sum(c, t1);
+	let (e, f) = two_sum(t2, t3);
+	(s, e, f)
+}
+
+/// Adds c into the two term accumulator (u, v), returning the top term once it is complete
+fn three_accumulate(u: &mut f64, v: &mut f64, c: f64) -> Option<f64> {
+	let (s, e) = two_sum(*v, c);
+	*v = e;
+	let (s, e) = two_sum(*u, s);
+	*u = e;
+	if *u != 0.0 && *v != 0.0 {
+		return Some(s);
+	}
+	if *v == 0.0 {
+		*v = *u;
+	}
+	*u = s;
+	None
+}
+
+impl QuadDouble {
+	/// Archimedes' constant π
+	pub const PI: Self = QuadDouble(
+		std::f64::consts::PI,
+		1.2246467991473532e-16,
+		-2.9947698097183397e-33,
+		1.1124542208633653e-49,
+	);
+	/// The full circle constant τ = 2π
+	pub const TAU: Self = QuadDouble(
+		std::f64::consts::TAU,
+		2.4492935982947064e-16,
+		-5.989539619436679e-33,
+		2.2249084417267306e-49,
+	);
+	/// π/2
+	pub const FRAC_PI_2: Self = QuadDouble(
+		std::f64::consts::FRAC_PI_2,
+		6.123233995736766e-17,
+		-1.4973849048591698e-33,
+		5.562271104316826e-50,
+	);
+	/// π/4
+	pub const FRAC_PI_4: Self = QuadDouble(
+		std::f64::consts::FRAC_PI_4,
+		3.061616997868383e-17,
+		-7.486924524295849e-34,
+		2.781135552158413e-50,
+	);
+	/// Euler's number e
+	pub const E: Self = QuadDouble(
+		std::f64::consts::E,
+		1.4456468917292502e-16,
+		-2.1277171080381768e-33,
+		1.5156301598412191e-49,
+	);
+	/// ln(2)
+	pub const LN_2: Self = QuadDouble(
+		std::f64::consts::LN_2,
+		2.3190468138462996e-17,
+		5.707708438416212e-34,
+		-3.5824322106018114e-50,
+	);
+	/// ln(10)
+	pub const LN_10: Self = QuadDouble(
+		std::f64::consts::LN_10,
+		-2.1707562233822494e-16,
+		-9.984262454465777e-33,
+		-4.023357454450206e-49,
+	);
+
+	/// Creates a new QuadDouble equal to a + b + c + d, given largest first
+	#[inline]
+	pub fn new(a: f64, b: f64, c: f64, d: f64) -> Self {
+		renormalise((a, b, c, d, 0.0)).into()
+	}
+
+	/// Returns the four terms, largest first
+	#[inline]
+	pub fn terms(self) -> [f64; 4] {
+		[self.0, self.1, self.2, self.3]
+	}
+
+	/// Returns the absolute value
+	#[inline]
+	pub fn abs(self) -> Self {
+		if self.0 < 0.0 {
+			-self
+		} else {
+			self
+		}
+	}
+
+	/// Returns self * 2^n, which is exact unless the result overflows or is subnormal
+	pub(crate) fn ldexp(self, n: i32) -> Self {
+		let a = 2f64.powi(n / 2);
+		let b = 2f64.powi(n - n / 2);
+		if !(self.0 * a * b).is_finite() {
+			return QuadDouble(self.0 * a * b, 0.0, 0.0, 0.0);
+		}
+		QuadDouble(
+			self.0 * a * b,
+			self.1 * a * b,
+			self.2 * a * b,
+			self.3 * a * b,
+		)
+	}
+
+	/// Returns the square root, NaN for negative numbers
+	pub fn sqrt(self) -> Self {
+		if self.is_zero() {
+			return self;
+		}
+		if self.0.is_nan() || self.0 < 0.0 || self.0 == f64::INFINITY {
+			return QuadDouble::from(self.0.sqrt());
+		}
+		// Newton's method for 1 / sqrt(x) doubles the precision of the f64 root three times, and
+		// needs no division
+		let half = QuadDouble::from(0.5);
+		let h = self.ldexp(-1);
+		let mut r = QuadDouble::from(1.0 / self.0.sqrt());
+		for _ in 0..3 {
+			r += r * (half - h * r * r);
+		}
+		r * self
+	}
+
+	/// Returns self raised to the unsigned power n by repeated squaring
+	fn powu(self, n: usize) -> Self {
+		let mut result = QuadDouble::one();
+		let mut base = self;
+		let mut e = n;
+		while e > 0 {
+			if e & 1 == 1 {
+				result *= base;
+			}
+			base *= base;
+			e >>= 1;
+		}
+		result
+	}
+
+	/// Returns self raised to the integer power n by repeated squaring
+	pub fn powi(self, n: i32) -> Self {
+		let result = self.powu(n.unsigned_abs() as usize);
+		if n < 0 {
+			QuadDouble::one() / result
+		} else {
+			result
+		}
+	}
+
+	/// Returns self - n c, where c is split into parts like [`LN_2_PARTS`]
+	fn reduce(self, n: f64, c: [f64; 5]) -> Self {
+		let mut t = self - QuadDouble::from(c[0] * n);
+		for &ci in &c[1..4] {
+			let (p, e) = two_prod(ci, n);
+			t -= QuadDouble::new(p, e, 0.0, 0.0);
+		}
+		t - QuadDouble::from(c[4] * n)
+	}
+
+	/// Returns e^r - 1 for |r| <= ln(2) / 2
+	fn exp_m1_reduced(self) -> Self {
+		if self.is_zero() {
+			return self;
+		}
+		let r = self.ldexp(-EXP_HALVINGS);
+		let mut term = r;
+		let mut sum = r;
+		let mut n = 1.0;
+		while term.0.abs() > EPS * sum.0.abs() {
+			n += 1.0;
+			term = term * r / QuadDouble::from(n);
+			sum += term;
+		}
+		for _ in 0..EXP_HALVINGS {
+			sum = sum * (sum + QuadDouble::from(2.0));
+		}
+		sum
+	}
+
+	/// Returns e^self
+	pub fn exp(self) -> Self {
+		if self.0.is_nan() {
+			return self;
+		}
+		if self.0 > 709.8 {
+			return QuadDouble::from(f64::INFINITY);
+		}
+		if self.0 < -745.2 {
+			return QuadDouble::zero();
+		}
+		let m = (self.0 / std::f64::consts::LN_2).round();
+		let r = self.reduce(m, LN_2_PARTS);
+		(r.exp_m1_reduced() + QuadDouble::one()).ldexp(m as i32)
+	}
+
+	/// Returns e^self - 1, which keeps its relative precision for small arguments
+	pub fn exp_m1(self) -> Self {
+		if self.0.abs() <= std::f64::consts::LN_2 / 2.0 {
+			self.exp_m1_reduced()
+		} else {
+			self.exp() - QuadDouble::one()
+		}
+	}
+
+	/// Returns ln(1 + self), which keeps its relative precision for small arguments
+	pub fn ln_1p(self) -> Self {
+		if self.0.abs() >= 0.5 || self.0.is_nan() {
+			return (QuadDouble::one() + self).ln();
+		}
+		// One Newton step on e^y - 1 = x from the DoubleDouble logarithm doubles its precision
+		let y = QuadDouble::from(DoubleDouble::from(self).ln_1p());
+		let e = y.exp_m1();
+		y - (e - self) / (e + QuadDouble::one())
+	}
+
+	/// Returns the natural logarithm, NaN for negative numbers
+	pub fn ln(self) -> Self {
+		if self.0.is_nan() || self.0 < 0.0 {
+			return QuadDouble::from(f64::NAN);
+		}
+		if self.0 == 0.0 {
+			return QuadDouble::from(f64::NEG_INFINITY);
+		}
+		if self.0 == f64::INFINITY {
+			return self;
+		}
+		// self = m 2^k with m within a factor of √2 of 1, where m - 1 is exact
+		let k = self.0.log2().round();
+		let m = self.ldexp(-(k as i32));
+		(m - QuadDouble::one()).ln_1p() + Self::LN_2 * QuadDouble::from(k)
+	}
+
+	/// Returns the base 10 logarithm
+	pub fn log10(self) -> Self {
+		self.ln() / Self::LN_10
+	}
+
+	/// Returns self raised to the power n
+	///
+	/// Integer powers are found by repeated squaring, so negative numbers have them too. Other
+	/// powers are e^(n ln self), NaN for negative numbers
+	pub fn pow(self, n: Self) -> Self {
+		if n.1 == 0.0 && n.0.fract() == 0.0 && n.0.abs() < i32::MAX as f64 {
+			return self.powi(n.0 as i32);
+		}
+		if self.is_zero() && n.0 > 0.0 {
+			return QuadDouble::zero();
+		}
+		(n * self.ln()).exp()
+	}
+
+	/// Returns the Taylor series of sin for start = t and cos for start = 1, given t^2
+	fn trig_series(start: Self, n: f64, t2: Self) -> Self {
+		let mut term = start;
+		let mut sum = start;
+		let mut n = n;
+		while term.0.abs() > EPS * sum.0.abs() {
+			term = -(term * t2 / QuadDouble::from((n + 1.0) * (n + 2.0)));
+			n += 2.0;
+			sum += term;
+		}
+		sum
+	}
+
+	/// Returns the sine and cosine
+	///
+	/// All four terms of the argument are reduced against 2/π by [`rem_frac_pi_2`], so large
+	/// arguments keep their precision
+	pub fn sin_cos(self) -> (Self, Self) {
+		if !self.0.is_finite() {
+			let nan = QuadDouble::from(f64::NAN);
+			return (nan, nan);
+		}
+		if self.is_zero() {
+			return (self, QuadDouble::one());
+		}
+		let (q, t) = if self.0.abs() <= std::f64::consts::FRAC_PI_4 {
+			(0, self)
+		} else {
+			let (q, r) = rem_frac_pi_2(&self.terms());
+			let r: QuadDouble = renormalise((r[0], r[1], r[2], r[3], 0.0)).into();
+			(q, r * Self::FRAC_PI_2)
+		};
+		let t2 = t * t;
+		let s = Self::trig_series(t, 1.0, t2);
+		let c = Self::trig_series(QuadDouble::one(), 0.0, t2);
+		match q {
+			0 => (s, c),
+			1 => (c, -s),
+			2 => (-s, -c),
+			_ => (-c, s),
+		}
+	}
+
+	/// Returns the sine
+	pub fn sin(self) -> Self {
+		self.sin_cos().0
+	}
+
+	/// Returns the cosine
+	pub fn cos(self) -> Self {
+		self.sin_cos().1
+	}
+
+	/// Returns the tangent
+	pub fn tan(self) -> Self {
+		let (s, c) = self.sin_cos();
+		s / c
+	}
+
+	/// Returns the angle of the point (x, self) from the positive x axis, in [-π, π]
+	pub fn atan2(self, x: Self) -> Self {
+		let y = self;
+		if !y.0.is_finite() || !x.0.is_finite() || y.is_zero() || x.is_zero() {
+			// The DoubleDouble angle is exact or as exact as it can be in these cases
+			let a = DoubleDouble::from(y).atan2(DoubleDouble::from(x));
+			return if a.hi().abs() == std::f64::consts::PI {
+				Self::PI.copysign(a.hi())
+			} else if a.hi().abs() == std::f64::consts::FRAC_PI_2 {
+				Self::FRAC_PI_2.copysign(a.hi())
+			} else {
+				QuadDouble::from(a)
+			};
+		}
+
+		// One Newton step from the DoubleDouble angle, on whichever of sin and cos is steeper
+		let r = (x * x + y * y).sqrt();
+		let (x, y) = (x / r, y / r);
+		let z = QuadDouble::from(DoubleDouble::from(y).atan2(DoubleDouble::from(x)));
+		let (s, c) = z.sin_cos();
+		if x.0.abs() > y.0.abs() {
+			z + (y - s) / c
+		} else {
+			z - (x - c) / s
+		}
+	}
+
+	/// Returns self with the sign of the f64 sign
+	fn copysign(self, sign: f64) -> Self {
+		if self.0.is_sign_negative() == sign.is_sign_negative() {
+			self
+		} else {
+			-self
+		}
+	}
+
+	/// Returns the arctangent, in [-π/2, π/2]
+	pub fn atan(self) -> Self {
+		self.atan2(QuadDouble::one())
+	}
+
+	/// Returns the arcsine, in [-π/2, π/2], NaN outside [-1, 1]
+	pub fn asin(self) -> Self {
+		let one = QuadDouble::one();
+		let c = ((one - self) * (one + self)).sqrt();
+		self.atan2(c)
+	}
+
+	/// Returns the arccosine, in [0, π], NaN outside [-1, 1]
+	pub fn acos(self) -> Self {
+		let one = QuadDouble::one();
+		let s = ((one - self) * (one + self)).sqrt();
+		s.atan2(self)
+	}
+
+	/// Returns the hyperbolic sine
+	pub fn sinh(self) -> Self {
+		let e = self.abs().exp_m1();
+		let s = (e + e / (e + QuadDouble::one())).ldexp(-1);
+		s.copysign(self.0)
+	}
+
+	/// Returns the hyperbolic cosine
+	pub fn cosh(self) -> Self {
+		let e = self.abs().exp();
+		(e + QuadDouble::one() / e).ldexp(-1)
+	}
+
+	/// Returns the hyperbolic tangent
+	pub fn tanh(self) -> Self {
+		let a = self.abs();
+		let t = if a.0 > 80.0 {
+			// 1 - tanh(a) is below the precision
+			QuadDouble::one()
+		} else {
+			let e = a.ldexp(1).exp_m1();
+			e / (e + QuadDouble::from(2.0))
+		};
+		t.copysign(self.0)
+	}
+}
+
+impl From<(f64, f64, f64, f64)> for QuadDouble {
+	fn from(value: (f64, f64, f64, f64)) -> Self {
+		Self(value.0, value.1, value.2, value.3)
+	}
+}
+
+impl From<&QuadDouble> for QuadDouble {
+	#[inline]
+	fn from(value: &QuadDouble) -> Self {
+		*value
+	}
+}
+
+impl From<f64> for QuadDouble {
+	#[inline]
+	fn from(value: f64) -> Self {
+		Self(value, 0.0, 0.0, 0.0)
+	}
+}
+
+impl From<DoubleDouble> for QuadDouble {
+	#[inline]
+	fn from(value: DoubleDouble) -> Self {
+		Self(value.0, value.1, 0.0, 0.0)
+	}
+}
+
+/// Rounds to the nearest DoubleDouble
+impl From<QuadDouble> for DoubleDouble {
+	#[inline]
+	fn from(value: QuadDouble) -> Self {
+		DoubleDouble::new(value.0, value.1 + value.2)
+	}
+}
+
+/// Rounds to the nearest f64, which is the first term
+impl From<QuadDouble> for f64 {
+	#[inline]
+	fn from(value: QuadDouble) -> Self {
+		value.0
+	}
+}
+
+/// Returns the larger of the next terms of a and b, advancing past it
+fn next_term(a: &[f64; 4], b: &[f64; 4], i: &mut usize, j: &mut usize) -> f64 {
+	if *j >= 4 || (*i < 4 && a[*i].abs() > b[*j].abs()) {
+		*i += 1;
+		a[*i - 1]
+	} else {
+		*j += 1;
+		b[*j - 1]
+	}
+}
+
+/// Returns the quad-double sum x + y
+///
+/// The terms of both are merged by size and accumulated, so the sum is accurate even when x and
+/// y cancel
+fn add(x: QuadDouble, y: QuadDouble) -> QuadDouble {
+	let (a, b) = (x.terms(), y.terms());
+	let (mut i, mut j) = (0, 0);
+	let u = next_term(&a, &b, &mut i, &mut j);
+	let v = next_term(&a, &b, &mut i, &mut j);
+	let (mut u, mut v) = quick_two_sum(u, v);
+
+	let mut s = [0.0; 4];
+	let mut k = 0;
+	while k < 4 {
+		if i >= 4 && j >= 4 {
+			s[k] = u;
+			if k < 3 {
+				s[k + 1] = v;
+			}
+			break;
+		}
+		let t = next_term(&a, &b, &mut i, &mut j);
+		if let Some(top) = three_accumulate(&mut u, &mut v, t) {
+			s[k] = top;
+			k += 1;
+		}
+	}
+	// Terms left over once the result is full are below its precision, so they are only added
+	// into the last one
+	for &t in a[i..].iter().chain(&b[j..]) {
+		s[3] += t;
+	}
+	renormalise((s[0], s[1], s[2], s[3], 0.0)).into()
+}
+
+/// Returns the quad-double product x y
+///
+/// Products of terms below 2^-212 of the result are dropped, and those near it are only summed
+/// in f64
+fn mul(x: QuadDouble, y: QuadDouble) -> QuadDouble {
+	let (a, b) = (x.terms(), y.terms());
+	let (p0, q0) = two_prod(a[0], b[0]);
+	let (p1, q1) = two_prod(a[0], b[1]);
+	let (p2, q2) = two_prod(a[1], b[0]);
+	let (p3, q3) = two_prod(a[0], b[2]);
+	let (p4, q4) = two_prod(a[1], b[1]);
+	let (p5, q5) = two_prod(a[2], b[0]);
+
+	// The terms of order 2^-53
+	let (p1, p2, q0) = three_sum(p1, p2, q0);
+
+	// The terms of order 2^-106
+	let (p2, q1, q2) = three_sum(p2, q1, q2);
+	let (p3, p4, p5) = three_sum(p3, p4, p5);
+	let (s0, t0) = two_sum(p2, p3);
+	let (s1, t1) = two_sum(q1, p4);
+	let s2 = q2 + p5;
+	let (s1, t0) = two_sum(s1, t0);
+	let s2 = s2 + (t0 + t1);
+
+	// The terms of order 2^-159
+	let s1 = s1 + (a[0] * b[3] + a[1] * b[2] + a[2] * b[1] + a[3] * b[0] + q0 + q3 + q4 + q5);
+
+	renormalise((p0, p1, s0, s1, s2)).into()
+}
+
+/// Returns the quad-double quotient x / y by long division, one f64 digit at a time
+fn div(x: QuadDouble, y: QuadDouble) -> QuadDouble {
+	let mut r = x;
+	let mut q = [0.0; 5];
+	for qi in &mut q {
+		*qi = r.0 / y.0;
+		r -= y * QuadDouble::from(*qi);
+	}
+	renormalise((q[0], q[1], q[2], q[3], q[4])).into()
+}
+
+/// Returns the quad-double difference x - y
+fn sub(x: QuadDouble, y: QuadDouble) -> QuadDouble {
+	add(x, -y)
+}
+
+impl_op_vers!(QuadDouble, add, Add, add);
+impl_op_vers!(QuadDouble, sub, Sub, sub);
+impl_op_vers!(QuadDouble, mul, Mul, mul);
+impl_op_vers!(QuadDouble, div, Div, div);
+
+impl_op_assign_vers!(QuadDouble, add_assign, AddAssign, add);
+impl_op_assign_vers!(QuadDouble, sub_assign, SubAssign, sub);
+impl_op_assign_vers!(QuadDouble, mul_assign, MulAssign, mul);
+impl_op_assign_vers!(QuadDouble, div_assign, DivAssign, div);
+
+impl Neg for QuadDouble {
+	type Output = QuadDouble;
+
+	#[inline]
+	fn neg(self) -> Self::Output {
+		QuadDouble(-self.0, -self.1, -self.2, -self.3)
+	}
+}
+
+impl Neg for &QuadDouble {
+	type Output = QuadDouble;
+
+	#[inline]
+	fn neg(self) -> Self::Output {
+		-*self
+	}
+}
+
+impl Zero for QuadDouble {
+	#[inline]
+	fn zero() -> Self {
+		QuadDouble(0.0, 0.0, 0.0, 0.0)
+	}
+
+	#[inline]
+	fn is_zero(&self) -> bool {
+		self.0 == 0.0
+	}
+}
+
+impl One for QuadDouble {
+	#[inline]
+	fn one() -> Self {
+		QuadDouble(1.0, 0.0, 0.0, 0.0)
+	}
+}
+
+macro_rules! impl_unary {
+	($bound:ident,$fn:ident) => {
+		impl $bound for QuadDouble {
+			#[inline]
+			fn $fn(self) -> Self {
+				QuadDouble::$fn(self)
+			}
+		}
+	};
+}
+
+macro_rules! impl_ref_unary {
+	($bound:ident,$fn:ident) => {
+		impl $bound for QuadDouble {
+			#[inline]
+			fn $fn(&self) -> Self {
+				QuadDouble::$fn(*self)
+			}
+		}
+	};
+}
+
+impl_unary!(Abs, abs);
+impl_unary!(Exp, exp);
+impl_unary!(Ln, ln);
+impl_unary!(Log10, log10);
+
+impl_ref_unary!(SquareRoot, sqrt);
+impl_ref_unary!(Sin, sin);
+impl_ref_unary!(Cos, cos);
+impl_ref_unary!(Tan, tan);
+impl_ref_unary!(Asin, asin);
+impl_ref_unary!(Acos, acos);
+impl_ref_unary!(Atan, atan);
+impl_ref_unary!(Sinh, sinh);
+impl_ref_unary!(Cosh, cosh);
+impl_ref_unary!(Tanh, tanh);
+
+impl Atan2 for QuadDouble {
+	#[inline]
+	fn atan2(&self, other: &Self) -> Self {
+		QuadDouble::atan2(*self, *other)
+	}
+}
+
+impl SinCos for QuadDouble {
+	#[inline]
+	fn sin_cos(&self) -> (Self, Self) {
+		QuadDouble::sin_cos(*self)
+	}
+}
+
+impl Pow for QuadDouble {
+	#[inline]
+	fn pow(&self, n: &Self) -> Self {
+		QuadDouble::pow(*self, *n)
+	}
+
+	#[inline]
+	fn powi(&self, n: usize) -> Self {
+		self.powu(n)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	// The operators are tested on references too
+	#![allow(clippy::op_ref)]
+
+	use num_traits::{One, Zero};
+	use orml_traits::fns::abs::Abs;
+	use orml_traits::fns::exp::{Exp, ExpM1};
+	use orml_traits::fns::log::{Ln, Ln1P};
+	use orml_vector::Vector;
+	use proptest::prelude::*;
+
+	use crate::quad::QuadDouble;
+	use crate::test_util::{args, close, exact, within, BF};
+	use crate::DoubleDouble;
+
+	fn qd() -> impl Strategy<Value = QuadDouble> {
+		(-1e6..1e6f64, -1.0..1.0f64, -1.0..1.0f64, -1.0..1.0f64).prop_map(|(a, b, c, d)| {
+			QuadDouble::new(a, a * b * 1e-17, a * c * 1e-34, a * d * 1e-51)
+		})
+	}
+
+	/// x + x / 21 to full precision, so every term of an argument is used
+	fn arg(x: f64) -> QuadDouble {
+		let third = QuadDouble::one() / QuadDouble::from(3.0);
+		QuadDouble::from(x) + third * QuadDouble::from(x / 7.0)
+	}
+
+	#[test]
+	fn operators() {
+		let a = QuadDouble::from(3.0);
+		let b = QuadDouble::from(0.5);
+		assert_eq!(a + b, QuadDouble::from(3.5));
+		assert_eq!(&a - &b, QuadDouble::from(2.5));
+		assert_eq!(a * &b, QuadDouble::from(1.5));
+		assert_eq!(&a / b, QuadDouble::from(6.0));
+		assert_eq!(-a, QuadDouble::from(-3.0));
+
+		let mut c = a;
+		c += b;
+		c -= &a;
+		c *= QuadDouble::from(4.0);
+		c /= &b;
+		assert_eq!(c, QuadDouble::from(4.0));
+
+		assert!(QuadDouble::zero().is_zero());
+		assert_eq!(QuadDouble::from(-2.0).abs(), QuadDouble::from(2.0));
+		assert_eq!(QuadDouble::from(2.0).powi(-2), QuadDouble::from(0.25));
+		assert_eq!(QuadDouble::from(16.0).sqrt(), QuadDouble::from(4.0));
+		assert!(QuadDouble::from(-1.0).sqrt().terms()[0].is_nan());
+
+		let dd = DoubleDouble::one() / DoubleDouble::from(3.0);
+		assert_eq!(DoubleDouble::from(QuadDouble::from(dd)), dd);
+	}
+
+	#[test]
+	fn third() {
+		// 1/3 to 212 bits, and back
+		let third = QuadDouble::one() / QuadDouble::from(3.0);
+		let expected = BF::from(1.0) / BF::from(3.0);
+		assert!(close(third, expected, 210));
+		assert!(third.terms().iter().all(|&t| t != 0.0));
+		let one = third * QuadDouble::from(3.0);
+		assert!((one - QuadDouble::one()).abs() < QuadDouble::from(1e-63));
+	}
+
+	#[test]
+	fn cancellation() {
+		// Only the last terms are left, and nothing is lost of them
+		let a = QuadDouble::new(1.0, 1e-17, 1e-34, 1e-51);
+		let b = QuadDouble::new(-1.0, -1e-17, -1e-34, 0.0);
+		let expected = exact(a) + exact(b);
+		assert!(close(a + b, expected, 200));
+	}
+
+	#[test]
+	fn constants() {
+		let pi = BF::from(-1.0).acos();
+		assert!(close(QuadDouble::PI, pi.clone(), 212));
+		assert!(close(QuadDouble::TAU, pi.clone() * BF::from(2.0), 212));
+		assert!(close(
+			QuadDouble::FRAC_PI_2,
+			pi.clone() / BF::from(2.0),
+			212
+		));
+		assert!(close(QuadDouble::FRAC_PI_4, pi / BF::from(4.0), 212));
+		assert!(close(QuadDouble::E, BF::from(1.0).exp(), 212));
+		assert!(close(QuadDouble::LN_2, BF::from(2.0).ln(), 212));
+		assert!(close(QuadDouble::LN_10, BF::from(10.0).ln(), 212));
+	}
+
+	#[test]
+	fn exp_ln() {
+		// 2^-200 is 60 significant digits
+		for x in args::<QuadDouble>(1.0 / 7.0) {
+			let e = exact(x);
+			assert!(close(x.exp(), e.clone().exp(), 200), "{x:?}");
+			assert!(close(x.exp_m1(), e.clone().exp_m1(), 200), "{x:?}");
+			let a = x.abs();
+			assert!(close(a.ln(), exact(a).ln(), 200), "{x:?}");
+			if x.terms()[0] > -1.0 {
+				assert!(close(x.ln_1p(), e.ln_1p(), 200), "{x:?}");
+			}
+		}
+		assert!(close(
+			QuadDouble::from(600.0).exp(),
+			BF::from(600.0).exp(),
+			200
+		));
+		let x = QuadDouble::new(1.0, 1e-40, 0.0, 0.0);
+		assert!(close(x.ln(), exact(x).ln(), 200));
+		assert_eq!(QuadDouble::zero().exp(), QuadDouble::one());
+		assert_eq!(QuadDouble::one().ln(), QuadDouble::zero());
+
+		let x = arg(1.5);
+		let n = arg(-2.25);
+		assert!(close(x.pow(n), exact(x).pow(&exact(n)), 200));
+		let hundred = QuadDouble::from(100.0).log10();
+		assert!((hundred - QuadDouble::from(2.0)).abs() < QuadDouble::from(1e-62));
+	}
+
+	#[test]
+	fn trig() {
+		for x in args::<QuadDouble>(1.0 / 7.0) {
+			let e = exact(x);
+			let (s, c) = x.sin_cos();
+			assert!(close(s, e.sin(), 198), "{x:?}");
+			assert!(close(c, e.cos(), 198), "{x:?}");
+			assert!(close(x.tan(), e.tan(), 198), "{x:?}");
+			assert!(close(x.atan(), e.atan(), 200), "{x:?}");
+			if x.abs() <= QuadDouble::one() {
+				assert!(close(x.asin(), e.asin(), 200), "{x:?}");
+				assert!(close(x.acos(), e.acos(), 200), "{x:?}");
+			}
+			assert!(close(x.sinh(), e.sinh(), 200), "{x:?}");
+			assert!(close(x.cosh(), e.cosh(), 200), "{x:?}");
+			assert!(close(x.tanh(), e.tanh(), 200), "{x:?}");
+		}
+		// Large enough that every bit of the argument is needed, with the low terms of 1e10 + 1/3
+		// held exactly by BF
+		let shifted = QuadDouble::from(1e10) + QuadDouble::one() / QuadDouble::from(3.0);
+		let large = [1e10, -1e18, 1e22, 2f64.powi(600), -1e300, f64::MAX].map(QuadDouble::from);
+		for x in large.into_iter().chain([shifted]) {
+			let (s, c) = x.sin_cos();
+			assert!(close(s, exact(x).sin(), 198), "{x:?}");
+			assert!(close(c, exact(x).cos(), 198), "{x:?}");
+		}
+		for (y, x) in [
+			(1.0, 2.0),
+			(3.0, -0.5),
+			(-1.0, -1.0),
+			(1.0, 0.0),
+			(0.0, -1.0),
+		] {
+			let a = QuadDouble::from(y).atan2(QuadDouble::from(x));
+			assert!(close(a, BF::from(y).atan2(&BF::from(x)), 200), "{y} {x}");
+		}
+	}
+
+	#[test]
+	fn generic() {
+		let v = Vector::from_array([3.0, 4.0, 12.0].map(QuadDouble::from));
+		assert_eq!(Vector::<QuadDouble, 3>::length(&v), QuadDouble::from(13.0));
+	}
+
+	proptest! {
+		#[test]
+		fn add_sub(a in qd(), b in qd()) {
+			let (ea, eb) = (exact(a), exact(b));
+			let scale = ea.clone().abs() + eb.clone().abs();
+			prop_assert!(within(a + b, ea.clone() + eb.clone(), scale.clone(), 210));
+			prop_assert!(within(a - b, ea - eb, scale, 210));
+		}
+
+		#[test]
+		fn mul_div(a in qd(), b in qd()) {
+			prop_assume!(b.terms()[0] != 0.0);
+			let (ea, eb) = (exact(a), exact(b));
+			let product = ea.clone() * eb.clone();
+			prop_assert!(within(a * b, product.clone(), product, 208));
+			let quotient = ea / eb;
+			prop_assert!(within(a / b, quotient.clone(), quotient, 208));
+		}
+
+		#[test]
+		fn square_root(a in qd()) {
+			let a = a.abs();
+			let root = exact(a).sqrt();
+			prop_assert!(within(a.sqrt(), root.clone(), root, 208));
+		}
+	}
+}
@@ -0,0 +1,73 @@
+//! This module contains the helpers the tests share for checking results against EasyBigFloat
+
+use std::ops::{Add, Div, Mul, Neg};
+
+use num_traits::One;
+use orml_easy_big_float::EasyBigFloat;
+use orml_traits::fns::abs::Abs;
+
+use crate::quad::QuadDouble;
+use crate::DoubleDouble;
+
+/// The reference float, which holds every DoubleDouble and the QuadDoubles of moderate size
+/// exactly
+pub type BF = EasyBigFloat<256>;
+
+/// A float that is the unevaluated sum of its terms
+pub trait Terms: Copy {
+	/// Returns the terms, largest first
+	fn to_terms(self) -> Vec<f64>;
+}
+
+impl Terms for DoubleDouble {
+	fn to_terms(self) -> Vec<f64> {
+		vec![self.hi(), self.lo()]
+	}
+}
+
+impl Terms for QuadDouble {
+	fn to_terms(self) -> Vec<f64> {
+		self.terms().to_vec()
+	}
+}
+
+/// Returns the exact value of x
+pub fn exact<T: Terms>(x: T) -> BF {
+	x.to_terms()
+		.into_iter()
+		.map(BF::from)
+		.fold(BF::from(0.0), |s, t| s + t)
+}
+
+/// Whether x is within 2^-bits of expected, relative to scale
+pub fn within<T: Terms>(x: T, expected: BF, scale: BF, bits: i32) -> bool {
+	let err = (exact(x) - expected).abs();
+	err <= scale.abs() * BF::from(2f64.powi(-bits))
+}
+
+/// Whether x is within 2^-bits of expected, relative to expected
+pub fn close<T: Terms>(x: T, expected: BF, bits: i32) -> bool {
+	within(x, expected.clone(), expected, bits)
+}
+
+/// Arguments from 1e-20 to 123.456 of both signs, each plus a third of x times low, so that the
+/// terms below the first are used too
+pub fn args<T>(low: f64) -> Vec<T>
+where
+	T: Terms
+		+ From<f64>
+		+ One
+		+ Add<Output = T>
+		+ Mul<Output = T>
+		+ Div<Output = T>
+		+ Neg<Output = T>,
+{
+	let third = T::one() / T::from(3.0);
+	[1e-20, 1e-5, 0.1, 0.5, 0.75, 1.0, 2.5, 10.0, 123.456]
+		.into_iter()
+		.flat_map(|x| {
+			let x = T::from(x) + third * T::from(x * low);
+			[x, -x]
+		})
+		.collect()
+}
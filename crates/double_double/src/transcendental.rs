@@ -0,0 +1,655 @@
+//! This module contains the elementary functions of DoubleDoubles
+//!
+//! Each function reduces its argument with the double-double constants below to a small range,
+//! where a Taylor series converges in a few terms, then undoes the reduction. The results are
+//! accurate to a few ulps of the low part for arguments whose reduction doesn't cancel
+
+use num_traits::{One, Zero};
+
+use crate::{mul12, DoubleDouble};
+
+/// ln(2) as the sum of three f64, the first of 32 bits so its integer multiples are exact
+const LN_2_PARTS: [f64; 3] = [
+	0.6931471803691238,
+	1.9082149292705877e-10,
+	1.1612227229362532e-26,
+];
+
+/// The bits of 2/π in 64 bit words, most significant first, far enough past 2^-1024 that the
+/// remainder of any f64 is found to [`REM_WORDS`] words
+const TWO_OVER_PI: [u64; 26] = [
+	0xA2F9836E4E441529,
+	0xFC2757D1F534DDC0,
+	0xDB6295993C439041,
+	0xFE5163ABDEBBC561,
+	0xB7246E3A424DD2E0,
+	0x06492EEA09D1921C,
+	0xFE1DEB1CB129A73E,
+	0xE88235F52EBB4484,
+	0xE99C7026B45F7E41,
+	0x3991D639835339F4,
+	0x9C845F8BBDF9283B,
+	0x1FF897FFDE05980F,
+	0xEF2F118B5A0A6D1F,
+	0x6D367ECF27CB09B7,
+	0x4F463F669E5FEA2D,
+	0x7527BAC7EBE5F17B,
+	0x3D0739F78A5292EA,
+	0x6BFB5FB11F8D5D08,
+	0x56033046FC7B6BAB,
+	0xF0CFBC209AF4361D,
+	0xA9E391615EE61B08,
+	0x6599855F14A06840,
+	0x8DFFD8804D732731,
+	0x06061556CA73A8C9,
+	0x60E27BC08C6B47C4,
+	0x19C367CDDCE8092A,
+];
+
+/// The words of the fixed point remainder x (2/π) mod 4, whose top two bits are its integer part
+const REM_WORDS: usize = 6;
+
+/// Terms below this fraction of the sum no longer change it
+const EPS: f64 = 1.0 / (1u128 << 110) as f64;
+
+/// The reduced argument of exp is halved this many times before its series is summed
+const EXP_HALVINGS: i32 = 9;
+
+/// Returns the 64 bits of 2/π starting from the one worth 2^-start
+fn two_over_pi_bits(start: i32) -> u64 {
+	// 2/π < 1, so the bits before the first are zero
+	let offset = start - 1;
+	if offset <= -64 {
+		return 0;
+	}
+	if offset < 0 {
+		return TWO_OVER_PI[0] >> -offset;
+	}
+	let word = |i: usize| TWO_OVER_PI.get(i).copied().unwrap_or(0);
+	let (i, shift) = ((offset / 64) as usize, offset % 64);
+	if shift == 0 {
+		word(i)
+	} else {
+		(word(i) << shift) | (word(i + 1) >> (64 - shift))
+	}
+}
+
+/// Adds x (2/π) mod 4 to the fixed point sum z
+///
+/// With x = m 2^e for an integer m, the bits of 2/π worth more than 2^(1 - e) only add multiples
+/// of 4, so the product only needs the bits after them. This is Payne and Hanek's reduction
+fn add_rem_frac_pi_2(z: &mut [u64; REM_WORDS], x: f64) {
+	if x == 0.0 {
+		return;
+	}
+	let bits = x.to_bits();
+	let exponent = ((bits >> 52) & 0x7ff) as i32;
+	let m = bits & ((1 << 52) - 1);
+	let (m, e) = if exponent == 0 {
+		(m, -1074)
+	} else {
+		(m | 1 << 52, exponent - 1075)
+	};
+
+	// m times the bits from 2^(1 - e), of which the top word is a multiple of 4 and the last
+	// two words are below the precision of z
+	let mut product = [0u64; REM_WORDS + 2];
+	let mut carry = 0u128;
+	for (k, word) in product.iter_mut().enumerate().rev() {
+		let t = u128::from(m) * u128::from(two_over_pi_bits(e - 1 + 64 * k as i32)) + carry;
+		*word = t as u64;
+		carry = t >> 64;
+	}
+
+	let mut carry = false;
+	for (zk, &pk) in z.iter_mut().zip(&product[..REM_WORDS]).rev() {
+		let (sum, c1, c2) = if x > 0.0 {
+			let (sum, c1) = zk.overflowing_add(pk);
+			let (sum, c2) = sum.overflowing_add(u64::from(carry));
+			(sum, c1, c2)
+		} else {
+			let (sum, c1) = zk.overflowing_sub(pk);
+			let (sum, c2) = sum.overflowing_sub(u64::from(carry));
+			(sum, c1, c2)
+		};
+		*zk = sum;
+		carry = c1 || c2;
+	}
+}
+
+/// Returns n mod 4 and the terms of x (2/π) - n, largest first, for the integer n nearest to
+/// x (2/π), where x is the sum of terms
+///
+/// The remainder is found from 2/π to 1664 bits, so it keeps its relative precision for any
+/// argument, and is in [-1/2, 1/2)
+pub(crate) fn rem_frac_pi_2(terms: &[f64]) -> (u8, [f64; 4]) {
+	let mut z = [0; REM_WORDS];
+	for &x in terms {
+		add_rem_frac_pi_2(&mut z, x);
+	}
+	// The nearest integer is the integer part after adding a half
+	let n = (z[0].wrapping_add(1 << 61) >> 62) as u8;
+	z[0] = z[0].wrapping_sub(u64::from(n) << 62);
+
+	let negative = z[0] >> 63 == 1;
+	if negative {
+		let mut carry = true;
+		for zk in z.iter_mut().rev() {
+			(*zk, carry) = (!*zk).overflowing_add(u64::from(carry));
+		}
+	}
+
+	// The remainder is z 2^(2 - 64 REM_WORDS), read off in 53 bit terms from its first set bit
+	let Some(first) = z.iter().position(|&zk| zk != 0) else {
+		return (n, [0.0; 4]);
+	};
+	let lead = 64 * first as i32 + z[first].leading_zeros() as i32;
+	let word = |i: usize| z.get(i).copied().map_or(0, u128::from);
+	let mut rem = [0.0; 4];
+	for (k, r) in rem.iter_mut().enumerate() {
+		let start = lead + 53 * k as i32;
+		let (i, shift) = ((start / 64) as usize, start % 64);
+		let window = (word(i) << 64) | word(i + 1);
+		let bits = ((window << shift) >> 75) as u64;
+		let term = bits as f64 * 2f64.powi(2 - start - 53);
+		*r = if negative { -term } else { term };
+	}
+	(n, rem)
+}
+
+impl DoubleDouble {
+	/// Archimedes' constant π
+	pub const PI: Self = DoubleDouble(std::f64::consts::PI, 1.2246467991473532e-16);
+	/// The full circle constant τ = 2π
+	pub const TAU: Self = DoubleDouble(std::f64::consts::TAU, 2.4492935982947064e-16);
+	/// π/2
+	pub const FRAC_PI_2: Self = DoubleDouble(std::f64::consts::FRAC_PI_2, 6.123233995736766e-17);
+	/// π/4
+	pub const FRAC_PI_4: Self = DoubleDouble(std::f64::consts::FRAC_PI_4, 3.061616997868383e-17);
+	/// Euler's number e
+	pub const E: Self = DoubleDouble(std::f64::consts::E, 1.4456468917292502e-16);
+	/// ln(2)
+	pub const LN_2: Self = DoubleDouble(std::f64::consts::LN_2, 2.3190468138462996e-17);
+	/// ln(10)
+	pub const LN_10: Self = DoubleDouble(std::f64::consts::LN_10, -2.1707562233822494e-16);
+
+	/// Returns self * 2^n, which is exact unless the result overflows or is subnormal
+	fn ldexp(self, n: i32) -> Self {
+		// Two steps keep each power of two in range
+		let a = 2f64.powi(n / 2);
+		let b = 2f64.powi(n - n / 2);
+		DoubleDouble(self.0 * a * b, self.1 * a * b)
+	}
+
+	/// Returns self - n c, where c is split into parts like [`LN_2_PARTS`]
+	///
+	/// The product of n with the first part is exact for integers below 2^21, so the cancellation
+	/// that leaves a small result is too
+	fn reduce(self, n: f64, c: [f64; 3]) -> Self {
+		let t = self - DoubleDouble::from(c[0] * n);
+		let t = t - DoubleDouble::from(mul12(c[1], n));
+		t - DoubleDouble::from(c[2] * n)
+	}
+
+	/// Returns e^r - 1 for |r| <= ln(2) / 2
+	fn exp_m1_reduced(self) -> Self {
+		if self.is_zero() {
+			return self;
+		}
+		// e^r - 1 is summed for r / 2^k, then doubled back k times with e^2x - 1 = y (y + 2)
+		let r = self.ldexp(-EXP_HALVINGS);
+		let mut term = r;
+		let mut sum = r;
+		let mut n = 1.0;
+		while term.0.abs() > EPS * sum.0.abs() {
+			n += 1.0;
+			term = term * r / DoubleDouble::from(n);
+			sum += term;
+		}
+		for _ in 0..EXP_HALVINGS {
+			sum = sum * (sum + DoubleDouble::from(2.0));
+		}
+		sum
+	}
+
+	/// Returns e^self
+	pub fn exp(self) -> Self {
+		if self.0.is_nan() {
+			return self;
+		}
+		if self.0 > 709.8 {
+			return DoubleDouble::from(f64::INFINITY);
+		}
+		if self.0 < -745.2 {
+			return DoubleDouble::zero();
+		}
+		let m = (self.0 / std::f64::consts::LN_2).round();
+		let r = self.reduce(m, LN_2_PARTS);
+		(r.exp_m1_reduced() + DoubleDouble::one()).ldexp(m as i32)
+	}
+
+	/// Returns e^self - 1, which keeps its relative precision for small arguments
+	pub fn exp_m1(self) -> Self {
+		if self.0.abs() <= std::f64::consts::LN_2 / 2.0 {
+			self.exp_m1_reduced()
+		} else {
+			self.exp() - DoubleDouble::one()
+		}
+	}
+
+	/// Returns 2^self, exact for integers
+	pub fn exp2(self) -> Self {
+		if !self.0.is_finite() {
+			return DoubleDouble::from(self.0.exp2());
+		}
+		let n = self.0.round();
+		if n > 1024.0 {
+			return DoubleDouble::from(f64::INFINITY);
+		}
+		if n < -1100.0 {
+			return DoubleDouble::zero();
+		}
+		let f = self - DoubleDouble::from(n);
+		(f * Self::LN_2).exp().ldexp(n as i32)
+	}
+
+	/// Splits a finite positive DoubleDouble into k and ln(m), where self = m 2^k and m is
+	/// within a factor of √2 of 1
+	fn ln_parts(self) -> (f64, Self) {
+		let k = self.0.log2().round();
+		let m = self.ldexp(-(k as i32));
+		// m - 1 is exact, so ln(m) keeps its precision when m is close to 1
+		(k, (m - DoubleDouble::one()).ln_1p())
+	}
+
+	/// Returns the non-finite or NaN result of a logarithm, or None for finite positive arguments
+	fn ln_special(self) -> Option<Self> {
+		if self.0.is_nan() || self.0 < 0.0 {
+			Some(DoubleDouble::from(f64::NAN))
+		} else if self.0 == 0.0 {
+			Some(DoubleDouble::from(f64::NEG_INFINITY))
+		} else if self.0 == f64::INFINITY {
+			Some(self)
+		} else {
+			None
+		}
+	}
+
+	/// Returns the natural logarithm, NaN for negative numbers
+	pub fn ln(self) -> Self {
+		if let Some(special) = self.ln_special() {
+			return special;
+		}
+		let (k, ln_m) = self.ln_parts();
+		ln_m + Self::LN_2 * DoubleDouble::from(k)
+	}
+
+	/// Returns ln(1 + self), which keeps its relative precision for small arguments
+	pub fn ln_1p(self) -> Self {
+		if self.0.abs() >= 0.5 || self.0.is_nan() {
+			return (DoubleDouble::one() + self).ln();
+		}
+		// One Newton step on e^y - 1 = x from the f64 logarithm doubles its precision
+		let y = DoubleDouble::from(self.0.ln_1p());
+		let e = y.exp_m1();
+		y - (e - self) / (e + DoubleDouble::one())
+	}
+
+	/// Returns the base 2 logarithm, exact for powers of two
+	pub fn log2(self) -> Self {
+		if let Some(special) = self.ln_special() {
+			return special;
+		}
+		let (k, ln_m) = self.ln_parts();
+		DoubleDouble::from(k) + ln_m / Self::LN_2
+	}
+
+	/// Returns the base 10 logarithm
+	pub fn log10(self) -> Self {
+		self.ln() / Self::LN_10
+	}
+
+	/// Returns self raised to the power n
+	///
+	/// Integer powers are found by repeated squaring, so negative numbers have them too. Other
+	/// powers are e^(n ln self), NaN for negative numbers
+	pub fn pow(self, n: Self) -> Self {
+		if n.1 == 0.0 && n.0.fract() == 0.0 && n.0.abs() < i32::MAX as f64 {
+			return self.powi(n.0 as i32);
+		}
+		if self.is_zero() && n.0 > 0.0 {
+			return DoubleDouble::zero();
+		}
+		(n * self.ln()).exp()
+	}
+
+	/// Returns the Taylor series of sin for start = t and cos for start = 1, given t^2
+	fn trig_series(start: Self, n: f64, t2: Self) -> Self {
+		let mut term = start;
+		let mut sum = start;
+		let mut n = n;
+		while term.0.abs() > EPS * sum.0.abs() {
+			term = -(term * t2 / DoubleDouble::from((n + 1.0) * (n + 2.0)));
+			n += 2.0;
+			sum += term;
+		}
+		sum
+	}
+
+	/// Returns the sine and cosine
+	///
+	/// The argument is reduced by a multiple of π/2 with [`rem_frac_pi_2`], which is exact enough
+	/// for any argument
+	pub fn sin_cos(self) -> (Self, Self) {
+		if !self.0.is_finite() {
+			let nan = DoubleDouble::from(f64::NAN);
+			return (nan, nan);
+		}
+		if self.is_zero() {
+			return (self, DoubleDouble::one());
+		}
+		let (q, t) = if self.0.abs() <= std::f64::consts::FRAC_PI_4 {
+			(0, self)
+		} else {
+			let (q, r) = rem_frac_pi_2(&[self.0, self.1]);
+			let r = DoubleDouble::new(r[0], r[1]) + DoubleDouble::from(r[2]);
+			(q, r * Self::FRAC_PI_2)
+		};
+		let t2 = t * t;
+		let s = Self::trig_series(t, 1.0, t2);
+		let c = Self::trig_series(DoubleDouble::one(), 0.0, t2);
+		match q {
+			0 => (s, c),
+			1 => (c, -s),
+			2 => (-s, -c),
+			_ => (-c, s),
+		}
+	}
+
+	/// Returns the sine
+	pub fn sin(self) -> Self {
+		self.sin_cos().0
+	}
+
+	/// Returns the cosine
+	pub fn cos(self) -> Self {
+		self.sin_cos().1
+	}
+
+	/// Returns the tangent
+	pub fn tan(self) -> Self {
+		let (s, c) = self.sin_cos();
+		s / c
+	}
+
+	/// Returns the angle of the point (x, self) from the positive x axis, in [-π, π]
+	pub fn atan2(self, x: Self) -> Self {
+		let y = self;
+		if !y.0.is_finite() || !x.0.is_finite() || (y.is_zero() && x.is_zero()) {
+			return DoubleDouble::from(y.0.atan2(x.0));
+		}
+		if x.is_zero() {
+			return if y.0 > 0.0 {
+				Self::FRAC_PI_2
+			} else {
+				-Self::FRAC_PI_2
+			};
+		}
+		if y.is_zero() {
+			return if x.0 > 0.0 {
+				y
+			} else if y.0.is_sign_negative() {
+				-Self::PI
+			} else {
+				Self::PI
+			};
+		}
+
+		// One Newton step from the f64 angle, on whichever of sin and cos is steeper there
+		let r = (x * x + y * y).sqrt();
+		let (x, y) = (x / r, y / r);
+		let z = DoubleDouble::from(y.0.atan2(x.0));
+		let (s, c) = z.sin_cos();
+		if x.0.abs() > y.0.abs() {
+			z + (y - s) / c
+		} else {
+			z - (x - c) / s
+		}
+	}
+
+	/// Returns the arctangent, in [-π/2, π/2]
+	pub fn atan(self) -> Self {
+		self.atan2(DoubleDouble::one())
+	}
+
+	/// Returns the arcsine, in [-π/2, π/2], NaN outside [-1, 1]
+	pub fn asin(self) -> Self {
+		let one = DoubleDouble::one();
+		// (1 - x)(1 + x) doesn't cancel near |x| = 1 the way 1 - x^2 does
+		let c = ((one - self) * (one + self)).sqrt();
+		self.atan2(c)
+	}
+
+	/// Returns the arccosine, in [0, π], NaN outside [-1, 1]
+	pub fn acos(self) -> Self {
+		let one = DoubleDouble::one();
+		let s = ((one - self) * (one + self)).sqrt();
+		s.atan2(self)
+	}
+
+	/// Returns the hyperbolic sine
+	pub fn sinh(self) -> Self {
+		// e^-a is small for large a, so it is found from e^a - 1 rather than subtracted away
+		let a = self.abs();
+		let e = a.exp_m1();
+		let s = (e + e / (e + DoubleDouble::one())).ldexp(-1);
+		if self.0 < 0.0 {
+			-s
+		} else {
+			s
+		}
+	}
+
+	/// Returns the hyperbolic cosine
+	pub fn cosh(self) -> Self {
+		let e = self.abs().exp();
+		(e + DoubleDouble::one() / e).ldexp(-1)
+	}
+
+	/// Returns the hyperbolic tangent
+	pub fn tanh(self) -> Self {
+		let a = self.abs();
+		let t = if a.0 > 40.0 {
+			// 1 - tanh(a) is below the precision
+			DoubleDouble::one()
+		} else {
+			let e = a.ldexp(1).exp_m1();
+			e / (e + DoubleDouble::from(2.0))
+		};
+		if self.0 < 0.0 {
+			-t
+		} else {
+			t
+		}
+	}
+
+	/// Returns the inverse hyperbolic sine
+	pub fn asinh(self) -> Self {
+		let a = self.abs();
+		let s = if a.0 > 1e150 {
+			// ln(a + sqrt(a^2 + 1)) is ln(2a) once 1 is below the precision of a^2
+			a.ln() + Self::LN_2
+		} else {
+			let a2 = a * a;
+			let root = (DoubleDouble::one() + a2).sqrt();
+			(a + a2 / (DoubleDouble::one() + root)).ln_1p()
+		};
+		if self.0 < 0.0 {
+			-s
+		} else {
+			s
+		}
+	}
+
+	/// Returns the inverse hyperbolic cosine, NaN below 1
+	pub fn acosh(self) -> Self {
+		if self.0 > 1e150 {
+			return self.ln() + Self::LN_2;
+		}
+		// ln(x + sqrt(x^2 - 1)) written in t = x - 1, which is exact near 1
+		let t = self - DoubleDouble::one();
+		(t + (t * (t + DoubleDouble::from(2.0))).sqrt()).ln_1p()
+	}
+
+	/// Returns the inverse hyperbolic tangent, NaN outside [-1, 1]
+	pub fn atanh(self) -> Self {
+		let t = self.ldexp(1) / (DoubleDouble::one() - self);
+		t.ln_1p().ldexp(-1)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use num_traits::{One, Zero};
+	use orml_traits::fns::exp::{Exp, ExpM1};
+	use orml_traits::fns::log::{Ln, Ln1P};
+
+	use crate::test_util::{args, close, exact, BF};
+	use crate::DoubleDouble;
+
+	#[test]
+	fn constants() {
+		let pi = BF::from(-1.0).acos();
+		assert!(close(DoubleDouble::PI, pi.clone(), 106));
+		assert!(close(DoubleDouble::TAU, pi.clone() * BF::from(2.0), 106));
+		assert!(close(
+			DoubleDouble::FRAC_PI_2,
+			pi.clone() / BF::from(2.0),
+			106
+		));
+		assert!(close(DoubleDouble::FRAC_PI_4, pi / BF::from(4.0), 106));
+		assert!(close(DoubleDouble::E, BF::from(1.0).exp(), 106));
+		assert!(close(DoubleDouble::LN_2, BF::from(2.0).ln(), 106));
+		assert!(close(DoubleDouble::LN_10, BF::from(10.0).ln(), 106));
+	}
+
+	#[test]
+	fn exp_ln() {
+		for x in args::<DoubleDouble>(1e-17) {
+			assert!(close(x.exp(), exact(x).exp(), 102), "{x}");
+			assert!(close(x.exp_m1(), exact(x).exp_m1(), 102), "{x}");
+			let a = x.abs();
+			assert!(close(a.ln(), exact(a).ln(), 102), "{x}");
+			if x.hi() > -1.0 {
+				assert!(close(x.ln_1p(), exact(x).ln_1p(), 102), "{x}");
+			}
+		}
+		// Near 1 the logarithm only has the low part to work with
+		let x = DoubleDouble::new(1.0, 1e-20);
+		assert!(close(x.ln(), exact(x).ln(), 102));
+		assert!(close(
+			DoubleDouble::from(700.0).exp(),
+			BF::from(700.0).exp(),
+			102
+		));
+		assert!(close(
+			DoubleDouble::from(-600.0).exp(),
+			BF::from(-600.0).exp(),
+			102
+		));
+
+		assert_eq!(DoubleDouble::zero().exp(), DoubleDouble::one());
+		assert_eq!(DoubleDouble::one().ln(), DoubleDouble::zero());
+		assert_eq!(DoubleDouble::from(1000.0).exp().hi(), f64::INFINITY);
+		assert_eq!(DoubleDouble::from(-1000.0).exp(), DoubleDouble::zero());
+		assert_eq!(DoubleDouble::zero().ln().hi(), f64::NEG_INFINITY);
+		assert!(DoubleDouble::from(-1.0).ln().hi().is_nan());
+		assert_eq!(DoubleDouble::from(10.0).exp2(), DoubleDouble::from(1024.0));
+		assert_eq!(DoubleDouble::from(-3.0).exp2(), DoubleDouble::from(0.125));
+		assert_eq!(DoubleDouble::from(1024.0).log2(), DoubleDouble::from(10.0));
+		let hundred = DoubleDouble::from(100.0).log10();
+		assert!((hundred - DoubleDouble::from(2.0)).abs() < DoubleDouble::from(1e-31));
+	}
+
+	#[test]
+	fn powers() {
+		let x = DoubleDouble::from(1.5) + DoubleDouble::from(1e-18);
+		let e = exact(x);
+		let half = DoubleDouble::from(0.5);
+		assert!(close(x.pow(half), e.sqrt(), 102));
+		let n = DoubleDouble::from(-2.25);
+		assert!(close(x.pow(n), e.pow(&BF::from(-2.25)), 102));
+		assert_eq!(
+			DoubleDouble::from(-2.0).pow(DoubleDouble::from(3.0)),
+			DoubleDouble::from(-8.0)
+		);
+		assert!(DoubleDouble::from(-2.0).pow(half).hi().is_nan());
+		assert_eq!(DoubleDouble::zero().pow(half), DoubleDouble::zero());
+	}
+
+	#[test]
+	fn trig() {
+		for x in args::<DoubleDouble>(1e-17) {
+			let e = exact(x);
+			let (s, c) = x.sin_cos();
+			assert!(close(s, e.sin(), 100), "{x}");
+			assert!(close(c, e.cos(), 100), "{x}");
+			assert!(close(x.tan(), e.tan(), 100), "{x}");
+			assert!(close(x.atan(), e.atan(), 102), "{x}");
+			if x.abs() <= DoubleDouble::one() {
+				assert!(close(x.asin(), e.asin(), 102), "{x}");
+				assert!(close(x.acos(), e.acos(), 102), "{x}");
+			}
+		}
+		// A point in every quadrant, and on each axis
+		for (y, x) in [
+			(1.0, 2.0),
+			(3.0, -0.5),
+			(-1.0, -1.0),
+			(-0.25, 4.0),
+			(1.0, 0.0),
+			(0.0, -1.0),
+		] {
+			let a = DoubleDouble::from(y).atan2(DoubleDouble::from(x));
+			assert!(close(a, BF::from(y).atan2(&BF::from(x)), 102), "{y} {x}");
+		}
+		assert_eq!(DoubleDouble::zero().sin(), DoubleDouble::zero());
+		assert_eq!(DoubleDouble::zero().cos(), DoubleDouble::one());
+		// Far beyond the multiples of π/2 that f64 can reduce, with a low part while BF holds it
+		let third = DoubleDouble::one() / DoubleDouble::from(3.0);
+		let mut large: Vec<_> = [1e8, -1e16, 1e22]
+			.into_iter()
+			.map(|x| DoubleDouble::from(x) + third)
+			.collect();
+		large.extend([1e8, -1e16, 1e22, 2f64.powi(600), -1e300, f64::MAX].map(DoubleDouble::from));
+		for x in large {
+			let (s, c) = x.sin_cos();
+			assert!(close(s, exact(x).sin(), 100), "{x}");
+			assert!(close(c, exact(x).cos(), 100), "{x}");
+		}
+		// The f64 nearest a multiple of π/2, whose remainder is 2^-61 times its size
+		let x = DoubleDouble::from(6381956970095103.0 * 2f64.powi(797));
+		assert!(close(x.cos(), exact(x).cos(), 100));
+		assert!(DoubleDouble::from(1.5).asin().hi().is_nan());
+		assert!(DoubleDouble::from(f64::INFINITY).sin().hi().is_nan());
+	}
+
+	#[test]
+	fn hyperbolic() {
+		for x in args::<DoubleDouble>(1e-17) {
+			let e = exact(x);
+			assert!(close(x.sinh(), e.sinh(), 102), "{x}");
+			assert!(close(x.cosh(), e.cosh(), 102), "{x}");
+			assert!(close(x.tanh(), e.tanh(), 102), "{x}");
+			assert!(close(x.asinh(), e.asinh(), 102), "{x}");
+			if x.hi() > 1.0 {
+				assert!(close(x.acosh(), e.acosh(), 100), "{x}");
+			}
+			if x.hi().abs() < 1.0 {
+				assert!(close(x.atanh(), e.atanh(), 100), "{x}");
+			}
+		}
+		assert_eq!(DoubleDouble::from(50.0).tanh(), DoubleDouble::one());
+		assert!(DoubleDouble::from(0.5).acosh().hi().is_nan());
+	}
+}
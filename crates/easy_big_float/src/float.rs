@@ -0,0 +1,386 @@
+//! This module contains the implementation of [`Float`] for EasyBigFloat
+//!
+//! Comparisons and the min/max family follow f64, so a NaN argument is ignored where f64 would
+//! ignore it
+
+use std::num::FpCategory;
+
+use astro_float::{BigFloat, Word};
+use num_traits::{One, Zero};
+use orml_traits::fns::trig::{Cos, Sin, SinCos, TrigFns};
+use orml_traits::fns::{
+	abs::*, exp::*, float::*, log::*, Ceil, Clamp, CubeRoot, Degrees, DivEuclid, Floor, Fract,
+	Hypot, Max, Min, MulAdd, Recip, RemEuclid, Round, Signed, Trunc,
+};
+use orml_traits::{Float, Ops};
+
+use crate::special::{exponent, round, GUARD_BITS};
+use crate::{EasyBigFloat, CC, RM};
+
+/// Returns x as an i64 if it is an integer below 2^31 in magnitude
+fn to_int(x: &BigFloat) -> Option<i64> {
+	if x.is_zero() {
+		return Some(0);
+	}
+	if !x.is_int() {
+		return None;
+	}
+	let (m, _, s, e, _) = x.as_raw_parts()?;
+	if !(1..32).contains(&e) {
+		return None;
+	}
+	// The mantissa is normalised so the integer is the top e bits of the last word
+	let n = (*m.last()? >> (Word::BITS - e as u32)) as i64;
+	Some(if s.is_negative() { -n } else { n })
+}
+
+macro_rules! impl_unary {
+	($bound:ident,$fn:ident,$x:ident,$body:expr) => {
+		impl<const P: usize> $bound for EasyBigFloat<P> {
+			#[inline]
+			fn $fn(self) -> Self {
+				let $x = &self.val;
+				Self { val: $body }
+			}
+		}
+	};
+}
+
+impl_unary!(Ln, ln, x, x.ln(P, RM, &mut CC!()));
+impl_unary!(Log10, log10, x, x.log10(P, RM, &mut CC!()));
+impl_unary!(Log2, log2, x, x.log2(P, RM, &mut CC!()));
+impl_unary!(Exp, exp, x, x.exp(P, RM, &mut CC!()));
+impl_unary!(Abs, abs, x, x.abs());
+impl_unary!(CubeRoot, cbrt, x, x.cbrt(P, RM));
+impl_unary!(Ceil, ceil, x, x.ceil());
+impl_unary!(Floor, floor, x, x.floor());
+impl_unary!(Fract, fract, x, x.fract());
+impl_unary!(Recip, recip, x, x.reciprocal(P, RM));
+impl_unary!(Trunc, trunc, x, x.int());
+
+impl_unary!(Ln1P, ln_1p, x, {
+	if exponent(x) < -(P as i64) {
+		// ln(1 + x) = x - x^2 / 2 + ... where x^2 is below the precision
+		x.clone()
+	} else {
+		// 1 + x is exact at full precision so only the ln rounds
+		let y = x.add_full_prec(&BigFloat::from_i64(1, P));
+		y.ln(P, RM, &mut CC!())
+	}
+});
+
+impl_unary!(ExpM1, exp_m1, x, {
+	let e = exponent(x);
+	if e < -(P as i64) {
+		x.clone()
+	} else {
+		// e^x - 1 cancels the leading bits of e^x when x is small
+		let p = P + GUARD_BITS + (-e).max(0) as usize;
+		let y = x.exp(p, RM, &mut CC!());
+		round(y.sub(&BigFloat::from_i64(1, p), p, RM), P)
+	}
+});
+
+impl_unary!(Exp2, exp2, x, {
+	let two = BigFloat::from_i64(2, P);
+	match to_int(x) {
+		// pow isn't exact for integers so powers of two are built directly
+		Some(n) if n < 0 => two.powi(n.unsigned_abs() as usize, P, RM).reciprocal(P, RM),
+		Some(n) => two.powi(n as usize, P, RM),
+		None => two.pow(x, P, RM, &mut CC!()),
+	}
+});
+
+impl_unary!(Round, round, x, {
+	// Rounds half away from zero like f64
+	let t = x.int();
+	let f = x.fract().abs();
+	if f.cmp(&BigFloat::from_f64(0.5, P)) >= Some(0) {
+		t.add(&x.signum(), P, RM)
+	} else {
+		t
+	}
+});
+
+impl<const P: usize> Log for EasyBigFloat<P> {
+	#[inline]
+	fn log(self, base: Self) -> Self {
+		Self {
+			val: self.val.log(&base.val, P, RM, &mut CC!()),
+		}
+	}
+}
+
+impl<const P: usize> LogFns for EasyBigFloat<P> {}
+
+impl<const P: usize> ExpFns for EasyBigFloat<P> {}
+
+impl<const P: usize> AbsSub for EasyBigFloat<P> {
+	/// The positive difference max(self - other, 0)
+	#[inline]
+	fn abs_sub(self, other: Self) -> Self {
+		if self <= other {
+			Self::zero()
+		} else {
+			self - other
+		}
+	}
+}
+
+impl<const P: usize> AbsFns for EasyBigFloat<P> {}
+
+impl<const P: usize> Infinite for EasyBigFloat<P> {
+	#[inline]
+	fn is_finite(&self) -> bool {
+		!self.val.is_inf() && !self.val.is_nan()
+	}
+
+	#[inline]
+	fn is_infinite(&self) -> bool {
+		self.val.is_inf()
+	}
+}
+
+impl<const P: usize> Nan for EasyBigFloat<P> {
+	#[inline]
+	fn is_nan(&self) -> bool {
+		self.val.is_nan()
+	}
+}
+
+impl<const P: usize> Normal for EasyBigFloat<P> {
+	#[inline]
+	fn is_normal(&self) -> bool {
+		self.val.classify() == FpCategory::Normal
+	}
+
+	#[inline]
+	fn is_subnormal(&self) -> bool {
+		self.val.is_subnormal()
+	}
+}
+
+impl<const P: usize> Classify for EasyBigFloat<P> {
+	#[inline]
+	fn classify(self) -> FpCategory {
+		self.val.classify()
+	}
+}
+
+impl<const P: usize> FloatFns for EasyBigFloat<P> {}
+
+impl<const P: usize> Clamp for EasyBigFloat<P> {
+	#[inline]
+	fn clamp(self, min: Self, max: Self) -> Self {
+		Self {
+			val: self.val.clamp(&min.val, &max.val),
+		}
+	}
+}
+
+impl<const P: usize> DivEuclid for EasyBigFloat<P> {
+	#[inline]
+	fn div_euclid(self, rhs: Self) -> Self {
+		let q = Trunc::trunc(&self / &rhs);
+		if (&self % &rhs).val.is_negative() {
+			if rhs.val.is_positive() {
+				q - Self::one()
+			} else {
+				q + Self::one()
+			}
+		} else {
+			q
+		}
+	}
+}
+
+impl<const P: usize> RemEuclid for EasyBigFloat<P> {
+	#[inline]
+	fn rem_euclid(self, rhs: Self) -> Self {
+		let r = &self % &rhs;
+		if r.val.is_negative() {
+			r + Abs::abs(rhs)
+		} else {
+			r
+		}
+	}
+}
+
+impl<const P: usize> Hypot for EasyBigFloat<P> {
+	#[inline]
+	fn hypot(self, other: Self) -> Self {
+		// The exponent range is too wide for the squares to overflow
+		(&self * &self + &other * &other).sqrt()
+	}
+}
+
+impl<const P: usize> Signed for EasyBigFloat<P> {
+	#[inline]
+	fn is_sign_positive(&self) -> bool {
+		self.val.is_positive()
+	}
+
+	#[inline]
+	fn is_sign_negative(&self) -> bool {
+		self.val.is_negative()
+	}
+
+	#[inline]
+	fn signum(&self) -> Self {
+		Self {
+			val: self.val.signum(),
+		}
+	}
+
+	#[inline]
+	fn copysign(&self, sign: &Self) -> Self {
+		let abs = self.val.abs();
+		Self {
+			val: if sign.val.is_negative() {
+				abs.neg()
+			} else {
+				abs
+			},
+		}
+	}
+}
+
+impl<const P: usize> Max for EasyBigFloat<P> {
+	#[inline]
+	fn max(self, other: Self) -> Self {
+		if other.val.is_nan() || self >= other {
+			self
+		} else {
+			other
+		}
+	}
+}
+
+impl<const P: usize> Min for EasyBigFloat<P> {
+	#[inline]
+	fn min(self, other: Self) -> Self {
+		if other.val.is_nan() || self <= other {
+			self
+		} else {
+			other
+		}
+	}
+}
+
+impl<const P: usize> MulAdd for EasyBigFloat<P> {
+	/// Computes self * a + b with only one rounding
+	#[inline]
+	fn mul_add(self, a: Self, b: Self) -> Self {
+		Self {
+			val: self.val.mul_full_prec(&a.val).add(&b.val, P, RM),
+		}
+	}
+}
+
+impl<const P: usize> Degrees for EasyBigFloat<P> {
+	#[inline]
+	fn to_degrees(self) -> Self {
+		let pi = CC!().pi(P + GUARD_BITS, RM);
+		let val = self
+			.val
+			.mul(&BigFloat::from_i64(180, P), P + GUARD_BITS, RM);
+		Self {
+			val: round(val.div(&pi, P + GUARD_BITS, RM), P),
+		}
+	}
+
+	#[inline]
+	fn to_radians(self) -> Self {
+		let pi = CC!().pi(P + GUARD_BITS, RM);
+		let val = self.val.mul(&pi, P + GUARD_BITS, RM);
+		Self {
+			val: round(val.div(&BigFloat::from_i64(180, P), P + GUARD_BITS, RM), P),
+		}
+	}
+}
+
+impl<const P: usize> SinCos for EasyBigFloat<P> {
+	#[inline]
+	fn sin_cos(&self) -> (Self, Self) {
+		(Sin::sin(self), Cos::cos(self))
+	}
+}
+
+impl<const P: usize> TrigFns for EasyBigFloat<P> {}
+
+impl<const P: usize> Ops for EasyBigFloat<P> {}
+
+impl<const P: usize> Float for EasyBigFloat<P> {}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	type Ebf = EasyBigFloat<256>;
+
+	fn ebf(f: f64) -> Ebf {
+		Ebf::from_f64(f)
+	}
+
+	fn to_polar<T: Float + Clone>(x: T, y: T) -> (T, T) {
+		(x.clone().hypot(y.clone()), y.atan2(&x))
+	}
+
+	fn from_polar<T: Float + Clone>(r: T, theta: T) -> (T, T) {
+		let (s, c) = theta.sin_cos();
+		(r.clone() * c, r * s)
+	}
+
+	#[test]
+	fn generic_float() {
+		let (r, theta) = to_polar(ebf(3.0), ebf(-4.0));
+		assert_eq!(r, ebf(5.0));
+		let expected = to_polar(3.0f64, -4.0).1;
+		assert!(Abs::abs(theta.clone() - ebf(expected)) < ebf(1e-15));
+		let (x, y) = from_polar(r, theta);
+		assert!(Abs::abs(x - ebf(3.0)) < ebf(1e-70));
+		assert!(Abs::abs(y + ebf(4.0)) < ebf(1e-70));
+	}
+
+	#[test]
+	fn rounding() {
+		assert_eq!(Round::round(ebf(2.5)), ebf(3.0));
+		assert_eq!(Round::round(ebf(-2.5)), ebf(-3.0));
+		assert_eq!(Round::round(ebf(2.4)), ebf(2.0));
+		assert_eq!(Floor::floor(ebf(-2.5)), ebf(-3.0));
+		assert_eq!(Ceil::ceil(ebf(-2.5)), ebf(-2.0));
+		assert_eq!(Trunc::trunc(ebf(-2.5)), ebf(-2.0));
+		assert_eq!(Fract::fract(ebf(-2.5)), ebf(-0.5));
+		assert_eq!(DivEuclid::div_euclid(ebf(-7.0), ebf(4.0)), ebf(-2.0));
+		assert_eq!(RemEuclid::rem_euclid(ebf(-7.0), ebf(4.0)), ebf(1.0));
+		assert_eq!(DivEuclid::div_euclid(ebf(7.0), ebf(-4.0)), ebf(-1.0));
+		assert_eq!(RemEuclid::rem_euclid(ebf(7.0), ebf(-4.0)), ebf(3.0));
+	}
+
+	#[test]
+	fn small_arguments() {
+		// Both keep full relative precision where 1 + x would lose it
+		let x = ebf(1e-30);
+		let rel = |a: Ebf, e: Ebf| Abs::abs((a - e.clone()) / e) < ebf(1e-70);
+		let (x2, x3) = (&x * &x, &x * &x * &x);
+		let exp_m1 = &x + &x2 / ebf(2.0) + &x3 / ebf(6.0);
+		let ln_1p = &x - &x2 / ebf(2.0) + &x3 / ebf(3.0);
+		assert!(rel(ExpM1::exp_m1(x.clone()), exp_m1));
+		assert!(rel(Ln1P::ln_1p(x), ln_1p));
+		assert_eq!(Exp2::exp2(ebf(10.0)), ebf(1024.0));
+		assert_eq!(Exp2::exp2(ebf(-3.0)), ebf(0.125));
+		assert_eq!(Log2::log2(ebf(1024.0)), ebf(10.0));
+	}
+
+	#[test]
+	fn classify() {
+		assert_eq!(Classify::classify(Ebf::zero()), FpCategory::Zero);
+		assert_eq!(Classify::classify(Ebf::one()), FpCategory::Normal);
+		let nan = Ebf::zero() / Ebf::zero();
+		assert!(nan.is_nan());
+		assert_eq!(Max::max(ebf(1.0), nan.clone()), ebf(1.0));
+		assert_eq!(Clamp::clamp(ebf(5.0), ebf(0.0), ebf(1.0)), ebf(1.0));
+		assert!(!(Ebf::one() / Ebf::zero()).is_finite());
+		assert_eq!(ebf(180.0).to_radians().to_degrees(), ebf(180.0));
+	}
+}
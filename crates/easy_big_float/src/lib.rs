@@ -138,16 +138,20 @@ impl_arith_4!(Add, add, EasyBigFloat<P>, RM);
 impl_arith_assign!(
 	AddAssign,
 	add_assign,
-	add_full_prec,
+	add,
 	EasyBigFloat<P>,
-	EasyBigFloat<P>
+	EasyBigFloat<P>,
+	P,
+	RM
 );
 impl_arith_assign!(
 	AddAssign,
 	add_assign,
-	add_full_prec,
+	add,
 	EasyBigFloat<P>,
-	&EasyBigFloat<P>
+	&EasyBigFloat<P>,
+	P,
+	RM
 );
 
 // CLONE
@@ -212,16 +216,20 @@ impl_arith_4!(Mul, mul, EasyBigFloat<P>, RM);
 impl_arith_assign!(
 	MulAssign,
 	mul_assign,
-	mul_full_prec,
+	mul,
+	EasyBigFloat<P>,
 	EasyBigFloat<P>,
-	EasyBigFloat<P>
+	P,
+	RM
 );
 impl_arith_assign!(
 	MulAssign,
 	mul_assign,
-	mul_full_prec,
+	mul,
 	EasyBigFloat<P>,
-	&EasyBigFloat<P>
+	&EasyBigFloat<P>,
+	P,
+	RM
 );
 
 // NEG
@@ -290,16 +298,20 @@ impl_arith_4!(Sub, sub, EasyBigFloat<P>, RM);
 impl_arith_assign!(
 	SubAssign,
 	sub_assign,
-	sub_full_prec,
+	sub,
+	EasyBigFloat<P>,
 	EasyBigFloat<P>,
-	EasyBigFloat<P>
+	P,
+	RM
 );
 impl_arith_assign!(
 	SubAssign,
 	sub_assign,
-	sub_full_prec,
+	sub,
 	EasyBigFloat<P>,
-	&EasyBigFloat<P>
+	&EasyBigFloat<P>,
+	P,
+	RM
 );
 
 impl<const P: usize> Zero for EasyBigFloat<P> {
@@ -346,6 +358,9 @@ macro_rules! CC {
 	};
 }
 
+mod float;
+mod special;
+
 #[inline]
 fn atan2<const P: usize>(y: &EasyBigFloat<P>, x: &EasyBigFloat<P>) -> EasyBigFloat<P> {
 	let zero = EasyBigFloat::zero();
@@ -456,7 +471,7 @@ impl_all_trig!(impl_trig);
 impl<const P: usize> Atan2 for EasyBigFloat<P> {
 	#[inline]
 	fn atan2(&self, other: &Self) -> Self {
-		EasyBigFloat::atan2(other, self)
+		EasyBigFloat::atan2(self, other)
 	}
 }
 
@@ -561,4 +576,35 @@ mod tests {
 		assert_eq!(a, a);
 		assert_eq!(c, a + b);
 	}
+
+	#[test]
+	fn assign_rounds() {
+		// The assign operators round to P like the binary ones instead of keeping every bit
+		let one = EasyBigFloat::<128>::one();
+		let tiny = EasyBigFloat::<128>::from_f64(2f64.powi(-300));
+		let mut sum = one.clone();
+		sum += &tiny;
+		assert_eq!(sum, &one + &tiny);
+		assert_eq!(sum, one);
+		let mut diff = one.clone();
+		diff -= tiny.clone();
+		assert_eq!(diff, &one - &tiny);
+		let third = EasyBigFloat::<128>::one() / EasyBigFloat::<128>::from_f64(3.0);
+		let mut prod = third.clone();
+		prod *= &third;
+		assert_eq!(prod, &third * &third);
+	}
+
+	#[test]
+	fn atan2_quadrants() {
+		// Atan2 follows f64, self is y and other is x
+		for (y, x) in [(1.0, 2.0), (1.0, -2.0), (-1.0, -2.0), (-1.0, 2.0)] {
+			let a = Atan2::atan2(
+				&EasyBigFloat::<256>::from_f64(y),
+				&EasyBigFloat::<256>::from_f64(x),
+			);
+			let e = EasyBigFloat::<256>::from_f64(f64::atan2(y, x));
+			assert!((&a - &e).val.abs() < BigFloat::from_f64(1e-15, 256));
+		}
+	}
 }
@@ -0,0 +1,740 @@
+//! This module contains the special functions for EasyBigFloat
+//!
+//! Every function is evaluated at a working precision with at least 64 guard bits over the
+//! precision of the result, more where an algorithm is known to cancel, then rounded back
+
+use astro_float::{BigFloat, RoundingMode};
+use orml_traits::fns::special::*;
+
+use crate::{EasyBigFloat, CC, RM};
+
+pub(crate) const GUARD_BITS: usize = 64;
+const LN_2: f64 = std::f64::consts::LN_2;
+const LOG2_E: f64 = std::f64::consts::LOG2_E;
+
+#[inline]
+fn int(n: i64, p: usize) -> BigFloat {
+	BigFloat::from_i64(n, p)
+}
+
+#[inline]
+fn pi(p: usize) -> BigFloat {
+	CC.lock().unwrap().pi(p, RM)
+}
+
+#[inline]
+fn exp(x: &BigFloat, p: usize) -> BigFloat {
+	x.exp(p, RM, &mut CC.lock().unwrap())
+}
+
+#[inline]
+fn ln(x: &BigFloat, p: usize) -> BigFloat {
+	x.ln(p, RM, &mut CC.lock().unwrap())
+}
+
+#[inline]
+fn sin(x: &BigFloat, p: usize) -> BigFloat {
+	x.sin(p, RM, &mut CC.lock().unwrap())
+}
+
+#[inline]
+fn cos(x: &BigFloat, p: usize) -> BigFloat {
+	x.cos(p, RM, &mut CC.lock().unwrap())
+}
+
+/// Returns the result rounded to the precision p
+#[inline]
+pub(crate) fn round(x: BigFloat, p: usize) -> BigFloat {
+	let mut x = x;
+	// Only fails for an invalid precision, in which case x is already NaN
+	let _ = x.set_precision(p, RoundingMode::ToEven);
+	x
+}
+
+/// Returns the exponent e of x, with 2^(e-1) <= |x| < 2^e
+#[inline]
+pub(crate) fn exponent(x: &BigFloat) -> i64 {
+	x.exponent().unwrap_or(0) as i64
+}
+
+/// Returns true once term no longer changes sum at the precision p
+#[inline]
+fn negligible(term: &BigFloat, sum: &BigFloat, p: usize) -> bool {
+	term.is_zero() || exponent(term) + (p as i64) < exponent(sum)
+}
+
+/// Returns the number of extra bits needed to cover a magnitude of 2^e
+#[inline]
+fn magnitude_bits(x: &BigFloat) -> usize {
+	exponent(x).max(0) as usize
+}
+
+#[inline]
+fn is_pole(x: &BigFloat) -> bool {
+	!x.is_positive() && x.is_int()
+}
+
+/// Returns true if x is negative and Γ(x) is negative, that is when floor(x) is odd
+fn gamma_is_negative(x: &BigFloat) -> bool {
+	x.is_negative() && !x.floor().div(&int(2, 64), 64, RM).is_int()
+}
+
+/// Returns the minimum argument for the Stirling series at the precision p
+#[inline]
+fn stirling_min(p: usize) -> i64 {
+	(p as f64 * LN_2 / (2.0 * std::f64::consts::PI)).ceil() as i64 + 1
+}
+
+/// Returns [B2, B4, ..., B2n] from the tangent numbers
+///
+/// The tangent numbers are built with only additions and multiplications of positive
+/// values so the rounding errors don't grow, unlike the usual Bernoulli recurrence
+fn bernoulli(n: usize, p: usize) -> Vec<BigFloat> {
+	let mut t = vec![int(1, p); n + 1];
+	for k in 2..=n {
+		t[k] = t[k - 1].mul(&int(k as i64 - 1, p), p, RM);
+	}
+	for k in 2..=n {
+		for j in k..=n {
+			let a = t[j - 1].mul(&int((j - k) as i64, p), p, RM);
+			let b = t[j].mul(&int((j - k + 2) as i64, p), p, RM);
+			t[j] = a.add(&b, p, RM);
+		}
+	}
+	// B2k = (-1)^(k-1) 2k T_k / (4^k (4^k - 1))
+	let mut four_k = int(1, p);
+	let one = int(1, p);
+	(1..=n)
+		.map(|k| {
+			four_k = four_k.mul(&int(4, p), p, RM);
+			let d = four_k.mul(&four_k.sub(&one, p, RM), p, RM);
+			let b = t[k].mul(&int(2 * k as i64, p), p, RM).div(&d, p, RM);
+			if k % 2 == 0 {
+				b.neg()
+			} else {
+				b
+			}
+		})
+		.collect()
+}
+
+/// Returns the Bernoulli numbers needed for the Stirling series at the precision p
+#[inline]
+fn stirling_bernoulli(p: usize) -> Vec<BigFloat> {
+	let n = (std::f64::consts::PI * stirling_min(p) as f64).ceil() as usize + 1;
+	bernoulli(n, p)
+}
+
+/// Returns ln Γ(x) for x >= 1/2
+fn ln_gamma_positive(x: &BigFloat, p: usize) -> BigFloat {
+	let min = int(stirling_min(p), p);
+	let one = int(1, p);
+	let mut z = x.clone();
+	let mut prod = one.clone();
+	while z < min {
+		prod = prod.mul(&z, p, RM);
+		z = z.add(&one, p, RM);
+	}
+
+	// (z - 1/2) ln z - z + ln(2π)/2 + Σ B2k / (2k (2k - 1) z^(2k-1))
+	let half = BigFloat::from_f64(0.5, p);
+	let ln_2pi = ln(&pi(p).mul(&int(2, p), p, RM), p);
+	let mut sum = z
+		.sub(&half, p, RM)
+		.mul(&ln(&z, p), p, RM)
+		.sub(&z, p, RM)
+		.add(&ln_2pi.mul(&half, p, RM), p, RM);
+	let z2 = z.mul(&z, p, RM);
+	let mut zk = z.clone();
+	for (k, b) in stirling_bernoulli(p).iter().enumerate() {
+		let k = k as i64 + 1;
+		let term = b.div(&zk.mul(&int(2 * k * (2 * k - 1), p), p, RM), p, RM);
+		if negligible(&term, &sum, p) {
+			break;
+		}
+		sum = sum.add(&term, p, RM);
+		zk = zk.mul(&z2, p, RM);
+	}
+	sum.sub(&ln(&prod, p), p, RM)
+}
+
+/// Returns ln |Γ(x)|, x must not be a pole
+fn ln_gamma_abs(x: &BigFloat, p: usize) -> BigFloat {
+	let half = BigFloat::from_f64(0.5, p);
+	if *x >= half {
+		return ln_gamma_positive(x, p);
+	}
+	// Reflection, Γ(x)Γ(1-x) = π / sin(πx), with enough bits to reduce πx
+	let ps = p + magnitude_bits(x);
+	let pi_s = pi(ps);
+	let s = sin(&pi_s.mul(x, ps, RM), ps).abs();
+	let one_x = int(1, p).sub(x, p, RM);
+	ln(&pi_s, p)
+		.sub(&ln(&s, p), p, RM)
+		.sub(&ln_gamma_positive(&one_x, p), p, RM)
+}
+
+fn ln_gamma(x: &BigFloat, p: usize) -> BigFloat {
+	if x.is_nan() {
+		return x.clone();
+	}
+	if x.is_inf() || is_pole(x) {
+		return astro_float::INF_POS;
+	}
+	round(ln_gamma_abs(x, p + GUARD_BITS), p)
+}
+
+fn gamma(x: &BigFloat, p: usize) -> BigFloat {
+	if x.is_nan() || x.is_inf_pos() {
+		return x.clone();
+	}
+	if x.is_zero() {
+		return if x.is_negative() {
+			astro_float::INF_NEG
+		} else {
+			astro_float::INF_POS
+		};
+	}
+	if x.is_inf_neg() || is_pole(x) {
+		return astro_float::NAN;
+	}
+	// The error in ln Γ(x) is magnified by its size, about x ln x
+	let wp = p + GUARD_BITS + 2 * magnitude_bits(x) + 8;
+	let g = exp(&ln_gamma_abs(x, wp), wp);
+	round(if gamma_is_negative(x) { g.neg() } else { g }, p)
+}
+
+fn beta(a: &BigFloat, b: &BigFloat, p: usize) -> BigFloat {
+	if a.is_nan() || b.is_nan() || is_pole(a) || is_pole(b) {
+		return astro_float::NAN;
+	}
+	let ab = a.add_full_prec(b);
+	if is_pole(&ab) {
+		return BigFloat::new(p);
+	}
+	let wp = p
+		+ GUARD_BITS
+		+ 2 * magnitude_bits(a)
+			.max(magnitude_bits(b))
+			.max(magnitude_bits(&ab))
+		+ 8;
+	let l =
+		ln_gamma_abs(a, wp)
+			.add(&ln_gamma_abs(b, wp), wp, RM)
+			.sub(&ln_gamma_abs(&ab, wp), wp, RM);
+	let r = exp(&l, wp);
+	let negative = gamma_is_negative(a) ^ gamma_is_negative(b) ^ gamma_is_negative(&ab);
+	round(if negative { r.neg() } else { r }, p)
+}
+
+/// Returns ψ(x) for x > 0
+fn digamma_positive(x: &BigFloat, p: usize) -> BigFloat {
+	let min = int(stirling_min(p), p);
+	let one = int(1, p);
+	let mut z = x.clone();
+	let mut acc = BigFloat::new(p);
+	while z < min {
+		acc = acc.sub(&one.div(&z, p, RM), p, RM);
+		z = z.add(&one, p, RM);
+	}
+
+	// ln z - 1/(2z) - Σ B2k / (2k z^2k)
+	let mut sum = acc
+		.add(&ln(&z, p), p, RM)
+		.sub(&one.div(&z.mul(&int(2, p), p, RM), p, RM), p, RM);
+	let z2 = z.mul(&z, p, RM);
+	let mut zk = z2.clone();
+	for (k, b) in stirling_bernoulli(p).iter().enumerate() {
+		let k = k as i64 + 1;
+		let term = b.div(&zk.mul(&int(2 * k, p), p, RM), p, RM);
+		if negligible(&term, &sum, p) {
+			break;
+		}
+		sum = sum.sub(&term, p, RM);
+		zk = zk.mul(&z2, p, RM);
+	}
+	sum
+}
+
+fn digamma(x: &BigFloat, p: usize) -> BigFloat {
+	if x.is_nan() || x.is_inf_pos() {
+		return x.clone();
+	}
+	if x.is_inf_neg() || is_pole(x) {
+		return astro_float::NAN;
+	}
+	let wp = p + GUARD_BITS;
+	if x.is_positive() {
+		return round(digamma_positive(x, wp), p);
+	}
+	// Reflection, ψ(1 - x) - ψ(x) = π cot(πx)
+	let ps = wp + magnitude_bits(x);
+	let pi_s = pi(ps);
+	let px = pi_s.mul(x, ps, RM);
+	let cot = cos(&px, ps).div(&sin(&px, ps), wp, RM);
+	let one_x = int(1, wp).sub(x, wp, RM);
+	round(
+		digamma_positive(&one_x, wp).sub(&pi_s.mul(&cot, wp, RM), wp, RM),
+		p,
+	)
+}
+
+/// Returns 2/√π x e^(-x^2) Σ (2x^2)^n / (1·3·5···(2n+1)) for x > 0, which is erf(x)
+///
+/// Every term is positive so nothing cancels
+fn erf_series(x: &BigFloat, p: usize) -> BigFloat {
+	let x2 = x.mul(x, p, RM);
+	let two_x2 = x2.mul(&int(2, p), p, RM);
+	let mut term = int(1, p);
+	let mut sum = term.clone();
+	let mut n = 0;
+	loop {
+		n += 1;
+		term = term.mul(&two_x2, p, RM).div(&int(2 * n + 1, p), p, RM);
+		if negligible(&term, &sum, p) {
+			break;
+		}
+		sum = sum.add(&term, p, RM);
+	}
+	let scale =
+		x.mul(&int(2, p), p, RM)
+			.div(&pi(p).sqrt(p, RM), p, RM)
+			.mul(&exp(&x2.neg(), p), p, RM);
+	sum.mul(&scale, p, RM)
+}
+
+/// Returns x^2 > p ln 2, where e^(-x^2) is below the precision p
+#[inline]
+fn exp_neg_sq_negligible(x: &BigFloat, p: usize) -> bool {
+	let x2 = x.mul(x, 64, RM);
+	x2 > BigFloat::from_f64(p as f64 * LN_2, 64)
+}
+
+fn erf(x: &BigFloat, p: usize) -> BigFloat {
+	if x.is_nan() || x.is_zero() {
+		return x.clone();
+	}
+	let one = int(1, p);
+	let wp = p + GUARD_BITS;
+	let ax = x.abs();
+	// 1 - erf(x) < e^(-x^2) so erf is 1 to the working precision
+	let r = if x.is_inf() || exp_neg_sq_negligible(&ax, wp) {
+		one
+	} else {
+		round(erf_series(&ax, wp), p)
+	};
+	if x.is_negative() {
+		r.neg()
+	} else {
+		r
+	}
+}
+
+fn erfc(x: &BigFloat, p: usize) -> BigFloat {
+	if x.is_nan() {
+		return x.clone();
+	}
+	if x.is_inf_pos() {
+		return BigFloat::new(p);
+	}
+	let wp = p + GUARD_BITS;
+	let one = int(1, wp);
+	if !x.is_positive() {
+		return round(one.add(&erf(&x.abs(), wp), wp, RM), p);
+	}
+	if !exp_neg_sq_negligible(x, wp) {
+		// 1 - erf(x) cancels about x^2 log2(e) bits, which is bounded as x^2 < wp ln 2
+		let x2 = x.mul(x, 64, RM);
+		let ep = wp + (2f64.powi(exponent(&x2) as i32) * LOG2_E).ceil() as usize;
+		return round(one.sub(&erf_series(x, ep), ep, RM), p);
+	}
+
+	// The asymptotic series e^(-x^2) / (x √π) Σ (-1)^n (2n-1)!! / (2x^2)^n, whose smallest
+	// term is around e^(-x^2) and so below the working precision
+	let two_x2 = x.mul(x, wp, RM).mul(&int(2, wp), wp, RM);
+	let mut term = one.clone();
+	let mut sum = one;
+	let mut n = 0;
+	loop {
+		n += 1;
+		let next = term
+			.mul(&int(2 * n - 1, wp), wp, RM)
+			.div(&two_x2, wp, RM)
+			.neg();
+		if negligible(&next, &sum, wp) || next.abs() >= term.abs() {
+			break;
+		}
+		sum = sum.add(&next, wp, RM);
+		term = next;
+	}
+	let scale = exp(&x.mul(x, wp, RM).neg(), wp).div(&x.mul(&pi(wp).sqrt(wp, RM), wp, RM), wp, RM);
+	round(sum.mul(&scale, wp, RM), p)
+}
+
+/// Returns Σ (-x^2/4)^k / (k! (k + n)!) (x/2)^n, which is Jn(x)
+fn bessel_j_series(x: &BigFloat, n: i64, p: usize) -> BigFloat {
+	let q = x.mul(x, p, RM).div(&int(4, p), p, RM).neg();
+	let half_x = x.div(&int(2, p), p, RM);
+	let mut term = if n == 0 { int(1, p) } else { half_x };
+	let mut sum = term.clone();
+	let mut k = 0;
+	loop {
+		k += 1;
+		term = term.mul(&q, p, RM).div(&int(k * (k + n), p), p, RM);
+		if negligible(&term, &sum, p) {
+			return sum;
+		}
+		sum = sum.add(&term, p, RM);
+	}
+}
+
+/// Returns Yn(x) from its series, using the harmonic numbers Hk = ψ(k + 1) + γ
+///
+/// Y0(x) = 2/π (ln(x/2) + γ) J0(x) + 2/π Σ (-1)^(k+1) Hk (x^2/4)^k / (k!)^2
+///
+/// Y1(x) = -2/(πx) + 2/π (ln(x/2) + γ) J1(x) - x/(2π) Σ (Hk + Hk+1) (-x^2/4)^k / (k! (k+1)!)
+fn bessel_y_series(x: &BigFloat, n: i64, p: usize) -> BigFloat {
+	let one = int(1, p);
+	let q = x.mul(x, p, RM).div(&int(4, p), p, RM).neg();
+	let euler_gamma = digamma_positive(&one, p).neg();
+	let l = ln(&x.div(&int(2, p), p, RM), p).add(&euler_gamma, p, RM);
+	let pi = pi(p);
+	let j = bessel_j_series(x, n, p);
+
+	let mut term = one.clone();
+	let mut h = BigFloat::new(p);
+	let mut sum = if n == 0 {
+		BigFloat::new(p)
+	} else {
+		one.clone()
+	};
+	let mut k = 0;
+	loop {
+		k += 1;
+		term = term.mul(&q, p, RM).div(&int(k * (k + n), p), p, RM);
+		let h_next = h.add(&one.div(&int(k, p), p, RM), p, RM);
+		// Y0 uses Hk, Y1 uses Hk + Hk+1 with term shifted by one
+		let weight = if n == 0 {
+			h_next.clone()
+		} else {
+			h_next.add(&h_next.add(&one.div(&int(k + 1, p), p, RM), p, RM), p, RM)
+		};
+		let t = term.mul(&weight, p, RM);
+		h = h_next;
+		if negligible(&t, &sum, p) && k > 1 {
+			break;
+		}
+		sum = sum.add(&t, p, RM);
+	}
+	let two_pi = int(2, p).div(&pi, p, RM);
+	if n == 0 {
+		two_pi.mul(&l.mul(&j, p, RM).sub(&sum, p, RM), p, RM)
+	} else {
+		let half_x = x.div(&int(2, p), p, RM);
+		two_pi
+			.mul(&l.mul(&j, p, RM), p, RM)
+			.sub(&two_pi.div(x, p, RM), p, RM)
+			.sub(&half_x.mul(&sum, p, RM).div(&pi, p, RM), p, RM)
+	}
+}
+
+/// Returns (Jn(x), Yn(x)) from the Hankel asymptotic expansion, whose smallest term is
+/// around e^(-2x)
+fn bessel_asymptotic(x: &BigFloat, n: i64, p: usize) -> (BigFloat, BigFloat) {
+	let mu = int(4 * n * n, p);
+	let eight_x = x.mul(&int(8, p), p, RM);
+	let mut big_p = int(1, p);
+	let mut big_q = BigFloat::new(p);
+	let mut term = int(1, p);
+	let mut k = 0;
+	loop {
+		k += 1;
+		let odd = int((2 * k - 1) * (2 * k - 1), p);
+		let next =
+			term.mul(&mu.sub(&odd, p, RM), p, RM)
+				.div(&eight_x.mul(&int(k, p), p, RM), p, RM);
+		if negligible(&next, &big_p, p) || next.abs() >= term.abs() {
+			break;
+		}
+		term = next;
+		let t = if (k / 2) % 2 == 0 {
+			term.clone()
+		} else {
+			term.neg()
+		};
+		if k % 2 == 0 {
+			big_p = big_p.add(&t, p, RM);
+		} else {
+			big_q = big_q.add(&t, p, RM);
+		}
+	}
+	// ω = x - (2n + 1)π/4, with enough bits to reduce it
+	let ps = p + magnitude_bits(x);
+	let pi_s = pi(ps);
+	let omega = x.sub(
+		&pi_s
+			.mul(&int(2 * n + 1, ps), ps, RM)
+			.div(&int(4, ps), ps, RM),
+		ps,
+		RM,
+	);
+	let (s, c) = (sin(&omega, ps), cos(&omega, ps));
+	let scale = int(2, p).div(&pi_s.mul(x, p, RM), p, RM).sqrt(p, RM);
+	let j = big_p
+		.mul(&c, p, RM)
+		.sub(&big_q.mul(&s, p, RM), p, RM)
+		.mul(&scale, p, RM);
+	let y = big_p
+		.mul(&s, p, RM)
+		.add(&big_q.mul(&c, p, RM), p, RM)
+		.mul(&scale, p, RM);
+	(j, y)
+}
+
+/// Returns Jn(x) or Yn(x) for x > 0
+fn bessel(x: &BigFloat, n: i64, second_kind: bool, p: usize) -> BigFloat {
+	let wp = p + GUARD_BITS;
+	if x > &BigFloat::from_f64(wp as f64 * LN_2 / 2.0, 64) {
+		let (j, y) = bessel_asymptotic(x, n, wp);
+		return round(if second_kind { y } else { j }, p);
+	}
+	// The terms of the series grow to around e^x before they fall so that many bits cancel
+	let ep = wp + (2f64.powi(exponent(x) as i32) * LOG2_E).ceil() as usize;
+	let r = if second_kind {
+		bessel_y_series(x, n, ep)
+	} else {
+		bessel_j_series(x, n, ep)
+	};
+	round(r, p)
+}
+
+fn j0(x: &BigFloat, p: usize) -> BigFloat {
+	if x.is_nan() {
+		return x.clone();
+	}
+	if x.is_inf() {
+		return BigFloat::new(p);
+	}
+	if x.is_zero() {
+		return int(1, p);
+	}
+	bessel(&x.abs(), 0, false, p)
+}
+
+fn j1(x: &BigFloat, p: usize) -> BigFloat {
+	if x.is_nan() || x.is_zero() {
+		return x.clone();
+	}
+	if x.is_inf() {
+		return BigFloat::new(p);
+	}
+	let r = bessel(&x.abs(), 1, false, p);
+	if x.is_negative() {
+		r.neg()
+	} else {
+		r
+	}
+}
+
+fn y(x: &BigFloat, n: i64, p: usize) -> BigFloat {
+	if x.is_nan() || x.is_negative() {
+		return astro_float::NAN;
+	}
+	if x.is_inf() {
+		return BigFloat::new(p);
+	}
+	if x.is_zero() {
+		return astro_float::INF_NEG;
+	}
+	bessel(x, n, true, p)
+}
+
+macro_rules! impl_special {
+	($bound:ident,$fn:ident,$($args:expr),*) => {
+		impl<const P: usize> $bound for EasyBigFloat<P> {
+			#[inline]
+			fn $fn(self) -> Self {
+				Self {
+					val: $fn(&self.val, $($args,)* P),
+				}
+			}
+		}
+	};
+}
+
+impl_special!(Erf, erf,);
+impl_special!(Erfc, erfc,);
+impl_special!(Gamma, gamma,);
+impl_special!(LnGamma, ln_gamma,);
+impl_special!(Digamma, digamma,);
+impl_special!(BesselJ0, j0,);
+impl_special!(BesselJ1, j1,);
+
+impl<const P: usize> BesselY0 for EasyBigFloat<P> {
+	#[inline]
+	fn y0(self) -> Self {
+		Self {
+			val: y(&self.val, 0, P),
+		}
+	}
+}
+
+impl<const P: usize> BesselY1 for EasyBigFloat<P> {
+	#[inline]
+	fn y1(self) -> Self {
+		Self {
+			val: y(&self.val, 1, P),
+		}
+	}
+}
+
+impl<const P: usize> Beta for EasyBigFloat<P> {
+	#[inline]
+	fn beta(self, other: Self) -> Self {
+		Self {
+			val: beta(&self.val, &other.val, P),
+		}
+	}
+}
+
+impl<const P: usize> SpecialFns for EasyBigFloat<P> {}
+
+#[cfg(test)]
+mod tests {
+	use astro_float::Radix;
+
+	use super::*;
+
+	type Ebf = EasyBigFloat<256>;
+
+	fn ebf(s: &str) -> Ebf {
+		Ebf {
+			val: BigFloat::parse(s, Radix::Dec, 256, RoundingMode::ToEven),
+		}
+	}
+
+	fn assert_close(a: Ebf, e: &str) {
+		let e = ebf(e);
+		let err = a.val.sub(&e.val, 320, RM).abs();
+		// Within a few ulps of 256 bits
+		let tol = e
+			.val
+			.abs()
+			.mul(&BigFloat::from_f64(2f64.powi(-250), 64), 320, RM);
+		assert!(err <= tol, "{} != {}", a, e);
+	}
+
+	#[test]
+	fn erf_erfc() {
+		assert_close(
+			ebf("0.5").erf(),
+			"0.52049987781304653768274665389196452873645157575796370005880572564719352171685357",
+		);
+		assert_close(
+			ebf("-3").erf(),
+			"-0.99997790950300141455862722387041767962015229291260075034276104515705754331637987",
+		);
+		assert_close(
+			ebf("12").erf(),
+			"0.99999999999999999999999999999999999999999999999999999999999999986437388307940958",
+		);
+		assert_close(
+			ebf("0.5").erfc(),
+			"0.47950012218695346231725334610803547126354842424203629994119427435280647828314643",
+		);
+		assert_close(
+			ebf("5").erfc(),
+			"1.5374597944280348501883434853833788901180503147233799306879140559203913645586915e-12",
+		);
+		assert_close(
+			ebf("30").erfc(),
+			"2.5646562037561116000333972775014471465488897227786170541225995861842386947791974e-393",
+		);
+		assert_close(
+			ebf("-2").erfc(),
+			"1.9953222650189527341620692563672529286108917970400600767383523262004372807199952",
+		);
+	}
+
+	#[test]
+	fn gamma_fns() {
+		assert_close(
+			ebf("0.5").gamma(),
+			"1.7724538509055160272981674833411451827975494561223871282138077898529112845910322",
+		);
+		assert_close(
+			ebf("-2.5").gamma(),
+			"-0.94530872048294188122568932444861076415869304326527313504736415458821935178188383",
+		);
+		assert_close(
+			ebf("30.25").gamma(),
+			"20628053137753468870372061023714.164277145191232022681186986161797815922315926821",
+		);
+		assert!(ebf("-3").gamma().val.is_nan());
+		assert_close(
+			ebf("100").ln_gamma(),
+			"359.13420536957539877604401046028690961262171808562972877561279307484079922862431",
+		);
+		assert_close(
+			ebf("-0.5").ln_gamma(),
+			"1.2655121234846453964889457971347059238991475408179110398774915452294625069121078",
+		);
+		assert_close(
+			ebf("2.5").beta(ebf("3.5")),
+			"0.036815538909255389513234102147806674424185578898927021339550131941107223511166512",
+		);
+		assert_close(
+			ebf("-0.5").beta(ebf("2.25")),
+			"-4.3700959238201996841080659831518656894712582523860386046947028396679843117840238",
+		);
+		assert_close(
+			ebf("1").digamma(),
+			"-0.57721566490153286060651209008240243104215933593992359880576723488486772677766467",
+		);
+		assert_close(
+			ebf("0.25").digamma(),
+			"-4.2274535333762654080895301460966835773672444387082422716552795595189567958298533",
+		);
+		assert_close(
+			ebf("-1.5").digamma(),
+			"0.70315664064524318722569033366791109947350706200623255961953941279501169594961256",
+		);
+	}
+
+	#[test]
+	fn bessel() {
+		assert_close(
+			ebf("2").j0(),
+			"0.22389077914123566805182745464994862582515448221860760312834970601085395776801071",
+		);
+		assert_close(
+			ebf("150").j0(),
+			"-0.00077409037539429124694634827393698480646147546288335138286901459989884383226802262",
+		);
+		assert_close(
+			ebf("-3").j1(),
+			"-0.33905895852593645892551459720647889697308041819800817548157077715022160988799102",
+		);
+		assert_close(
+			ebf("150").j1(),
+			"-0.065145163657727360304582973797069883748673181030243588983463807625399823529383996",
+		);
+		assert_close(
+			ebf("0.5").y0(),
+			"-0.44451873350670655714839847506833191037356512440151102041489117938823968793141729",
+		);
+		assert_close(
+			ebf("150").y0(),
+			"-0.065142221509037354596321173094932141579447827479472462683863921405623114554767693",
+		);
+		assert_close(
+			ebf("7").y1(),
+			"-0.30266723702418487006076816955839496834131089203393953780158416495055339422290388",
+		);
+		assert_close(
+			ebf("150").y1(),
+			"0.00055695634956083998369705194202872472702366904696222303661395789598507907848743444",
+		);
+		assert!(ebf("-1").y1().val.is_nan());
+	}
+}
@@ -0,0 +1,224 @@
+//! This module contains implementations of mathematical operations on Matrices
+//!
+//! Addition and subtraction are element-wise between Matrices of the same size, multiplication
+//! is the matrix product so the inner dimensions are checked at compile time
+
+use std::array;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use orml_vector::Vector;
+
+use super::Matrix;
+
+impl<T, const R: usize, const C: usize> Matrix<T, R, C>
+where
+	T: Add<Output = T> + Default,
+	for<'a> &'a T: Mul<&'a T, Output = T>,
+{
+	/// Multiplies the Matrix by a column vector
+	#[inline]
+	fn mul_vector(&self, rhs: &Vector<T, C>) -> Vector<T, R> {
+		Vector::from_array(array::from_fn(|r| {
+			std::iter::zip(self.cols.iter(), rhs.iter())
+				.fold(Default::default(), |acc: T, (col, x)| acc + &col[r] * x)
+		}))
+	}
+}
+
+macro_rules! impl_op {
+	($lhs:ty,$rhs:ty,$func:ident,$bound:ident) => {
+		impl<T, const R: usize, const C: usize> $bound<$rhs> for $lhs
+		where
+			T: Default,
+			for<'a> &'a T: $bound<&'a T, Output = T>,
+		{
+			type Output = Matrix<T, R, C>;
+
+			#[inline]
+			fn $func(self, rhs: $rhs) -> Self::Output {
+				Matrix {
+					cols: array::from_fn(|c| $bound::$func(&self.cols[c], &rhs.cols[c])),
+				}
+			}
+		}
+	};
+}
+
+macro_rules! impl_op_vers {
+	($tt:ty,$func:ident,$bound:ident) => {
+		impl_op!($tt, $tt, $func, $bound);
+		impl_op!($tt, &$tt, $func, $bound);
+		impl_op!(&$tt, &$tt, $func, $bound);
+		impl_op!(&$tt, $tt, $func, $bound);
+	};
+}
+
+macro_rules! impl_op_assign {
+	($lhs:ty,$func:ident,$bound:ident,$func2:ident,$bound2:ident) => {
+		impl<T, const R: usize, const C: usize> $bound for $lhs
+		where
+			T: Default,
+			for<'a> &'a T: $bound2<&'a T, Output = T>,
+		{
+			#[inline]
+			fn $func(&mut self, rhs: Self) {
+				for (col, rhs) in self.cols.iter_mut().zip(&rhs.cols) {
+					let temp = $bound2::$func2(&*col, rhs);
+					*col = temp;
+				}
+			}
+		}
+	};
+}
+
+macro_rules! impl_mul {
+	($lhs:ty,$rhs:ty,$out:ty,$body:expr,$($dim:ident),*) => {
+		impl<T, $(const $dim: usize),*> Mul<$rhs> for $lhs
+		where
+			T: Add<Output = T> + Default,
+			for<'a> &'a T: Mul<&'a T, Output = T>,
+		{
+			type Output = $out;
+
+			#[inline]
+			fn mul(self, rhs: $rhs) -> Self::Output {
+				$body(&self, &rhs)
+			}
+		}
+	};
+}
+
+macro_rules! impl_mul_vers {
+	($lhs:ty,$rhs:ty,$out:ty,$body:expr,$($dim:ident),*) => {
+		impl_mul!($lhs, $rhs, $out, $body, $($dim),*);
+		impl_mul!($lhs, &$rhs, $out, $body, $($dim),*);
+		impl_mul!(&$lhs, &$rhs, $out, $body, $($dim),*);
+		impl_mul!(&$lhs, $rhs, $out, $body, $($dim),*);
+	};
+}
+
+// Add Impl
+impl_op_vers!(Matrix<T, R, C>,add,Add);
+
+// AddAssign Impl
+impl_op_assign!(Matrix<T, R, C>,add_assign,AddAssign,add,Add);
+
+// Sub Impl
+impl_op_vers!(Matrix<T, R, C>,sub,Sub);
+
+// SubAssign Impl
+impl_op_assign!(Matrix<T, R, C>,sub_assign,SubAssign,sub,Sub);
+
+// Matrix Mul Impl
+impl_mul_vers!(
+	Matrix<T, R, K>,
+	Matrix<T, K, C>,
+	Matrix<T, R, C>,
+	|a: &Matrix<T, R, K>, b: &Matrix<T, K, C>| Matrix {
+		cols: array::from_fn(|c| a.mul_vector(&b.cols[c])),
+	},
+	R,
+	K,
+	C
+);
+
+// Vector Mul Impl
+impl_mul_vers!(
+	Matrix<T, R, K>,
+	Vector<T, K>,
+	Vector<T, R>,
+	|a: &Matrix<T, R, K>, b: &Vector<T, K>| a.mul_vector(b),
+	R,
+	K
+);
+
+// Neg Implmentation
+impl<T, const R: usize, const C: usize> Neg for Matrix<T, R, C>
+where
+	T: Default,
+	for<'a> &'a T: Neg<Output = T>,
+{
+	type Output = Self;
+
+	#[inline]
+	fn neg(self) -> Self::Output {
+		Self {
+			cols: self.cols.map(Neg::neg),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	// The reference versions of the operators are tested too
+	#![allow(clippy::op_ref)]
+
+	use orml_vector::{typedefs::Vec3i32, Vector};
+
+	use crate::{typedefs::Mat2i32, Matrix};
+
+	#[test]
+	fn add() {
+		let m1: Mat2i32 = [[1, 2], [3, 4]].into();
+		let m2: Mat2i32 = [[4, 3], [2, 1]].into();
+		let expected: Mat2i32 = [[5, 5], [5, 5]].into();
+
+		assert_eq!(expected, m1 + m2);
+		assert_eq!(expected, &m1 + m2);
+		assert_eq!(expected, m1 + &m2);
+		assert_eq!(expected, &m1 + &m2);
+	}
+
+	#[test]
+	fn add_assign() {
+		let mut m: Mat2i32 = [[1, 2], [3, 4]].into();
+		m += [[4, 3], [2, 1]].into();
+		assert_eq!(m, [[5, 5], [5, 5]].into());
+	}
+
+	#[test]
+	fn sub() {
+		let m1: Mat2i32 = [[1, 2], [3, 4]].into();
+		let m2: Mat2i32 = [[4, 3], [2, 1]].into();
+		let expected: Mat2i32 = [[-3, -1], [1, 3]].into();
+
+		assert_eq!(expected, m1 - m2);
+		assert_eq!(expected, &m1 - &m2);
+	}
+
+	#[test]
+	fn sub_assign() {
+		let mut m: Mat2i32 = [[1, 2], [3, 4]].into();
+		m -= [[4, 3], [2, 1]].into();
+		assert_eq!(m, [[-3, -1], [1, 3]].into());
+	}
+
+	#[test]
+	fn neg() {
+		let m: Mat2i32 = [[1, -2], [3, 0]].into();
+		assert_eq!(-m, [[-1, 2], [-3, 0]].into());
+	}
+
+	#[test]
+	fn mul() {
+		let m1: Matrix<i32, 2, 3> = [[1, 2, 3], [4, 5, 6]].into();
+		let m2: Matrix<i32, 3, 2> = [[7, 8], [9, 10], [11, 12]].into();
+		let expected: Mat2i32 = [[58, 64], [139, 154]].into();
+		assert_eq!(expected, m1 * m2);
+		assert_eq!(expected, &m1 * &m2);
+
+		let expected: Matrix<i32, 3, 3> = [[39, 54, 69], [49, 68, 87], [59, 82, 105]].into();
+		assert_eq!(expected, m2 * m1);
+		assert_eq!(Mat2i32::identity() * m1, m1);
+		assert_eq!(m2 * Mat2i32::identity(), m2);
+	}
+
+	#[test]
+	fn mul_vector() {
+		let m: Matrix<i32, 2, 3> = [[1, 2, 3], [4, 5, 6]].into();
+		let v: Vec3i32 = [1, 0, -1].into();
+		assert_eq!(m * v, Vector::from_array([-2, -2]));
+		assert_eq!(&m * &v, Vector::from_array([-2, -2]));
+		assert_eq!(Matrix::<i32, 3, 3>::identity() * v, v);
+	}
+}
@@ -0,0 +1,60 @@
+//! This module contains the orthogonal and triangular decompositions of real Matrices
+//!
+//! Unlike [`crate::lu`] these need square roots, so they are bound on [`Float`] and the same
+//! code runs for f32, f64 and EasyBigFloat. Iterative methods stop once an update no longer
+//! changes anything at the precision of the type, so there is no epsilon to choose
+
+use std::fmt::Display;
+
+use num_traits::{One, Zero};
+use orml_traits::Float;
+
+pub mod cholesky;
+pub mod eigen;
+pub mod qr;
+pub mod svd;
+
+pub use cholesky::Cholesky;
+pub use eigen::{Complex, SymmetricEigen};
+pub use qr::Qr;
+pub use svd::Svd;
+
+/// The bounds needed by the decompositions, implemented for every type that meets them
+pub trait Real: Float + Clone + PartialOrd + Zero + One {}
+
+impl<T> Real for T where T: Float + Clone + PartialOrd + Zero + One {}
+
+/// The errors that can occur when decomposing a Matrix
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum DecompositionError {
+	/// The Matrix was not exactly symmetric
+	NotSymmetric,
+	/// The Matrix was symmetric but not positive definite
+	NotPositiveDefinite,
+	/// An iterative method did not converge
+	NoConvergence,
+}
+
+impl Display for DecompositionError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			DecompositionError::NotSymmetric => write!(f, "matrix is not symmetric"),
+			DecompositionError::NotPositiveDefinite => {
+				write!(f, "matrix is not positive definite")
+			}
+			DecompositionError::NoConvergence => write!(f, "iteration did not converge"),
+		}
+	}
+}
+
+impl std::error::Error for DecompositionError {}
+
+/// Returns the sum of the products of the pairs of elements
+#[inline]
+pub(crate) fn dot<'a, T>(a: impl Iterator<Item = &'a T>, b: impl Iterator<Item = &'a T>) -> T
+where
+	T: Real + 'a,
+{
+	a.zip(b)
+		.fold(T::zero(), |acc, (x, y)| acc + x.clone() * y.clone())
+}
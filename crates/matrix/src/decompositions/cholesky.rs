@@ -0,0 +1,161 @@
+//! This module contains the Cholesky decomposition of symmetric positive definite Matrices
+
+use std::array;
+use std::cmp::Ordering;
+
+use orml_vector::Vector;
+
+use super::{dot, DecompositionError, Real};
+use crate::Matrix;
+
+/// The Cholesky decomposition A = L L^T of a symmetric positive definite Matrix A
+///
+/// L is lower triangular with a positive diagonal. Created by [`Matrix::cholesky`]
+#[derive(Debug, Clone)]
+pub struct Cholesky<T, const N: usize> {
+	l: Matrix<T, N, N>,
+}
+
+impl<T, const N: usize> Matrix<T, N, N>
+where
+	T: Real,
+{
+	/// Returns the Cholesky decomposition of the Matrix
+	///
+	/// The Matrix has to be exactly symmetric, which A^T A and similar products always are
+	pub fn cholesky(&self) -> Result<Cholesky<T, N>, DecompositionError> {
+		for c in 0..N {
+			for r in c + 1..N {
+				if self[(r, c)] != self[(c, r)] {
+					return Err(DecompositionError::NotSymmetric);
+				}
+			}
+		}
+
+		let mut l = self.clone();
+		for j in 0..N {
+			let d = l[(j, j)].clone() - dot(l.row_iter(j, j), l.row_iter(j, j));
+			// NaN fails too
+			if d.partial_cmp(&T::zero()) != Some(Ordering::Greater) {
+				return Err(DecompositionError::NotPositiveDefinite);
+			}
+			let d = d.sqrt();
+			for i in j + 1..N {
+				let s = l[(i, j)].clone() - dot(l.row_iter(i, j), l.row_iter(j, j));
+				l[(i, j)] = s / d.clone();
+			}
+			l[(j, j)] = d;
+			for i in 0..j {
+				l[(i, j)] = T::zero();
+			}
+		}
+
+		Ok(Cholesky { l })
+	}
+
+	/// Returns an iter over the first n elements of row r
+	#[inline]
+	fn row_iter(&self, r: usize, n: usize) -> impl Iterator<Item = &T> {
+		self.cols[..n].iter().map(move |col| &col[r])
+	}
+}
+
+impl<T, const N: usize> Cholesky<T, N>
+where
+	T: Real,
+{
+	/// Returns the lower triangular factor L
+	#[inline]
+	pub fn l(&self) -> &Matrix<T, N, N> {
+		&self.l
+	}
+
+	/// Returns the determinant of the decomposed Matrix
+	pub fn determinant(&self) -> T {
+		(0..N).fold(T::one(), |acc, i| {
+			acc * self.l[(i, i)].clone() * self.l[(i, i)].clone()
+		})
+	}
+
+	/// Returns the x that solves A x = b
+	pub fn solve(&self, b: &Vector<T, N>) -> Vector<T, N> {
+		// Forward substitution L y = b
+		let mut x: [T; N] = array::from_fn(|i| b[i].clone());
+		for i in 0..N {
+			for j in 0..i {
+				x[i] = x[i].clone() - self.l[(i, j)].clone() * x[j].clone();
+			}
+			x[i] = x[i].clone() / self.l[(i, i)].clone();
+		}
+
+		// Back substitution L^T x = y
+		for i in (0..N).rev() {
+			for j in i + 1..N {
+				x[i] = x[i].clone() - self.l[(j, i)].clone() * x[j].clone();
+			}
+			x[i] = x[i].clone() / self.l[(i, i)].clone();
+		}
+
+		Vector::from_array(x)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use orml_easy_big_float::EasyBigFloat;
+
+	use crate::{
+		decompositions::DecompositionError,
+		typedefs::{Mat3f32, Mat3f64},
+		Matrix,
+	};
+
+	#[test]
+	fn decomposition() {
+		let m: Mat3f64 = [
+			[4.0, 12.0, -16.0],
+			[12.0, 37.0, -43.0],
+			[-16.0, -43.0, 98.0],
+		]
+		.into();
+		let ch = m.cholesky().unwrap();
+		let expected: Mat3f64 = [[2.0, 0.0, 0.0], [6.0, 1.0, 0.0], [-8.0, 5.0, 3.0]].into();
+		assert_eq!(*ch.l(), expected);
+		assert_eq!(ch.determinant(), 36.0);
+
+		let x = ch.solve(&[1.0, 2.0, 3.0].into());
+		let b = m * x;
+		assert!((0..3).all(|i| (b[i] - (i + 1) as f64).abs() < 1e-10));
+	}
+
+	#[test]
+	fn errors() {
+		let m: Mat3f64 = [[1.0, 2.0, 0.0], [2.0, 1.0, 0.0], [0.0, 0.0, 1.0]].into();
+		assert_eq!(
+			m.cholesky().unwrap_err(),
+			DecompositionError::NotPositiveDefinite
+		);
+
+		let m: Mat3f32 = [[1.0, 2.0, 0.0], [0.0, 5.0, 0.0], [0.0, 0.0, 1.0]].into();
+		assert_eq!(m.cholesky().unwrap_err(), DecompositionError::NotSymmetric);
+
+		// Positive semi-definite isn't enough
+		let m: Matrix<f64, 2, 2> = [[1.0, 1.0], [1.0, 1.0]].into();
+		assert!(m.cholesky().is_err());
+	}
+
+	#[test]
+	fn big_float() {
+		type BF = EasyBigFloat<256>;
+
+		let m: Matrix<BF, 3, 3> = [[2.0, -1.0, 0.0], [-1.0, 2.0, -1.0], [0.0, -1.0, 2.0]]
+			.map(|r| r.map(BF::from))
+			.into();
+		let ch = m.cholesky().unwrap();
+		let diff = ch.l() * ch.l().clone().transpose() - m;
+		assert!(diff
+			.iter_cols()
+			.flatten()
+			.all(|e| e.clone() < BF::from(1e-70) && -e < BF::from(1e-70)));
+	}
+}
@@ -0,0 +1,568 @@
+//! This module contains eigenvalue solvers for symmetric and general real Matrices
+//!
+//! Symmetric Matrices use cyclic Jacobi rotations, which give eigenvectors that are
+//! orthonormal to the precision of the type. General Matrices are reduced to Hessenberg form
+//! and then iterated with the Francis double shift QR algorithm so complex conjugate pairs of
+//! eigenvalues never need complex arithmetic
+
+use std::array;
+use std::cmp::Ordering;
+
+use num_traits::Zero;
+use orml_vector::Vector;
+
+use super::{dot, DecompositionError, Real};
+use crate::Matrix;
+
+/// The most sweeps of the Jacobi method, each converges quadratically once close
+const MAX_SWEEPS: usize = 64;
+
+/// The most QR iterations spent on a single eigenvalue before giving up
+const MAX_ITERATIONS: usize = 100;
+
+/// A complex number re + im i, the eigenvalues of a general real Matrix
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Complex<T> {
+	/// The real part
+	pub re: T,
+	/// The imaginary part
+	pub im: T,
+}
+
+impl<T> Complex<T> {
+	/// Creates a new Complex from its real and imaginary parts
+	#[inline]
+	pub fn new(re: T, im: T) -> Self {
+		Self { re, im }
+	}
+
+	/// Returns true if the imaginary part is exactly zero
+	#[inline]
+	pub fn is_real(&self) -> bool
+	where
+		T: Zero,
+	{
+		self.im.is_zero()
+	}
+}
+
+/// The eigen-decomposition A = V D V^T of a symmetric Matrix A
+///
+/// The eigenvalues are in ascending order and eigenvector i belongs to eigenvalue i. Created by
+/// [`Matrix::symmetric_eigen`]
+#[derive(Debug, Clone)]
+pub struct SymmetricEigen<T, const N: usize> {
+	values: [T; N],
+	vectors: [Vector<T, N>; N],
+}
+
+impl<T, const N: usize> SymmetricEigen<T, N> {
+	/// Returns the eigenvalues in ascending order
+	#[inline]
+	pub fn eigenvalues(&self) -> &[T; N] {
+		&self.values
+	}
+
+	/// Returns the orthonormal eigenvectors
+	#[inline]
+	pub fn eigenvectors(&self) -> &[Vector<T, N>; N] {
+		&self.vectors
+	}
+}
+
+/// Returns |a| with the sign of b
+#[inline]
+fn sign<T: Real>(a: T, b: &T) -> T {
+	let a = a.abs();
+	if *b < T::zero() {
+		-a
+	} else {
+		a
+	}
+}
+
+impl<T, const N: usize> Matrix<T, N, N>
+where
+	T: Real,
+{
+	/// Returns the eigenvalues and eigenvectors of the symmetric Matrix
+	///
+	/// The Matrix has to be exactly symmetric, which A^T A and similar products always are. Fails
+	/// with [`DecompositionError::NoConvergence`] if the rotations haven't settled after the last
+	/// sweep
+	pub fn symmetric_eigen(&self) -> Result<SymmetricEigen<T, N>, DecompositionError> {
+		for c in 0..N {
+			for r in c + 1..N {
+				if self[(r, c)] != self[(c, r)] {
+					return Err(DecompositionError::NotSymmetric);
+				}
+			}
+		}
+
+		let mut a = self.clone();
+		let mut v = Matrix::<T, N, N>::identity();
+		let two = T::one() + T::one();
+
+		let mut converged = false;
+		for _ in 0..MAX_SWEEPS {
+			let mut rotated = false;
+			for p in 0..N {
+				for q in p + 1..N {
+					let apq = a[(p, q)].clone();
+					if apq.is_zero() {
+						continue;
+					}
+					// Drop elements too small to change either diagonal element
+					let (app, aqq) = (a[(p, p)].clone(), a[(q, q)].clone());
+					if app.clone() + apq.clone().abs() == app
+						&& aqq.clone() + apq.clone().abs() == aqq
+					{
+						a[(p, q)] = T::zero();
+						a[(q, p)] = T::zero();
+						continue;
+					}
+					rotated = true;
+
+					let theta = (aqq - app) / (two.clone() * apq.clone());
+					let t = T::one()
+						/ (theta.clone().abs() + (T::one() + theta.clone() * theta.clone()).sqrt());
+					let t = sign(t, &theta);
+					let c = T::one() / (T::one() + t.clone() * t.clone()).sqrt();
+					let s = c.clone() * t.clone();
+
+					for r in 0..N {
+						if r != p && r != q {
+							let (g, h) = (a[(r, p)].clone(), a[(r, q)].clone());
+							a[(r, p)] = c.clone() * g.clone() - s.clone() * h.clone();
+							a[(r, q)] = s.clone() * g + c.clone() * h;
+							a[(p, r)] = a[(r, p)].clone();
+							a[(q, r)] = a[(r, q)].clone();
+						}
+						let (g, h) = (v[(r, p)].clone(), v[(r, q)].clone());
+						v[(r, p)] = c.clone() * g.clone() - s.clone() * h.clone();
+						v[(r, q)] = s.clone() * g + c.clone() * h;
+					}
+					a[(p, p)] = a[(p, p)].clone() - t.clone() * apq.clone();
+					a[(q, q)] = a[(q, q)].clone() + t * apq;
+					a[(p, q)] = T::zero();
+					a[(q, p)] = T::zero();
+				}
+			}
+			if !rotated {
+				converged = true;
+				break;
+			}
+		}
+		if !converged {
+			return Err(DecompositionError::NoConvergence);
+		}
+
+		let mut order: [usize; N] = array::from_fn(|i| i);
+		order.sort_by(|&i, &j| a[(i, i)].partial_cmp(&a[(j, j)]).unwrap_or(Ordering::Equal));
+
+		Ok(SymmetricEigen {
+			values: order.map(|i| a[(i, i)].clone()),
+			vectors: order.map(|i| v.cols[i].clone()),
+		})
+	}
+
+	/// Returns the Matrix in upper Hessenberg form with the same eigenvalues
+	///
+	/// Everything below the first subdiagonal is zero. The reduction is done by Householder
+	/// similarity transforms so it is stable
+	pub fn hessenberg(&self) -> Self {
+		let mut h = self.clone();
+		let two = T::one() + T::one();
+
+		for k in 0..N.saturating_sub(2) {
+			let norm = dot(h.cols[k].iter().skip(k + 1), h.cols[k].iter().skip(k + 1)).sqrt();
+			if norm.is_zero() {
+				continue;
+			}
+			let alpha = -sign(norm, &h[(k + 1, k)]);
+			let mut v: [T; N] =
+				array::from_fn(|i| if i <= k { T::zero() } else { h[(i, k)].clone() });
+			v[k + 1] = v[k + 1].clone() - alpha.clone();
+			let scale = two.clone() / dot(v.iter(), v.iter());
+
+			// H = P H, column k is known to become alpha e_(k+1)
+			for c in k + 1..N {
+				let f = scale.clone() * dot(v.iter().skip(k + 1), h.cols[c].iter().skip(k + 1));
+				for i in k + 1..N {
+					h[(i, c)] = h[(i, c)].clone() - f.clone() * v[i].clone();
+				}
+			}
+			h[(k + 1, k)] = alpha;
+			for i in k + 2..N {
+				h[(i, k)] = T::zero();
+			}
+
+			// H = H P
+			for i in 0..N {
+				let f = scale.clone() * dot((k + 1..N).map(|j| &h[(i, j)]), v.iter().skip(k + 1));
+				for j in k + 1..N {
+					h[(i, j)] = h[(i, j)].clone() - f.clone() * v[j].clone();
+				}
+			}
+		}
+
+		h
+	}
+
+	/// Returns the eigenvalues of the Matrix sorted by real then imaginary part
+	///
+	/// Complex eigenvalues come in conjugate pairs. Fails with
+	/// [`DecompositionError::NoConvergence`] if the QR iteration stalls
+	pub fn eigenvalues(&self) -> Result<[Complex<T>; N], DecompositionError> {
+		let mut values = hqr(self.hessenberg())?;
+		values.sort_by(|a, b| {
+			a.re.partial_cmp(&b.re)
+				.unwrap_or(Ordering::Equal)
+				.then(a.im.partial_cmp(&b.im).unwrap_or(Ordering::Equal))
+		});
+		Ok(values)
+	}
+}
+
+/// Returns the eigenvalues of an upper Hessenberg Matrix by the Francis double shift QR
+/// algorithm
+///
+/// The active block is rows l to last, it shrinks from the bottom as eigenvalues split off.
+/// Small subdiagonal elements are found by whether adding them changes their neighbours
+fn hqr<T, const N: usize>(h: Matrix<T, N, N>) -> Result<[Complex<T>; N], DecompositionError>
+where
+	T: Real,
+{
+	let mut a = h;
+	let mut values: [Complex<T>; N] = array::from_fn(|_| Complex::new(T::zero(), T::zero()));
+	let half = T::one() / (T::one() + T::one());
+
+	// Only used to scale the test when both neighbours are zero
+	let mut anorm = T::zero();
+	for r in 0..N {
+		for c in r.saturating_sub(1)..N {
+			anorm += a[(r, c)].clone().abs();
+		}
+	}
+
+	// The shifts already taken out of the diagonal
+	let mut t = T::zero();
+	let mut nn = N;
+	while nn > 0 {
+		let last = nn - 1;
+		let mut its = 0;
+		loop {
+			// Look for a single small subdiagonal element to split the Matrix at
+			let mut l = last;
+			while l > 0 {
+				let mut s = a[(l - 1, l - 1)].clone().abs() + a[(l, l)].clone().abs();
+				if s.is_zero() {
+					s = anorm.clone();
+				}
+				if a[(l, l - 1)].clone().abs() + s.clone() == s {
+					a[(l, l - 1)] = T::zero();
+					break;
+				}
+				l -= 1;
+			}
+
+			let x = a[(last, last)].clone();
+			if l == last {
+				// One root found
+				values[last] = Complex::new(x + t.clone(), T::zero());
+				nn -= 1;
+				break;
+			}
+
+			let y = a[(last - 1, last - 1)].clone();
+			let w = a[(last, last - 1)].clone() * a[(last - 1, last)].clone();
+			if l == last - 1 {
+				// Two roots found, real or a complex pair
+				let p = half.clone() * (y - x.clone());
+				let q = p.clone() * p.clone() + w.clone();
+				let z = q.clone().abs().sqrt();
+				let x = x + t.clone();
+				if q >= T::zero() {
+					let z = p.clone() + sign(z, &p);
+					values[last - 1] = Complex::new(x.clone() + z.clone(), T::zero());
+					values[last] = if z.is_zero() {
+						values[last - 1].clone()
+					} else {
+						Complex::new(x - w / z, T::zero())
+					};
+				} else {
+					values[last - 1] = Complex::new(x.clone() + p.clone(), -z.clone());
+					values[last] = Complex::new(x + p, z);
+				}
+				nn -= 2;
+				break;
+			}
+
+			if its == MAX_ITERATIONS {
+				return Err(DecompositionError::NoConvergence);
+			}
+			let (mut x, mut y, mut w) = (x, y, w);
+			if its % 10 == 0 && its > 0 {
+				// Exceptional shift to break out of a cycle
+				t += x.clone();
+				for i in 0..=last {
+					a[(i, i)] = a[(i, i)].clone() - x.clone();
+				}
+				let s = a[(last, last - 1)].clone().abs() + a[(last - 1, last - 2)].clone().abs();
+				// x = y = 3 s / 4 and w = -7 s^2 / 16 as in EISPACK
+				let quarter = half.clone() * half.clone();
+				x = s.clone() - quarter.clone() * s.clone();
+				y = x.clone();
+				w = (quarter.clone() * quarter - half.clone()) * s.clone() * s;
+			}
+			its += 1;
+
+			// Look for two consecutive small subdiagonal elements to start the sweep at
+			let (mut p, mut q, mut r);
+			let mut m = last - 2;
+			loop {
+				let z = a[(m, m)].clone();
+				let rr = x.clone() - z.clone();
+				let ss = y.clone() - z.clone();
+				p = (rr.clone() * ss.clone() - w.clone()) / a[(m + 1, m)].clone()
+					+ a[(m, m + 1)].clone();
+				q = a[(m + 1, m + 1)].clone() - z.clone() - rr - ss;
+				r = a[(m + 2, m + 1)].clone();
+				let s = p.clone().abs() + q.clone().abs() + r.clone().abs();
+				p /= s.clone();
+				q /= s.clone();
+				r /= s;
+				if m == l {
+					break;
+				}
+				let u = a[(m, m - 1)].clone().abs() * (q.clone().abs() + r.clone().abs());
+				let v = p.clone().abs()
+					* (a[(m - 1, m - 1)].clone().abs() + z.abs() + a[(m + 1, m + 1)].clone().abs());
+				if u + v.clone() == v {
+					break;
+				}
+				m -= 1;
+			}
+
+			for i in m + 2..=last {
+				a[(i, i - 2)] = T::zero();
+				if i != m + 2 {
+					a[(i, i - 3)] = T::zero();
+				}
+			}
+
+			// The double shift QR step on rows and columns l to last
+			for k in m..last {
+				let mut scale = T::one();
+				if k != m {
+					p = a[(k, k - 1)].clone();
+					q = a[(k + 1, k - 1)].clone();
+					r = if k != last - 1 {
+						a[(k + 2, k - 1)].clone()
+					} else {
+						T::zero()
+					};
+					scale = p.clone().abs() + q.clone().abs() + r.clone().abs();
+					if !scale.is_zero() {
+						p /= scale.clone();
+						q /= scale.clone();
+						r /= scale.clone();
+					}
+				}
+				let s = sign(
+					(p.clone() * p.clone() + q.clone() * q.clone() + r.clone() * r.clone()).sqrt(),
+					&p,
+				);
+				if s.is_zero() {
+					continue;
+				}
+				if k == m {
+					if l != m {
+						a[(k, k - 1)] = -a[(k, k - 1)].clone();
+					}
+				} else {
+					a[(k, k - 1)] = -s.clone() * scale;
+				}
+				p += s.clone();
+				let x = p.clone() / s.clone();
+				let y = q.clone() / s.clone();
+				let z = r.clone() / s;
+				q /= p.clone();
+				r /= p.clone();
+
+				// Row modification
+				for j in k..=last {
+					let mut pp = a[(k, j)].clone() + q.clone() * a[(k + 1, j)].clone();
+					if k != last - 1 {
+						pp += r.clone() * a[(k + 2, j)].clone();
+						a[(k + 2, j)] = a[(k + 2, j)].clone() - pp.clone() * z.clone();
+					}
+					a[(k + 1, j)] = a[(k + 1, j)].clone() - pp.clone() * y.clone();
+					a[(k, j)] = a[(k, j)].clone() - pp * x.clone();
+				}
+
+				// Column modification
+				for i in l..=last.min(k + 3) {
+					let mut pp = x.clone() * a[(i, k)].clone() + y.clone() * a[(i, k + 1)].clone();
+					if k != last - 1 {
+						pp += z.clone() * a[(i, k + 2)].clone();
+						a[(i, k + 2)] = a[(i, k + 2)].clone() - pp.clone() * r.clone();
+					}
+					a[(i, k + 1)] = a[(i, k + 1)].clone() - pp.clone() * q.clone();
+					a[(i, k)] = a[(i, k)].clone() - pp;
+				}
+			}
+		}
+	}
+
+	Ok(values)
+}
+
+#[cfg(test)]
+mod tests {
+	use orml_easy_big_float::EasyBigFloat;
+
+	use crate::{
+		decompositions::{Complex, DecompositionError},
+		typedefs::{Mat3f32, Mat3f64, Mat4f64},
+		Matrix,
+	};
+
+	#[test]
+	fn symmetric() {
+		let m: Mat3f64 = [[2.0, -1.0, 0.0], [-1.0, 2.0, -1.0], [0.0, -1.0, 2.0]].into();
+		let eigen = m.symmetric_eigen().unwrap();
+		let values = eigen.eigenvalues();
+		let s = 2f64.sqrt();
+		let expected = [2.0 - s, 2.0, 2.0 + s];
+		assert!((0..3).all(|i| (values[i] - expected[i]).abs() < 1e-14));
+
+		let vectors = eigen.eigenvectors();
+		for i in 0..3 {
+			let diff = m * vectors[i] - vectors[i] * values[i];
+			assert!(diff.iter().all(|e| e.abs() < 1e-14));
+			for j in 0..3 {
+				let d = if i == j { 1.0 } else { 0.0 };
+				assert!((vectors[i].dot(&vectors[j]) - d).abs() < 1e-14);
+			}
+		}
+
+		// An inertia tensor with a repeated principal moment
+		let m: Mat3f32 = [[2.0, 1.0, 0.0], [1.0, 2.0, 0.0], [0.0, 0.0, 3.0]].into();
+		let values = *m.symmetric_eigen().unwrap().eigenvalues();
+		assert!((0..3).all(|i| (values[i] - [1.0, 3.0, 3.0][i]).abs() < 1e-6));
+
+		let m: Mat3f64 = [[1.0, 2.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]].into();
+		assert_eq!(
+			m.symmetric_eigen().unwrap_err(),
+			DecompositionError::NotSymmetric
+		);
+
+		let m: Mat3f64 = [[f64::NAN, 1.0, 0.0], [1.0, 2.0, 0.0], [0.0, 0.0, 1.0]].into();
+		assert_eq!(
+			m.symmetric_eigen().unwrap_err(),
+			DecompositionError::NoConvergence
+		);
+	}
+
+	#[test]
+	fn hessenberg() {
+		let m: Mat4f64 = [
+			[4.0, 1.0, -2.0, 2.0],
+			[1.0, 2.0, 0.0, 1.0],
+			[-2.0, 0.0, 3.0, -2.0],
+			[2.0, 1.0, -2.0, -1.0],
+		]
+		.into();
+		let h = m.hessenberg();
+		for c in 0..4 {
+			for r in c + 2..4 {
+				assert_eq!(h[(r, c)], 0.0);
+			}
+		}
+		// Similar Matrices keep the trace and determinant
+		let trace = |m: &Mat4f64| (0..4).map(|i| m[(i, i)]).sum::<f64>();
+		assert!((trace(&h) - trace(&m)).abs() < 1e-12);
+		assert!((h.determinant() - m.determinant()).abs() < 1e-12);
+	}
+
+	#[test]
+	fn general() {
+		let close = |a: &Complex<f64>, re: f64, im: f64| {
+			(a.re - re).abs() < 1e-12 && (a.im - im).abs() < 1e-12
+		};
+
+		// A quarter turn has eigenvalues +-i
+		let m: Matrix<f64, 2, 2> = [[0.0, -1.0], [1.0, 0.0]].into();
+		let values = m.eigenvalues().unwrap();
+		assert!(close(&values[0], 0.0, -1.0));
+		assert!(close(&values[1], 0.0, 1.0));
+
+		// The companion Matrix of x^3 - 1 has the cube roots of unity
+		let m: Mat3f64 = [[0.0, 0.0, 1.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]].into();
+		let values = m.eigenvalues().unwrap();
+		let h = 3f64.sqrt() / 2.0;
+		assert!(close(&values[0], -0.5, -h));
+		assert!(close(&values[1], -0.5, h));
+		assert!(close(&values[2], 1.0, 0.0));
+		assert!(values[2].is_real());
+
+		// The companion Matrix of (x - 1)(x - 2)(x - 3)(x - 4)
+		let m: Mat4f64 = [
+			[0.0, 0.0, 0.0, -24.0],
+			[1.0, 0.0, 0.0, 50.0],
+			[0.0, 1.0, 0.0, -35.0],
+			[0.0, 0.0, 1.0, 10.0],
+		]
+		.into();
+		let values = m.eigenvalues().unwrap();
+		assert!(
+			(0..4).all(|i| (values[i].re - (i + 1) as f64).abs() < 1e-10 && values[i].is_real())
+		);
+
+		// A block of a scaled rotation next to a real eigenvalue
+		let m: Matrix<f64, 5, 5> = [
+			[1.0, -2.0, 0.0, 0.0, 0.0],
+			[2.0, 1.0, 0.0, 0.0, 0.0],
+			[0.0, 0.0, 3.0, 0.0, 0.0],
+			[0.0, 0.0, 1.0, -1.0, 0.0],
+			[0.0, 0.0, 0.0, 0.0, 5.0],
+		]
+		.into();
+		let values = m.eigenvalues().unwrap();
+		assert!(close(&values[0], -1.0, 0.0));
+		assert!(close(&values[1], 1.0, -2.0));
+		assert!(close(&values[2], 1.0, 2.0));
+		assert!(close(&values[3], 3.0, 0.0));
+		assert!(close(&values[4], 5.0, 0.0));
+	}
+
+	#[test]
+	fn big_float() {
+		type BF = EasyBigFloat<512>;
+		let tol = BF::from(1e-140);
+		let close = |a: &BF, b: &BF| {
+			let d = a.clone() - b.clone();
+			d < tol && -d < tol
+		};
+
+		let m: Matrix<BF, 3, 3> = [[2.0, -1.0, 0.0], [-1.0, 2.0, -1.0], [0.0, -1.0, 2.0]]
+			.map(|r| r.map(BF::from))
+			.into();
+		let s = BF::from(2.0).sqrt();
+		let values = m.symmetric_eigen().unwrap().eigenvalues().clone();
+		assert!(close(&values[0], &(BF::from(2.0) - s.clone())));
+		assert!(close(&values[1], &BF::from(2.0)));
+		assert!(close(&values[2], &(BF::from(2.0) + s)));
+
+		let m: Matrix<BF, 3, 3> = [[0.0, 0.0, 1.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]
+			.map(|r| r.map(BF::from))
+			.into();
+		let values = m.eigenvalues().unwrap();
+		let h = BF::from(3.0).sqrt() / BF::from(2.0);
+		assert!(close(&values[0].re, &BF::from(-0.5)));
+		assert!(close(&values[0].im, &-h.clone()));
+		assert!(close(&values[1].im, &h));
+		assert!(close(&values[2].re, &BF::from(1.0)));
+	}
+}
@@ -0,0 +1,173 @@
+//! This module contains the Householder QR decomposition
+
+use std::array;
+
+use orml_vector::Vector;
+
+use super::{dot, Real};
+use crate::Matrix;
+
+/// The QR decomposition A = Q R of an R by C Matrix A
+///
+/// Q is an R by R orthogonal Matrix and R is R by C upper triangular. Created by [`Matrix::qr`]
+#[derive(Debug, Clone)]
+pub struct Qr<T, const R: usize, const C: usize> {
+	q: Matrix<T, R, R>,
+	r: Matrix<T, R, C>,
+}
+
+impl<T, const R: usize, const C: usize> Matrix<T, R, C>
+where
+	T: Real,
+{
+	/// Returns the QR decomposition of the Matrix using Householder reflections
+	pub fn qr(&self) -> Qr<T, R, C> {
+		let mut q = Matrix::<T, R, R>::identity();
+		let mut r = self.clone();
+		let two = T::one() + T::one();
+
+		// The last row has nothing below the diagonal to eliminate
+		for k in 0..R.saturating_sub(1).min(C) {
+			let norm = dot(r.cols[k].iter().skip(k), r.cols[k].iter().skip(k)).sqrt();
+			if norm.is_zero() {
+				continue;
+			}
+			// Reflect onto the axis on the far side of the element so v doesn't cancel
+			let alpha = if r[(k, k)] < T::zero() { norm } else { -norm };
+			let mut v: [T; R] =
+				array::from_fn(|i| if i < k { T::zero() } else { r[(i, k)].clone() });
+			v[k] = v[k].clone() - alpha.clone();
+			let scale = two.clone() / dot(v.iter(), v.iter());
+
+			// R = H R, column k is known to become alpha e_k
+			for c in k + 1..C {
+				let f = scale.clone() * dot(v.iter().skip(k), r.cols[c].iter().skip(k));
+				for i in k..R {
+					r[(i, c)] = r[(i, c)].clone() - f.clone() * v[i].clone();
+				}
+			}
+			r[(k, k)] = alpha;
+			for i in k + 1..R {
+				r[(i, k)] = T::zero();
+			}
+
+			// Q = Q H
+			for i in 0..R {
+				let f = scale.clone() * dot((k..R).map(|j| &q[(i, j)]), v.iter().skip(k));
+				for j in k..R {
+					q[(i, j)] = q[(i, j)].clone() - f.clone() * v[j].clone();
+				}
+			}
+		}
+
+		Qr { q, r }
+	}
+}
+
+impl<T, const R: usize, const C: usize> Qr<T, R, C>
+where
+	T: Real,
+{
+	/// Returns the orthogonal factor Q
+	#[inline]
+	pub fn q(&self) -> &Matrix<T, R, R> {
+		&self.q
+	}
+
+	/// Returns the upper triangular factor R
+	#[inline]
+	pub fn r(&self) -> &Matrix<T, R, C> {
+		&self.r
+	}
+
+	/// Returns the x that minimises |A x - b|
+	///
+	/// Returns None if there are more columns than rows or A doesn't have full column rank
+	pub fn solve(&self, b: &Vector<T, R>) -> Option<Vector<T, C>> {
+		if C > R || (0..C).any(|i| self.r[(i, i)].is_zero()) {
+			return None;
+		}
+
+		// R x = Q^T b, the rows of R past C are zero and only add to the residual
+		let mut x: [T; C] = array::from_fn(|i| dot(self.q.cols[i].iter(), b.iter()));
+		for i in (0..C).rev() {
+			for j in i + 1..C {
+				x[i] = x[i].clone() - self.r[(i, j)].clone() * x[j].clone();
+			}
+			x[i] = x[i].clone() / self.r[(i, i)].clone();
+		}
+
+		Some(Vector::from_array(x))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use orml_easy_big_float::EasyBigFloat;
+
+	use crate::{typedefs::Mat3f32, Matrix};
+
+	fn max_diff<const R: usize, const C: usize>(a: Matrix<f64, R, C>, b: Matrix<f64, R, C>) -> f64 {
+		(a - b)
+			.iter_cols()
+			.flatten()
+			.fold(0.0, |acc, e| acc.max(e.abs()))
+	}
+
+	#[test]
+	fn decomposition() {
+		let m: Matrix<f64, 4, 3> = [
+			[12.0, -51.0, 4.0],
+			[6.0, 167.0, -68.0],
+			[-4.0, 24.0, -41.0],
+			[1.0, 2.0, 3.0],
+		]
+		.into();
+		let qr = m.qr();
+		let (q, r) = (*qr.q(), *qr.r());
+
+		assert!(max_diff(q * r, m) < 1e-12);
+		assert!(max_diff(q.transpose() * q, Matrix::identity()) < 1e-15);
+		for c in 0..3 {
+			for r in c + 1..4 {
+				assert_eq!(qr.r()[(r, c)], 0.0);
+			}
+		}
+
+		// More columns than rows
+		let m: Matrix<f64, 2, 3> = [[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]].into();
+		let qr = m.qr();
+		assert!(max_diff(*qr.q() * *qr.r(), m) < 1e-12);
+	}
+
+	#[test]
+	fn least_squares() {
+		// Fits y = 1 + 2 t through points with symmetric noise
+		let a: Matrix<f64, 4, 2> = [[1.0, 0.0], [1.0, 1.0], [1.0, 2.0], [1.0, 3.0]].into();
+		let x = a.qr().solve(&[1.5, 2.5, 4.5, 7.5].into()).unwrap();
+		assert!((x[0] - 1.0).abs() < 1e-12);
+		assert!((x[1] - 2.0).abs() < 1e-12);
+
+		let a: Matrix<f64, 3, 2> = [[1.0, 0.0], [2.0, 0.0], [3.0, 0.0]].into();
+		assert_eq!(a.qr().solve(&[1.0, 2.0, 3.0].into()), None);
+	}
+
+	#[test]
+	fn generic() {
+		let m: Mat3f32 = [[2.0, -1.0, 0.0], [-1.0, 2.0, -1.0], [0.0, -1.0, 2.0]].into();
+		let qr = m.qr();
+		let diff = *qr.q() * *qr.r() - m;
+		assert!(diff.iter_cols().flatten().all(|e| e.abs() < 1e-5));
+
+		type BF = EasyBigFloat<512>;
+		let m: Matrix<BF, 3, 3> = [[2.0, -1.0, 0.0], [-1.0, 2.0, -1.0], [0.0, -1.0, 2.0]]
+			.map(|r| r.map(BF::from))
+			.into();
+		let qr = m.qr();
+		let diff = qr.q() * qr.r() - m;
+		assert!(diff
+			.iter_cols()
+			.flatten()
+			.all(|e| e.clone() < BF::from(1e-150) && -e < BF::from(1e-150)));
+	}
+}
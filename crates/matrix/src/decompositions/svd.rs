@@ -0,0 +1,245 @@
+//! This module contains the singular value decomposition by one-sided Jacobi rotations
+//!
+//! The columns of A are rotated in pairs until they are all orthogonal, the rotations make up
+//! V and the column lengths are the singular values. This is slower than bidiagonalisation
+//! but gets small singular values to full relative precision
+
+use std::array;
+
+use orml_vector::Vector;
+
+use super::{dot, DecompositionError, Real};
+use crate::Matrix;
+
+/// The most sweeps over every pair of columns, each sweep converges quadratically once close
+const MAX_SWEEPS: usize = 64;
+
+/// The singular value decomposition A = U S V^T of an R by C Matrix A
+///
+/// U is R by C with orthonormal columns, S is diagonal with the singular values in descending
+/// order and V is C by C orthogonal. When R < C the trailing singular values are zero and the
+/// matching columns of U are zero. Created by [`Matrix::svd`]
+#[derive(Debug, Clone)]
+pub struct Svd<T, const R: usize, const C: usize> {
+	u: Matrix<T, R, C>,
+	s: [T; C],
+	v: Matrix<T, C, C>,
+}
+
+impl<T, const R: usize, const C: usize> Matrix<T, R, C>
+where
+	T: Real,
+{
+	/// Returns the singular value decomposition of the Matrix
+	///
+	/// Fails with [`DecompositionError::NoConvergence`] if the columns are still being rotated
+	/// after the last sweep, which happens for NaN elements
+	pub fn svd(&self) -> Result<Svd<T, R, C>, DecompositionError> {
+		let mut u = self.clone();
+		let mut v = Matrix::<T, C, C>::identity();
+		let two = T::one() + T::one();
+
+		let mut converged = false;
+		for _ in 0..MAX_SWEEPS {
+			let mut rotated = false;
+			for i in 0..C {
+				for j in i + 1..C {
+					let alpha = dot(u.cols[i].iter(), u.cols[i].iter());
+					let beta = dot(u.cols[j].iter(), u.cols[j].iter());
+					let gamma = dot(u.cols[i].iter(), u.cols[j].iter());
+
+					// Skip columns that are already orthogonal at this precision, or so small that
+					// their squares underflow and no rotation can change them
+					let norm = alpha.clone().sqrt() * beta.clone().sqrt();
+					if norm.clone() + gamma.clone().abs() == norm || norm.is_zero() {
+						continue;
+					}
+					rotated = true;
+
+					let zeta = (beta - alpha) / (two.clone() * gamma);
+					let t = T::one()
+						/ (zeta.clone().abs() + (T::one() + zeta.clone() * zeta.clone()).sqrt());
+					let t = if zeta < T::zero() { -t } else { t };
+					let c = T::one() / (T::one() + t.clone() * t.clone()).sqrt();
+					let s = c.clone() * t;
+
+					rotate(&mut u, i, j, &c, &s);
+					rotate(&mut v, i, j, &c, &s);
+				}
+			}
+			if !rotated {
+				converged = true;
+				break;
+			}
+		}
+		if !converged {
+			return Err(DecompositionError::NoConvergence);
+		}
+
+		let sigma: [T; C] = array::from_fn(|i| dot(u.cols[i].iter(), u.cols[i].iter()).sqrt());
+		for (col, sigma) in u.cols.iter_mut().zip(&sigma) {
+			if !sigma.is_zero() {
+				for e in col.iter_mut() {
+					*e = e.clone() / sigma.clone();
+				}
+			}
+		}
+
+		let mut order: [usize; C] = array::from_fn(|i| i);
+		order.sort_by(|&a, &b| {
+			sigma[b]
+				.partial_cmp(&sigma[a])
+				.unwrap_or(std::cmp::Ordering::Equal)
+		});
+
+		Ok(Svd {
+			u: Matrix::from_cols(order.map(|i| u.cols[i].clone())),
+			s: order.map(|i| sigma[i].clone()),
+			v: Matrix::from_cols(order.map(|i| v.cols[i].clone())),
+		})
+	}
+}
+
+/// Rotates columns i and j of the Matrix by the rotation with cosine c and sine s
+#[inline]
+fn rotate<T, const R: usize, const C: usize>(
+	m: &mut Matrix<T, R, C>,
+	i: usize,
+	j: usize,
+	c: &T,
+	s: &T,
+) where
+	T: Real,
+{
+	for r in 0..R {
+		let (x, y) = (m[(r, i)].clone(), m[(r, j)].clone());
+		m[(r, i)] = c.clone() * x.clone() - s.clone() * y.clone();
+		m[(r, j)] = s.clone() * x + c.clone() * y;
+	}
+}
+
+impl<T, const R: usize, const C: usize> Svd<T, R, C>
+where
+	T: Real,
+{
+	/// Returns the left singular vectors U
+	#[inline]
+	pub fn u(&self) -> &Matrix<T, R, C> {
+		&self.u
+	}
+
+	/// Returns the singular values in descending order
+	#[inline]
+	pub fn singular_values(&self) -> &[T; C] {
+		&self.s
+	}
+
+	/// Returns the right singular vectors V
+	#[inline]
+	pub fn v(&self) -> &Matrix<T, C, C> {
+		&self.v
+	}
+
+	/// Returns the Matrix U S V^T
+	pub fn reconstruct(&self) -> Matrix<T, R, C> {
+		let mut m = self.u.clone();
+		for (col, s) in m.cols.iter_mut().zip(&self.s) {
+			for e in col.iter_mut() {
+				*e = e.clone() * s.clone();
+			}
+		}
+		// (U S) V^T without needing references to T to multiply
+		Matrix::from_cols(array::from_fn(|c| {
+			Vector::from_array(array::from_fn(|r| {
+				dot((0..C).map(|k| &m[(r, k)]), (0..C).map(|k| &self.v[(c, k)]))
+			}))
+		}))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use orml_easy_big_float::EasyBigFloat;
+
+	use crate::{decompositions::DecompositionError, typedefs::Mat3f32, Matrix};
+
+	fn max_diff<const R: usize, const C: usize>(a: Matrix<f64, R, C>, b: Matrix<f64, R, C>) -> f64 {
+		(a - b)
+			.iter_cols()
+			.flatten()
+			.fold(0.0, |acc, e| acc.max(e.abs()))
+	}
+
+	#[test]
+	fn decomposition() {
+		let m: Matrix<f64, 4, 3> = [
+			[1.0, 2.0, 3.0],
+			[4.0, 5.0, 6.0],
+			[7.0, 8.0, 10.0],
+			[-1.0, 0.0, 2.0],
+		]
+		.into();
+		let svd = m.svd().unwrap();
+		let (u, v) = (*svd.u(), *svd.v());
+		let s = svd.singular_values();
+
+		assert!(max_diff(svd.reconstruct(), m) < 1e-12);
+		assert!(max_diff(u.transpose() * u, Matrix::identity()) < 1e-14);
+		assert!(max_diff(v.transpose() * v, Matrix::identity()) < 1e-14);
+		assert!(s.windows(2).all(|w| w[0] >= w[1]));
+	}
+
+	#[test]
+	fn known_values() {
+		// More columns than rows pads with a zero singular value
+		let m: Matrix<f64, 2, 3> = [[3.0, 2.0, 2.0], [2.0, 3.0, -2.0]].into();
+		let svd = m.svd().unwrap();
+		let s = svd.singular_values();
+		assert!((s[0] - 5.0).abs() < 1e-14);
+		assert!((s[1] - 3.0).abs() < 1e-14);
+		assert!(s[2].abs() < 1e-14);
+		assert!(max_diff(svd.reconstruct(), m) < 1e-14);
+
+		// Rank deficient
+		let m: Mat3f32 = [[1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [1.0, 1.0, 1.0]].into();
+		let svd = m.svd().unwrap();
+		assert!(svd.singular_values()[2].abs() < 1e-5);
+		let diff = svd.reconstruct() - m;
+		assert!(diff.iter_cols().flatten().all(|e| e.abs() < 1e-5));
+	}
+
+	#[test]
+	fn no_convergence() {
+		let m: Matrix<f64, 3, 2> = [[1.0, 2.0], [f64::NAN, 3.0], [0.0, 1.0]].into();
+		assert_eq!(m.svd().unwrap_err(), DecompositionError::NoConvergence);
+	}
+
+	#[test]
+	fn big_float() {
+		type BF = EasyBigFloat<1024>;
+
+		let tol = BF::from(2f64.powi(-1000));
+		let close = |a: &BF, b: &BF| {
+			let d = a.clone() - b.clone();
+			d < tol && -d < tol
+		};
+
+		let m: Matrix<BF, 3, 2> = [[3.0, 2.0], [2.0, 3.0], [2.0, -2.0]]
+			.map(|r| r.map(BF::from))
+			.into();
+		let svd = m.svd().unwrap();
+		let s = svd.singular_values();
+		assert!(close(&s[0], &BF::from(5.0)));
+		assert!(close(&s[1], &BF::from(3.0)));
+
+		let r = svd.reconstruct();
+		for c in 0..2 {
+			for row in 0..3 {
+				assert!(close(&r[(row, c)], &m[(row, c)]));
+			}
+		}
+		let utu = svd.u().clone().transpose() * svd.u();
+		assert!(close(&utu[(0, 0)], &BF::from(1.0)));
+		assert!(close(&utu[(0, 1)], &BF::from(0.0)));
+	}
+}
@@ -0,0 +1,439 @@
+//! This module contains a heap backed Matrix (DMatrix<T>) for sizes only known at runtime
+//!
+//! Like [`Matrix`] it is stored as columns, each a [`DVector`]. Operators between DMatrices
+//! and DVectors check the dimensions and return a [`DimensionError`] instead of panicking
+
+use std::fmt::Display;
+use std::ops::{Add, Index, IndexMut, Mul, Neg, Sub};
+
+use num_traits::{One, Zero};
+use orml_vector::dynamic::{DVector, DimensionError};
+use orml_vector::Vector;
+
+use super::Matrix;
+
+/// A Mathimatical Matrix with its number of rows and columns chosen at runtime
+#[derive(Debug, Clone, Default)]
+pub struct DMatrix<T> {
+	rows: usize,
+	cols: Vec<DVector<T>>,
+}
+
+impl<T> DMatrix<T> {
+	/// Creates a new DMatrix with rows rows and cols columns from a function of (row, col)
+	#[inline]
+	pub fn from_fn(rows: usize, cols: usize, mut f: impl FnMut(usize, usize) -> T) -> Self {
+		Self {
+			rows,
+			cols: (0..cols)
+				.map(|c| (0..rows).map(|r| f(r, c)).collect())
+				.collect(),
+		}
+	}
+
+	/// Creates a new DMatrix from its columns, which all need the same dimension
+	pub fn from_cols(cols: Vec<DVector<T>>) -> Result<Self, DimensionError> {
+		let rows = cols.first().map_or(0, DVector::len);
+		for col in &cols {
+			DimensionError::check(rows, col.len())?;
+		}
+		Ok(Self { rows, cols })
+	}
+
+	/// Creates a new DMatrix from its rows, which all need the same dimension
+	pub fn from_rows(rows: Vec<DVector<T>>) -> Result<Self, DimensionError> {
+		Ok(Self::from_cols(rows)?.transpose())
+	}
+
+	/// Returns the columns of the DMatrix
+	#[inline]
+	pub fn to_cols(self) -> Vec<DVector<T>> {
+		self.cols
+	}
+
+	/// Returns a reference to the column at index c
+	#[inline]
+	pub fn col(&self, c: usize) -> &DVector<T> {
+		&self.cols[c]
+	}
+
+	/// Returns a mutable reference to the column at index c
+	#[inline]
+	pub fn col_mut(&mut self, c: usize) -> &mut DVector<T> {
+		&mut self.cols[c]
+	}
+
+	/// Returns a copy of the row at index r
+	#[inline]
+	pub fn row(&self, r: usize) -> DVector<T>
+	where
+		T: Clone,
+	{
+		self.cols.iter().map(|col| col[r].clone()).collect()
+	}
+
+	/// Returns the DMatrix with its rows and columns swapped
+	pub fn transpose(self) -> Self {
+		let rows = self.rows;
+		let mut cols: Vec<_> = self.cols.into_iter().map(DVector::into_iter).collect();
+		Self {
+			rows: cols.len(),
+			cols: (0..rows)
+				.map(|_| {
+					// Every column has exactly rows elements so this is taken rows times
+					cols.iter_mut().map(|col| col.next().unwrap()).collect()
+				})
+				.collect(),
+		}
+	}
+
+	/// Returns the number of rows of the DMatrix
+	#[inline]
+	pub fn rows(&self) -> usize {
+		self.rows
+	}
+
+	/// Returns the number of columns of the DMatrix
+	#[inline]
+	pub fn cols(&self) -> usize {
+		self.cols.len()
+	}
+
+	/// Returns true if the DMatrix is square
+	#[inline]
+	pub fn is_square(&self) -> bool {
+		self.rows == self.cols.len()
+	}
+
+	/// Returns an iter over the columns of the DMatrix
+	#[inline]
+	pub fn iter_cols(&self) -> std::slice::Iter<'_, DVector<T>> {
+		self.cols.iter()
+	}
+
+	/// Returns an error unless the other DMatrix has the same number of rows and columns
+	#[inline]
+	fn check_shape(&self, other: &Self) -> Result<(), DimensionError> {
+		DimensionError::check(self.rows, other.rows)?;
+		DimensionError::check(self.cols.len(), other.cols.len())
+	}
+}
+
+impl<T> DMatrix<T>
+where
+	T: Default,
+{
+	/// Create a new DMatrix with every element set to the default
+	#[inline]
+	pub fn new(rows: usize, cols: usize) -> Self {
+		Self::from_fn(rows, cols, |_, _| T::default())
+	}
+}
+
+impl<T> DMatrix<T>
+where
+	T: Zero + One,
+{
+	/// Returns the n by n identity DMatrix
+	#[inline]
+	pub fn identity(n: usize) -> Self {
+		Self::from_fn(n, n, |r, c| if r == c { T::one() } else { T::zero() })
+	}
+}
+
+impl<T> DMatrix<T>
+where
+	T: Add<Output = T> + Default,
+	for<'a> &'a T: Mul<&'a T, Output = T>,
+{
+	/// Multiplies the DMatrix by a column vector with the right dimension
+	#[inline]
+	fn mul_vector(&self, rhs: &DVector<T>) -> DVector<T> {
+		(0..self.rows)
+			.map(|r| {
+				std::iter::zip(self.cols.iter(), rhs.iter())
+					.fold(Default::default(), |acc: T, (col, x)| acc + &col[r] * x)
+			})
+			.collect()
+	}
+}
+
+impl<T, const R: usize, const C: usize> From<Matrix<T, R, C>> for DMatrix<T> {
+	#[inline]
+	fn from(value: Matrix<T, R, C>) -> Self {
+		Self {
+			rows: R,
+			cols: value.cols.into_iter().map(DVector::from).collect(),
+		}
+	}
+}
+
+impl<T, const R: usize, const C: usize> TryFrom<DMatrix<T>> for Matrix<T, R, C> {
+	type Error = DimensionError;
+
+	fn try_from(value: DMatrix<T>) -> Result<Self, Self::Error> {
+		DimensionError::check(R, value.rows)?;
+		DimensionError::check(C, value.cols.len())?;
+		let cols = value
+			.cols
+			.into_iter()
+			.map(Vector::try_from)
+			.collect::<Result<Vec<_>, _>>()?;
+		// The number of columns was checked above
+		Ok(Matrix::from_cols(cols.try_into().ok().unwrap()))
+	}
+}
+
+/// Creates a DMatrix from rows of elements, which all need the same length
+impl<T> TryFrom<Vec<Vec<T>>> for DMatrix<T> {
+	type Error = DimensionError;
+
+	#[inline]
+	fn try_from(value: Vec<Vec<T>>) -> Result<Self, Self::Error> {
+		Self::from_rows(value.into_iter().map(DVector::from).collect())
+	}
+}
+
+impl<T> Index<(usize, usize)> for DMatrix<T> {
+	type Output = T;
+
+	/// Returns the element at (row, col)
+	#[inline]
+	fn index(&self, index: (usize, usize)) -> &Self::Output {
+		&self.cols[index.1][index.0]
+	}
+}
+
+impl<T> IndexMut<(usize, usize)> for DMatrix<T> {
+	/// Returns the element at (row, col)
+	#[inline]
+	fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
+		&mut self.cols[index.1][index.0]
+	}
+}
+
+impl<T> PartialEq for DMatrix<T>
+where
+	T: PartialEq,
+{
+	#[inline]
+	fn eq(&self, other: &Self) -> bool {
+		self.rows == other.rows && self.cols == other.cols
+	}
+}
+
+impl<T> Eq for DMatrix<T> where T: Eq {}
+
+impl<T> Display for DMatrix<T>
+where
+	T: Display,
+{
+	#[inline]
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		for r in 0..self.rows {
+			if r > 0 {
+				writeln!(f)?;
+			}
+			write!(f, "[")?;
+			for col in &self.cols {
+				write!(f, " {},", col[r])?;
+			}
+			write!(f, " ]")?;
+		}
+		Ok(())
+	}
+}
+
+macro_rules! impl_op {
+	($lhs:ty,$rhs:ty,$func:ident,$bound:ident) => {
+		impl<T> $bound<$rhs> for $lhs
+		where
+			for<'a> &'a T: $bound<&'a T, Output = T>,
+		{
+			type Output = Result<DMatrix<T>, DimensionError>;
+
+			#[inline]
+			fn $func(self, rhs: $rhs) -> Self::Output {
+				self.check_shape(&rhs)?;
+				Ok(DMatrix {
+					rows: self.rows,
+					cols: std::iter::zip(self.cols.iter(), rhs.cols.iter())
+						// The shapes match so every column does too
+						.map(|(a, b)| $bound::$func(a, b).unwrap())
+						.collect(),
+				})
+			}
+		}
+	};
+}
+
+macro_rules! impl_op_vers {
+	($tt:ty,$func:ident,$bound:ident) => {
+		impl_op!($tt, $tt, $func, $bound);
+		impl_op!($tt, &$tt, $func, $bound);
+		impl_op!(&$tt, &$tt, $func, $bound);
+		impl_op!(&$tt, $tt, $func, $bound);
+	};
+}
+
+macro_rules! impl_mul {
+	($lhs:ty,$rhs:ty,$out:ty,$body:expr) => {
+		impl<T> Mul<$rhs> for $lhs
+		where
+			T: Add<Output = T> + Default,
+			for<'a> &'a T: Mul<&'a T, Output = T>,
+		{
+			type Output = Result<$out, DimensionError>;
+
+			#[inline]
+			fn mul(self, rhs: $rhs) -> Self::Output {
+				$body(&self, &rhs)
+			}
+		}
+	};
+}
+
+macro_rules! impl_mul_vers {
+	($lhs:ty,$rhs:ty,$out:ty,$body:expr) => {
+		impl_mul!($lhs, $rhs, $out, $body);
+		impl_mul!($lhs, &$rhs, $out, $body);
+		impl_mul!(&$lhs, &$rhs, $out, $body);
+		impl_mul!(&$lhs, $rhs, $out, $body);
+	};
+}
+
+// Add Impl
+impl_op_vers!(DMatrix<T>, add, Add);
+
+// Sub Impl
+impl_op_vers!(DMatrix<T>, sub, Sub);
+
+// Matrix Mul Impl
+impl_mul_vers!(
+	DMatrix<T>,
+	DMatrix<T>,
+	DMatrix<T>,
+	|a: &DMatrix<T>, b: &DMatrix<T>| {
+		DimensionError::check(a.cols(), b.rows)?;
+		Ok(DMatrix {
+			rows: a.rows,
+			cols: b.cols.iter().map(|col| a.mul_vector(col)).collect(),
+		})
+	}
+);
+
+// Vector Mul Impl
+impl_mul_vers!(
+	DMatrix<T>,
+	DVector<T>,
+	DVector<T>,
+	|a: &DMatrix<T>, b: &DVector<T>| {
+		DimensionError::check(a.cols(), b.len())?;
+		Ok(a.mul_vector(b))
+	}
+);
+
+// Neg Implmentation
+impl<T> Neg for DMatrix<T>
+where
+	for<'a> &'a T: Neg<Output = T>,
+{
+	type Output = Self;
+
+	#[inline]
+	fn neg(self) -> Self::Output {
+		Self {
+			rows: self.rows,
+			cols: self.cols.into_iter().map(Neg::neg).collect(),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	// The reference versions of the operators are tested too
+	#![allow(clippy::op_ref)]
+
+	use orml_vector::dynamic::{DVector, DimensionError};
+
+	use super::DMatrix;
+	use crate::{typedefs::Mat2i32, Matrix};
+
+	fn dmatrix(rows: Vec<Vec<i32>>) -> DMatrix<i32> {
+		rows.try_into().unwrap()
+	}
+
+	#[test]
+	fn new() {
+		let m: DMatrix<i32> = DMatrix::new(2, 3);
+		assert_eq!(m, dmatrix(vec![vec![0; 3]; 2]));
+		assert_eq!((m.rows(), m.cols()), (2, 3));
+		assert!(!m.is_square());
+		assert_eq!(DMatrix::identity(2), dmatrix(vec![vec![1, 0], vec![0, 1]]));
+
+		let m = dmatrix(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+		assert_eq!(m.row(1), vec![4, 5, 6].into());
+		assert_eq!(*m.col(2), vec![3, 6].into());
+		assert_eq!(m[(0, 1)], 2);
+		assert_eq!(m.to_string(), "[ 1, 2, 3, ]\n[ 4, 5, 6, ]");
+		assert_eq!(
+			DMatrix::try_from(vec![vec![1, 2], vec![3]]),
+			Err(DimensionError {
+				expected: 2,
+				found: 1
+			})
+		);
+	}
+
+	#[test]
+	fn transpose() {
+		let m = dmatrix(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+		let expected = dmatrix(vec![vec![1, 4], vec![2, 5], vec![3, 6]]);
+		assert_eq!(m.clone().transpose(), expected);
+		assert_eq!(m.clone().transpose().transpose(), m);
+	}
+
+	#[test]
+	fn arithmetic() {
+		let a = dmatrix(vec![vec![1, 2], vec![3, 4]]);
+		let b = dmatrix(vec![vec![4, 3], vec![2, 1]]);
+
+		assert_eq!(&a + &b, Ok(dmatrix(vec![vec![5, 5], vec![5, 5]])));
+		assert_eq!(a.clone() - &b, Ok(dmatrix(vec![vec![-3, -1], vec![1, 3]])));
+		assert_eq!(-a.clone(), dmatrix(vec![vec![-1, -2], vec![-3, -4]]));
+
+		let m1 = dmatrix(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+		let m2 = dmatrix(vec![vec![7, 8], vec![9, 10], vec![11, 12]]);
+		assert_eq!(&m1 * &m2, Ok(dmatrix(vec![vec![58, 64], vec![139, 154]])));
+		assert_eq!(&m1 * DVector::from(vec![1, 0, -1]), Ok(vec![-2, -2].into()));
+	}
+
+	#[test]
+	fn mismatched() {
+		let a = dmatrix(vec![vec![1, 2], vec![3, 4]]);
+		let b = dmatrix(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+		let err = DimensionError {
+			expected: 2,
+			found: 3,
+		};
+
+		assert_eq!(&a + &b, Err(err));
+		assert_eq!(
+			&b * &a,
+			Err(DimensionError {
+				expected: 3,
+				found: 2
+			})
+		);
+		assert_eq!(&a * DVector::from(vec![1, 2, 3]), Err(err));
+	}
+
+	#[test]
+	fn conversions() {
+		let fixed: Mat2i32 = [[1, 2], [3, 4]].into();
+		let dynamic = DMatrix::from(fixed);
+		assert_eq!(dynamic, dmatrix(vec![vec![1, 2], vec![3, 4]]));
+		assert_eq!(Mat2i32::try_from(dynamic.clone()), Ok(fixed));
+		assert!(Matrix::<i32, 2, 3>::try_from(dynamic).is_err());
+	}
+}
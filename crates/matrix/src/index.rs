@@ -0,0 +1,56 @@
+//! This module contains implementations for indexing operations
+//! on the elements of the Matrix
+
+use std::ops::{Index, IndexMut};
+
+use super::Matrix;
+
+/// Indexes the element at (row, column)
+impl<T, const R: usize, const C: usize> Index<(usize, usize)> for Matrix<T, R, C> {
+	type Output = T;
+
+	#[inline]
+	fn index(&self, (r, c): (usize, usize)) -> &Self::Output {
+		&self.cols[c][r]
+	}
+}
+
+impl<T, const R: usize, const C: usize> IndexMut<(usize, usize)> for Matrix<T, R, C> {
+	#[inline]
+	fn index_mut(&mut self, (r, c): (usize, usize)) -> &mut Self::Output {
+		&mut self.cols[c][r]
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::Matrix;
+
+	#[test]
+	fn valid_index() {
+		let mut m: Matrix<i32, 2, 3> = Matrix::new();
+		for r in 0..2 {
+			for c in 0..3 {
+				m[(r, c)] = (r * 3 + c) as i32;
+			}
+		}
+
+		let expected: Matrix<i32, 2, 3> = [[0, 1, 2], [3, 4, 5]].into();
+		assert_eq!(m, expected);
+		assert_eq!(m[(1, 0)], 3);
+	}
+
+	#[test]
+	#[should_panic]
+	fn invalid_row() {
+		let m: Matrix<i32, 2, 3> = Matrix::new();
+		let _ = m[(2, 0)];
+	}
+
+	#[test]
+	#[should_panic]
+	fn invalid_col() {
+		let m: Matrix<i32, 2, 3> = Matrix::new();
+		let _ = m[(0, 3)];
+	}
+}
@@ -0,0 +1,269 @@
+//! This module contains iterative solvers for linear systems A x = b
+//!
+//! The solvers only see A through [`LinearOperator`], so it can be a dense Matrix, a sparse
+//! Matrix or a closure that never stores A at all. The vectors go through [`VectorStorage`],
+//! which uses the dot and length of [`Vector`] and [`DVector`], so the same code runs for f64
+//! and EasyBigFloat. Every solver returns a [`Solution`] with the residual norm of each
+//! iteration, also when it stopped without converging
+
+use std::ops::{Add, Index, IndexMut, Mul};
+
+use orml_traits::fns::SquareRoot;
+use orml_vector::dynamic::{DVector, DimensionError};
+use orml_vector::Vector;
+
+use crate::decompositions::Real;
+use crate::dynamic::DMatrix;
+use crate::sparse::{CscMatrix, CsrMatrix};
+use crate::Matrix;
+
+pub mod bicgstab;
+pub mod cg;
+pub mod gmres;
+pub mod preconditioners;
+
+pub use bicgstab::bicgstab;
+pub use cg::{cg, pcg};
+pub use gmres::gmres;
+pub use preconditioners::{Identity, IncompleteCholesky, Jacobi, Preconditioner};
+
+/// A vector the iterative solvers can work on
+pub trait VectorStorage<T>: Clone + Index<usize, Output = T> + IndexMut<usize> {
+	/// Returns the number of elements
+	fn dim(&self) -> usize;
+
+	/// Returns the dot product with a vector of the same dimension
+	fn dot(&self, rhs: &Self) -> Result<T, DimensionError>;
+
+	/// Returns the length of the vector
+	fn length(&self) -> T;
+}
+
+impl<T, const N: usize> VectorStorage<T> for Vector<T, N>
+where
+	T: Clone + Add<Output = T> + Default + SquareRoot,
+	for<'a> &'a T: Mul<&'a T, Output = T>,
+{
+	#[inline]
+	fn dim(&self) -> usize {
+		N
+	}
+
+	#[inline]
+	fn dot(&self, rhs: &Self) -> Result<T, DimensionError> {
+		Ok(Vector::<T, N>::dot(self, rhs))
+	}
+
+	#[inline]
+	fn length(&self) -> T {
+		Vector::<T, N>::length(self)
+	}
+}
+
+impl<T> VectorStorage<T> for DVector<T>
+where
+	T: Clone + Add<Output = T> + Default + SquareRoot,
+	for<'a> &'a T: Mul<&'a T, Output = T>,
+{
+	#[inline]
+	fn dim(&self) -> usize {
+		self.len()
+	}
+
+	#[inline]
+	fn dot(&self, rhs: &Self) -> Result<T, DimensionError> {
+		DVector::<T>::dot(self, rhs)
+	}
+
+	#[inline]
+	fn length(&self) -> T {
+		DVector::<T>::length(self)
+	}
+}
+
+/// A linear map applied to vectors of type V, the A of A x = b
+pub trait LinearOperator<V> {
+	/// Returns A x
+	fn apply(&self, x: &V) -> Result<V, DimensionError>;
+}
+
+impl<T, const N: usize> LinearOperator<Vector<T, N>> for Matrix<T, N, N>
+where
+	T: Add<Output = T> + Default,
+	for<'a> &'a T: Mul<&'a T, Output = T>,
+{
+	#[inline]
+	fn apply(&self, x: &Vector<T, N>) -> Result<Vector<T, N>, DimensionError> {
+		Ok(self * x)
+	}
+}
+
+impl<T> LinearOperator<DVector<T>> for DMatrix<T>
+where
+	T: Add<Output = T> + Default,
+	for<'a> &'a T: Mul<&'a T, Output = T>,
+{
+	#[inline]
+	fn apply(&self, x: &DVector<T>) -> Result<DVector<T>, DimensionError> {
+		self * x
+	}
+}
+
+impl<T> LinearOperator<DVector<T>> for CsrMatrix<T>
+where
+	T: Add<Output = T> + Default,
+	for<'a> &'a T: Mul<&'a T, Output = T>,
+{
+	#[inline]
+	fn apply(&self, x: &DVector<T>) -> Result<DVector<T>, DimensionError> {
+		self * x
+	}
+}
+
+impl<T> LinearOperator<DVector<T>> for CscMatrix<T>
+where
+	T: Add<Output = T> + Default,
+	for<'a> &'a T: Mul<&'a T, Output = T>,
+{
+	#[inline]
+	fn apply(&self, x: &DVector<T>) -> Result<DVector<T>, DimensionError> {
+		self * x
+	}
+}
+
+/// Closures are matrix-free operators
+impl<V, F> LinearOperator<V> for F
+where
+	F: Fn(&V) -> V,
+{
+	#[inline]
+	fn apply(&self, x: &V) -> Result<V, DimensionError> {
+		Ok(self(x))
+	}
+}
+
+/// When the solvers stop
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SolverOptions<T> {
+	/// Converged once the residual norm is at most tolerance times the norm of b
+	pub tolerance: T,
+	/// The most iterations to run before giving up
+	pub max_iterations: usize,
+}
+
+impl<T> SolverOptions<T> {
+	/// Creates new SolverOptions
+	#[inline]
+	pub fn new(tolerance: T, max_iterations: usize) -> Self {
+		Self {
+			tolerance,
+			max_iterations,
+		}
+	}
+}
+
+/// The result of an iterative solver
+#[derive(Debug, Clone, PartialEq)]
+pub struct Solution<V, T> {
+	/// The last iterate
+	pub x: V,
+	/// The number of iterations run
+	pub iterations: usize,
+	/// The residual norm of the initial guess followed by that of each iteration
+	pub residuals: Vec<T>,
+	/// Whether the tolerance was reached
+	pub converged: bool,
+}
+
+/// Returns A x after checking it has the dimension n
+#[inline]
+pub(crate) fn apply<T, V, A>(a: &A, x: &V, n: usize) -> Result<V, DimensionError>
+where
+	V: VectorStorage<T>,
+	A: LinearOperator<V> + ?Sized,
+{
+	let y = a.apply(x)?;
+	DimensionError::check(n, y.dim())?;
+	Ok(y)
+}
+
+/// Returns b - A x and checks the dimensions of x and A x
+#[inline]
+pub(crate) fn residual<T, V, A>(a: &A, b: &V, x: &V) -> Result<V, DimensionError>
+where
+	T: Real,
+	V: VectorStorage<T>,
+	A: LinearOperator<V> + ?Sized,
+{
+	DimensionError::check(b.dim(), x.dim())?;
+	let ax = apply(a, x, b.dim())?;
+	let mut r = b.clone();
+	for i in 0..r.dim() {
+		r[i] = r[i].clone() - ax[i].clone();
+	}
+	Ok(r)
+}
+
+/// Sets y to y + alpha x
+#[inline]
+pub(crate) fn axpy<T, V>(y: &mut V, alpha: &T, x: &V)
+where
+	T: Real,
+	V: VectorStorage<T>,
+{
+	for i in 0..y.dim() {
+		y[i] = y[i].clone() + alpha.clone() * x[i].clone();
+	}
+}
+
+/// Returns a vector with the dimension of x and every element zero
+#[inline]
+pub(crate) fn zeros_like<T, V>(x: &V) -> V
+where
+	T: Real,
+	V: VectorStorage<T>,
+{
+	let mut z = x.clone();
+	for i in 0..z.dim() {
+		z[i] = T::zero();
+	}
+	z
+}
+
+/// Returns the residual norm below which a solver has converged
+#[inline]
+pub(crate) fn threshold<T, V>(b: &V, options: &SolverOptions<T>) -> T
+where
+	T: Real,
+	V: VectorStorage<T>,
+{
+	options.tolerance.clone() * b.length()
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+	use orml_vector::dynamic::DVector;
+
+	use crate::sparse::{CooMatrix, CsrMatrix};
+
+	/// The 1D Poisson matrix tridiag(-1, 2, -1), symmetric positive definite
+	pub(crate) fn poisson(n: usize) -> CsrMatrix<f64> {
+		let mut coo = CooMatrix::new(n, n);
+		for i in 0..n {
+			coo.push(i, i, 2.0);
+			if i > 0 {
+				coo.push(i, i - 1, -1.0);
+				coo.push(i - 1, i, -1.0);
+			}
+		}
+		coo.into()
+	}
+
+	/// Returns the largest difference between the elements
+	pub(crate) fn max_diff(a: &DVector<f64>, b: &DVector<f64>) -> f64 {
+		a.iter()
+			.zip(b.iter())
+			.map(|(a, b)| (a - b).abs())
+			.fold(0.0, f64::max)
+	}
+}
@@ -0,0 +1,145 @@
+//! This module contains the biconjugate gradient stabilised method for general A
+
+use orml_vector::dynamic::DimensionError;
+
+use super::{
+	apply, axpy, residual, threshold, zeros_like, LinearOperator, Solution, SolverOptions,
+	VectorStorage,
+};
+use crate::decompositions::Real;
+
+/// Solves A x = b by the biconjugate gradient stabilised method starting from x0
+///
+/// A only has to be nonsingular. It stops early if the method breaks down, which the residuals
+/// and converged of the [`Solution`] show
+pub fn bicgstab<T, V, A>(
+	a: &A,
+	b: &V,
+	x0: V,
+	options: &SolverOptions<T>,
+) -> Result<Solution<V, T>, DimensionError>
+where
+	T: Real,
+	V: VectorStorage<T>,
+	A: LinearOperator<V> + ?Sized,
+{
+	let threshold = threshold(b, options);
+	let mut x = x0;
+	let mut r = residual(a, b, &x)?;
+	let mut residuals = vec![r.length()];
+	let mut converged = residuals[0] <= threshold;
+
+	let r_hat = r.clone();
+	let (mut rho, mut alpha, mut omega) = (T::one(), T::one(), T::one());
+	let mut v = zeros_like(&r);
+	let mut p = zeros_like(&r);
+	let mut iterations = 0;
+	while !converged && iterations < options.max_iterations {
+		let rho_next = r_hat.dot(&r)?;
+		if rho_next.is_zero() || omega.is_zero() {
+			break;
+		}
+		let beta = (rho_next.clone() / rho) * (alpha / omega.clone());
+		for i in 0..p.dim() {
+			p[i] = r[i].clone() + beta.clone() * (p[i].clone() - omega.clone() * v[i].clone());
+		}
+		v = apply(a, &p, b.dim())?;
+		let r_hat_v = r_hat.dot(&v)?;
+		if r_hat_v.is_zero() {
+			break;
+		}
+		alpha = rho_next.clone() / r_hat_v;
+		axpy(&mut x, &alpha, &p);
+		// r becomes s = r - alpha v
+		axpy(&mut r, &-alpha.clone(), &v);
+		iterations += 1;
+
+		let norm = r.length();
+		if norm <= threshold {
+			converged = true;
+			residuals.push(norm);
+			break;
+		}
+
+		let t = apply(a, &r, b.dim())?;
+		let tt = t.dot(&t)?;
+		omega = if tt.is_zero() {
+			T::zero()
+		} else {
+			t.dot(&r)? / tt
+		};
+		axpy(&mut x, &omega, &r);
+		axpy(&mut r, &-omega.clone(), &t);
+
+		let norm = r.length();
+		converged = norm <= threshold;
+		residuals.push(norm);
+		rho = rho_next;
+	}
+
+	Ok(Solution {
+		x,
+		iterations,
+		residuals,
+		converged,
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use orml_vector::dynamic::DVector;
+
+	use super::bicgstab;
+	use crate::dynamic::DMatrix;
+	use crate::iterative::tests::max_diff;
+	use crate::iterative::SolverOptions;
+	use crate::sparse::{CooMatrix, CsrMatrix};
+
+	/// A convection-diffusion matrix tridiag(-1.5, 2, -0.5), which is not symmetric
+	fn convection(n: usize) -> CsrMatrix<f64> {
+		let mut coo = CooMatrix::new(n, n);
+		for i in 0..n {
+			coo.push(i, i, 2.0);
+			if i > 0 {
+				coo.push(i, i - 1, -1.5);
+				coo.push(i - 1, i, -0.5);
+			}
+		}
+		coo.into()
+	}
+
+	#[test]
+	fn nonsymmetric() {
+		let n = 40;
+		let a = convection(n);
+		let expected: DVector<f64> = (0..n).map(|i| 1.0 + (i % 3) as f64).collect();
+		let b = (&a * &expected).unwrap();
+
+		let solution = bicgstab(&a, &b, DVector::new(n), &SolverOptions::new(1e-12, 200)).unwrap();
+		assert!(solution.converged);
+		assert_eq!(solution.residuals.len(), solution.iterations + 1);
+		assert!(max_diff(&solution.x, &expected) < 1e-9);
+	}
+
+	#[test]
+	fn dense() {
+		let a: DMatrix<f64> = vec![
+			vec![3.0, 1.0, 0.0],
+			vec![-1.0, 2.0, 1.0],
+			vec![0.0, 1.0, 4.0],
+		]
+		.try_into()
+		.unwrap();
+		let expected: DVector<f64> = vec![1.0, -2.0, 0.5].into();
+		let b = (&a * &expected).unwrap();
+
+		let solution = bicgstab(&a, &b, DVector::new(3), &SolverOptions::new(1e-14, 20)).unwrap();
+		assert!(solution.converged);
+		assert!(max_diff(&solution.x, &expected) < 1e-12);
+
+		// Starting at the solution needs no iterations
+		let solution = bicgstab(&a, &b, expected, &SolverOptions::new(1e-14, 20)).unwrap();
+		assert!(solution.converged);
+		assert_eq!(solution.iterations, 0);
+	}
+}
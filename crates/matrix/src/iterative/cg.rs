@@ -0,0 +1,187 @@
+//! This module contains the conjugate gradient method for symmetric positive definite A
+
+use orml_vector::dynamic::DimensionError;
+
+use super::preconditioners::{Identity, Preconditioner};
+use super::{
+	apply, axpy, residual, threshold, LinearOperator, Solution, SolverOptions, VectorStorage,
+};
+use crate::decompositions::Real;
+
+/// Solves A x = b by the conjugate gradient method starting from x0
+///
+/// A has to be symmetric positive definite
+#[inline]
+pub fn cg<T, V, A>(
+	a: &A,
+	b: &V,
+	x0: V,
+	options: &SolverOptions<T>,
+) -> Result<Solution<V, T>, DimensionError>
+where
+	T: Real,
+	V: VectorStorage<T>,
+	A: LinearOperator<V> + ?Sized,
+{
+	pcg(a, &Identity, b, x0, options)
+}
+
+/// Solves A x = b by the conjugate gradient method preconditioned by M, starting from x0
+///
+/// A and M have to be symmetric positive definite
+pub fn pcg<T, V, A, M>(
+	a: &A,
+	m: &M,
+	b: &V,
+	x0: V,
+	options: &SolverOptions<T>,
+) -> Result<Solution<V, T>, DimensionError>
+where
+	T: Real,
+	V: VectorStorage<T>,
+	A: LinearOperator<V> + ?Sized,
+	M: Preconditioner<V> + ?Sized,
+{
+	let threshold = threshold(b, options);
+	let mut x = x0;
+	let mut r = residual(a, b, &x)?;
+	let mut residuals = vec![r.length()];
+	let mut converged = residuals[0] <= threshold;
+
+	let mut z = m.apply(&r)?;
+	let mut p = z.clone();
+	let mut rz = r.dot(&z)?;
+	let mut iterations = 0;
+	while !converged && iterations < options.max_iterations {
+		let ap = apply(a, &p, b.dim())?;
+		let pap = p.dot(&ap)?;
+		if pap.is_zero() {
+			break;
+		}
+		let alpha = rz.clone() / pap;
+		axpy(&mut x, &alpha, &p);
+		axpy(&mut r, &-alpha, &ap);
+		iterations += 1;
+
+		let norm = r.length();
+		converged = norm <= threshold;
+		residuals.push(norm);
+
+		z = m.apply(&r)?;
+		let rz_next = r.dot(&z)?;
+		let beta = rz_next.clone() / rz;
+		for i in 0..p.dim() {
+			p[i] = z[i].clone() + beta.clone() * p[i].clone();
+		}
+		rz = rz_next;
+	}
+
+	Ok(Solution {
+		x,
+		iterations,
+		residuals,
+		converged,
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use orml_easy_big_float::EasyBigFloat;
+	use orml_vector::dynamic::{DVector, DimensionError};
+	use orml_vector::Vector;
+
+	use super::{cg, pcg};
+	use crate::iterative::tests::{max_diff, poisson};
+	use crate::iterative::{IncompleteCholesky, Jacobi, SolverOptions};
+	use crate::Matrix;
+
+	#[test]
+	fn poisson_system() {
+		let n = 50;
+		let a = poisson(n);
+		let expected: DVector<f64> = (0..n).map(|i| (i as f64 * 0.1).sin()).collect();
+		let b = (&a * &expected).unwrap();
+		let options = SolverOptions::new(1e-12, 200);
+
+		let plain = cg(&a, &b, DVector::new(n), &options).unwrap();
+		assert!(plain.converged);
+		// Exact arithmetic needs at most n iterations
+		assert!(plain.iterations <= n + 5);
+		assert_eq!(plain.residuals.len(), plain.iterations + 1);
+		assert!(max_diff(&plain.x, &expected) < 1e-9);
+
+		let jacobi = pcg(&a, &Jacobi::from_csr(&a), &b, DVector::new(n), &options).unwrap();
+		assert!(jacobi.converged);
+		assert!(max_diff(&jacobi.x, &expected) < 1e-9);
+
+		// IC(0) is exact for a tridiagonal matrix
+		let ic = IncompleteCholesky::new(&a).unwrap();
+		let ic = pcg(&a, &ic, &b, DVector::new(n), &options).unwrap();
+		assert!(ic.converged);
+		assert!(ic.iterations <= 2);
+		assert!(max_diff(&ic.x, &expected) < 1e-9);
+	}
+
+	#[test]
+	fn stops() {
+		let a = poisson(20);
+		let b: DVector<f64> = vec![1.0; 20].into();
+		let solution = cg(&a, &b, DVector::new(20), &SolverOptions::new(1e-12, 3)).unwrap();
+		assert!(!solution.converged);
+		assert_eq!(solution.iterations, 3);
+		assert_eq!(solution.residuals.len(), 4);
+
+		assert_eq!(
+			cg(&a, &b, DVector::new(3), &SolverOptions::new(1e-12, 3)),
+			Err(DimensionError {
+				expected: 20,
+				found: 3
+			})
+		);
+		// A preconditioner of the wrong size is an error too, not a panic
+		let m = Jacobi::from_csr(&poisson(10));
+		assert_eq!(
+			pcg(&a, &m, &b, DVector::new(20), &SolverOptions::new(1e-12, 3)),
+			Err(DimensionError {
+				expected: 10,
+				found: 20
+			})
+		);
+	}
+
+	#[test]
+	fn matrix_free() {
+		// A = diag(1, 2, 3) as a closure on fixed size Vectors
+		let a = |x: &Vector<f64, 3>| Vector::from_array([x[0], 2.0 * x[1], 3.0 * x[2]]);
+		let b = Vector::from_array([1.0, 1.0, 1.0]);
+		let solution = cg(&a, &b, Vector::new(), &SolverOptions::new(1e-14, 10)).unwrap();
+		assert!(solution.converged);
+		assert!((solution.x[2] - 1.0 / 3.0).abs() < 1e-14);
+
+		let m: Matrix<f64, 2, 2> = [[4.0, 1.0], [1.0, 3.0]].into();
+		let solution = cg(
+			&m,
+			&Vector::from_array([1.0, 2.0]),
+			Vector::new(),
+			&SolverOptions::new(1e-14, 10),
+		)
+		.unwrap();
+		assert!((solution.x[0] - 1.0 / 11.0).abs() < 1e-14);
+		assert!((solution.x[1] - 7.0 / 11.0).abs() < 1e-14);
+	}
+
+	#[test]
+	fn big_float() {
+		type BF = EasyBigFloat<256>;
+
+		let m: Matrix<BF, 2, 2> = [[4.0, 1.0], [1.0, 3.0]].map(|r| r.map(BF::from)).into();
+		let b = Vector::from_array([1.0, 2.0].map(BF::from));
+		let tol = BF::from(2f64.powi(-240));
+		let solution = cg(&m, &b, Vector::new(), &SolverOptions::new(tol.clone(), 10)).unwrap();
+		assert!(solution.converged);
+
+		let expected = BF::from(7.0) / BF::from(11.0);
+		let d = solution.x[1].clone() - expected;
+		assert!(d < tol && -d < tol);
+	}
+}
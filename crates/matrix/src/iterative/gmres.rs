@@ -0,0 +1,187 @@
+//! This module contains the restarted generalised minimal residual method for general A
+
+use orml_vector::dynamic::DimensionError;
+
+use super::{
+	apply, axpy, residual, threshold, LinearOperator, Solution, SolverOptions, VectorStorage,
+};
+use crate::decompositions::Real;
+
+/// Solves A x = b by GMRES restarted every restart iterations, starting from x0
+///
+/// Each cycle builds an orthonormal Krylov basis of up to restart vectors and picks the x that
+/// minimises the residual over it. The residuals of the [`Solution`] are those estimated by the
+/// Givens rotations, which are exact up to rounding
+///
+/// # Panics
+/// If restart is 0
+pub fn gmres<T, V, A>(
+	a: &A,
+	b: &V,
+	x0: V,
+	restart: usize,
+	options: &SolverOptions<T>,
+) -> Result<Solution<V, T>, DimensionError>
+where
+	T: Real,
+	V: VectorStorage<T>,
+	A: LinearOperator<V> + ?Sized,
+{
+	assert!(restart > 0, "GMRES needs a restart of at least 1");
+	let threshold = threshold(b, options);
+	let mut x = x0;
+	let mut r = residual(a, b, &x)?;
+	let mut beta = r.length();
+	let mut residuals = vec![beta.clone()];
+	let mut converged = beta <= threshold;
+	let mut iterations = 0;
+
+	while !converged && iterations < options.max_iterations {
+		// basis[j] is the jth Krylov vector, h[j] the jth column of the Hessenberg matrix
+		let mut basis = Vec::with_capacity(restart + 1);
+		let mut h: Vec<Vec<T>> = Vec::with_capacity(restart);
+		let mut rotations: Vec<(T, T)> = Vec::with_capacity(restart);
+		let mut g = vec![beta.clone()];
+		let inv_beta = T::one() / beta.clone();
+		let mut v = r.clone();
+		for i in 0..v.dim() {
+			v[i] = v[i].clone() * inv_beta.clone();
+		}
+		basis.push(v);
+
+		while basis.len() <= restart && iterations < options.max_iterations {
+			let j = basis.len() - 1;
+			let mut w = apply(a, &basis[j], b.dim())?;
+			let mut col = Vec::with_capacity(j + 2);
+			// Modified Gram-Schmidt
+			for v in &basis {
+				let hij = w.dot(v)?;
+				axpy(&mut w, &-hij.clone(), v);
+				col.push(hij);
+			}
+			let norm = w.length();
+			col.push(norm.clone());
+
+			for (i, (c, s)) in rotations.iter().enumerate() {
+				let (hi, hi1) = (col[i].clone(), col[i + 1].clone());
+				col[i] = c.clone() * hi.clone() + s.clone() * hi1.clone();
+				col[i + 1] = c.clone() * hi1 - s.clone() * hi;
+			}
+			let d = col[j].clone().hypot(col[j + 1].clone());
+			let (c, s) = if d.is_zero() {
+				(T::one(), T::zero())
+			} else {
+				(col[j].clone() / d.clone(), col[j + 1].clone() / d.clone())
+			};
+			col[j] = d;
+			col[j + 1] = T::zero();
+			g.push(-s.clone() * g[j].clone());
+			g[j] = c.clone() * g[j].clone();
+			rotations.push((c, s));
+			h.push(col);
+			iterations += 1;
+
+			let estimate = g[j + 1].clone().abs();
+			converged = estimate <= threshold;
+			residuals.push(estimate);
+			// A zero norm means the basis spans the solution
+			if converged || norm.is_zero() {
+				break;
+			}
+			let inv_norm = T::one() / norm;
+			for i in 0..w.dim() {
+				w[i] = w[i].clone() * inv_norm.clone();
+			}
+			basis.push(w);
+		}
+
+		// Back substitution of the triangular system H y = g
+		let k = h.len();
+		let mut y = g;
+		y.truncate(k);
+		for i in (0..k).rev() {
+			for jj in i + 1..k {
+				y[i] = y[i].clone() - h[jj][i].clone() * y[jj].clone();
+			}
+			y[i] = y[i].clone() / h[i][i].clone();
+		}
+		for (yi, v) in y.iter().zip(&basis) {
+			axpy(&mut x, yi, v);
+		}
+
+		if !converged {
+			r = residual(a, b, &x)?;
+			beta = r.length();
+			converged = beta <= threshold;
+		}
+	}
+
+	Ok(Solution {
+		x,
+		iterations,
+		residuals,
+		converged,
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use orml_vector::dynamic::DVector;
+
+	use super::gmres;
+	use crate::dynamic::DMatrix;
+	use crate::iterative::tests::{max_diff, poisson};
+	use crate::iterative::SolverOptions;
+
+	#[test]
+	fn restarted() {
+		let n = 30;
+		let a = poisson(n);
+		let expected: DVector<f64> = (0..n).map(|i| (i as f64).cos()).collect();
+		let b = (&a * &expected).unwrap();
+
+		// Without a restart GMRES finishes in at most n iterations
+		let full = gmres(&a, &b, DVector::new(n), n, &SolverOptions::new(1e-12, 100)).unwrap();
+		assert!(full.converged);
+		assert!(full.iterations <= n);
+		assert!(max_diff(&full.x, &expected) < 1e-9);
+		// The residual never grows
+		assert!(full
+			.residuals
+			.windows(2)
+			.all(|w| w[1] <= w[0] * (1.0 + 1e-12)));
+
+		let restarted = gmres(
+			&a,
+			&b,
+			DVector::new(n),
+			10,
+			&SolverOptions::new(1e-10, 2000),
+		)
+		.unwrap();
+		assert!(restarted.converged);
+		assert!(restarted.iterations > full.iterations);
+		assert_eq!(restarted.residuals.len(), restarted.iterations + 1);
+		let r = (&a * &restarted.x).unwrap();
+		assert!(max_diff(&r, &b) < 1e-8);
+	}
+
+	#[test]
+	fn nonsymmetric() {
+		let a: DMatrix<f64> = vec![
+			vec![1.0, 2.0, 0.0, 0.0],
+			vec![0.0, 1.0, 2.0, 0.0],
+			vec![0.0, 0.0, 1.0, 2.0],
+			vec![3.0, 0.0, 0.0, 1.0],
+		]
+		.try_into()
+		.unwrap();
+		let expected: DVector<f64> = vec![1.0, 2.0, 3.0, 4.0].into();
+		let b = (&a * &expected).unwrap();
+
+		let solution = gmres(&a, &b, DVector::new(4), 4, &SolverOptions::new(1e-14, 10)).unwrap();
+		assert!(solution.converged);
+		assert_eq!(solution.iterations, 4);
+		assert!(max_diff(&solution.x, &expected) < 1e-12);
+	}
+}
@@ -0,0 +1,246 @@
+//! This module contains the preconditioners for [`super::pcg`]
+
+use orml_vector::dynamic::DimensionError;
+
+use super::VectorStorage;
+use crate::decompositions::{DecompositionError, Real};
+use crate::sparse::{CooMatrix, CsrMatrix};
+
+/// An approximation M of A that is cheap to invert
+pub trait Preconditioner<V> {
+	/// Returns M⁻¹ r
+	fn apply(&self, r: &V) -> Result<V, DimensionError>;
+}
+
+/// The preconditioner M = I, which leaves the solver unchanged
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Identity;
+
+impl<V> Preconditioner<V> for Identity
+where
+	V: Clone,
+{
+	#[inline]
+	fn apply(&self, r: &V) -> Result<V, DimensionError> {
+		Ok(r.clone())
+	}
+}
+
+/// The preconditioner M = diag(A)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Jacobi<T> {
+	inv_diagonal: Vec<T>,
+}
+
+impl<T> Jacobi<T>
+where
+	T: Real,
+{
+	/// Creates a new Jacobi preconditioner from the diagonal of A, which must have no zeros
+	#[inline]
+	pub fn from_diagonal(diagonal: impl IntoIterator<Item = T>) -> Self {
+		Self {
+			inv_diagonal: diagonal.into_iter().map(|d| T::one() / d).collect(),
+		}
+	}
+
+	/// Creates a new Jacobi preconditioner from a square CsrMatrix with no zeros on its diagonal
+	#[inline]
+	pub fn from_csr(a: &CsrMatrix<T>) -> Self {
+		Self::from_diagonal((0..a.rows()).map(|i| a.get(i, i).cloned().unwrap_or_else(T::zero)))
+	}
+}
+
+impl<T, V> Preconditioner<V> for Jacobi<T>
+where
+	T: Real,
+	V: VectorStorage<T>,
+{
+	#[inline]
+	fn apply(&self, r: &V) -> Result<V, DimensionError> {
+		DimensionError::check(self.inv_diagonal.len(), r.dim())?;
+		let mut z = r.clone();
+		for (i, d) in self.inv_diagonal.iter().enumerate() {
+			z[i] = z[i].clone() * d.clone();
+		}
+		Ok(z)
+	}
+}
+
+/// The zero fill-in incomplete Cholesky preconditioner M = L Lᵀ
+///
+/// L is lower triangular and only has elements where A does, so it stays as sparse as A
+#[derive(Debug, Clone, PartialEq)]
+pub struct IncompleteCholesky<T> {
+	l: CsrMatrix<T>,
+}
+
+impl<T> IncompleteCholesky<T>
+where
+	T: Real,
+{
+	/// Creates a new IncompleteCholesky preconditioner from a symmetric positive definite CsrMatrix
+	pub fn new(a: &CsrMatrix<T>) -> Result<Self, DecompositionError> {
+		let n = a.rows();
+		if a.cols() != n || a.iter().any(|(i, j, v)| a.get(j, i) != Some(v)) {
+			return Err(DecompositionError::NotSymmetric);
+		}
+
+		// The rows of L as (col, value) sorted by col, the diagonal last
+		let mut rows: Vec<Vec<(usize, T)>> = Vec::with_capacity(n);
+		for i in 0..n {
+			let mut row: Vec<(usize, T)> = Vec::new();
+			for (j, aij) in a.row(i).take_while(|(j, _)| *j <= i) {
+				let other = if j < i { &rows[j] } else { &row };
+				let s = aij.clone() - sparse_dot(&row, other);
+				if j < i {
+					// Only reached once row j is finished, so its diagonal is its last element
+					let ljj = rows[j][rows[j].len() - 1].1.clone();
+					row.push((j, s / ljj));
+				} else if s > T::zero() {
+					row.push((i, s.sqrt()));
+				} else {
+					return Err(DecompositionError::NotPositiveDefinite);
+				}
+			}
+			if row.last().map(|(j, _)| *j) != Some(i) {
+				return Err(DecompositionError::NotPositiveDefinite);
+			}
+			rows.push(row);
+		}
+
+		let mut l = CooMatrix::with_capacity(n, n, rows.iter().map(Vec::len).sum());
+		for (i, row) in rows.into_iter().enumerate() {
+			for (j, v) in row {
+				l.push(i, j, v);
+			}
+		}
+		Ok(Self { l: l.into() })
+	}
+
+	/// Returns the lower triangular factor L
+	#[inline]
+	pub fn l(&self) -> &CsrMatrix<T> {
+		&self.l
+	}
+}
+
+/// Returns the sum of the products of the elements at the cols both sparse rows store
+fn sparse_dot<T>(a: &[(usize, T)], b: &[(usize, T)]) -> T
+where
+	T: Real,
+{
+	let (mut i, mut j) = (0, 0);
+	let mut sum = T::zero();
+	while i < a.len() && j < b.len() {
+		if a[i].0 == b[j].0 {
+			sum += a[i].1.clone() * b[j].1.clone();
+			i += 1;
+			j += 1;
+		} else if a[i].0 < b[j].0 {
+			i += 1;
+		} else {
+			j += 1;
+		}
+	}
+	sum
+}
+
+impl<T, V> Preconditioner<V> for IncompleteCholesky<T>
+where
+	T: Real,
+	V: VectorStorage<T>,
+{
+	/// Solves L Lᵀ z = r by a forward and a backward substitution
+	fn apply(&self, r: &V) -> Result<V, DimensionError> {
+		let n = self.l.rows();
+		DimensionError::check(n, r.dim())?;
+
+		// L y = r
+		let mut z = r.clone();
+		for i in 0..n {
+			let mut s = z[i].clone();
+			let mut diag = T::one();
+			for (j, v) in self.l.row(i) {
+				if j < i {
+					s -= v.clone() * z[j].clone();
+				} else {
+					diag = v.clone();
+				}
+			}
+			z[i] = s / diag;
+		}
+
+		// Lᵀ z = y, going up the rows of L as the columns of Lᵀ
+		for i in (0..n).rev() {
+			// Every row of L ends with its diagonal
+			let diag = self.l.row(i).last().map_or_else(T::one, |(_, d)| d.clone());
+			z[i] = z[i].clone() / diag;
+			for (j, v) in self.l.row(i).filter(|(j, _)| *j < i) {
+				z[j] = z[j].clone() - v.clone() * z[i].clone();
+			}
+		}
+		Ok(z)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use orml_vector::dynamic::{DVector, DimensionError};
+
+	use super::{IncompleteCholesky, Jacobi, Preconditioner};
+	use crate::decompositions::DecompositionError;
+	use crate::iterative::tests::{max_diff, poisson};
+	use crate::sparse::CooMatrix;
+
+	#[test]
+	fn jacobi() {
+		let m = Jacobi::from_csr(&poisson(3));
+		let z: DVector<f64> = m.apply(&vec![2.0, 4.0, 1.0].into()).unwrap();
+		assert_eq!(z, vec![1.0, 2.0, 0.5].into());
+		let err = DimensionError {
+			expected: 3,
+			found: 2,
+		};
+		assert_eq!(m.apply(&DVector::from(vec![2.0, 4.0])), Err(err));
+	}
+
+	#[test]
+	fn incomplete_cholesky() {
+		// A tridiagonal matrix has no fill-in, so IC(0) is the exact Cholesky factor
+		let a = poisson(6);
+		let ic = IncompleteCholesky::new(&a).unwrap();
+		let l = ic.l().to_dense();
+		let llt = (&l * &l.clone().transpose()).unwrap();
+		for r in 0..6 {
+			for c in 0..6 {
+				assert!((llt[(r, c)] - a.get(r, c).copied().unwrap_or(0.0)).abs() < 1e-14);
+			}
+		}
+
+		let b: DVector<f64> = vec![1.0, 0.0, 2.0, -1.0, 0.5, 3.0].into();
+		let x = ic.apply(&b).unwrap();
+		assert!(max_diff(&(&a * &x).unwrap(), &b) < 1e-13);
+		assert!(ic.apply(&DVector::from(vec![1.0; 5])).is_err());
+	}
+
+	#[test]
+	fn invalid() {
+		let mut coo = CooMatrix::new(2, 2);
+		coo.push(0, 0, 1.0);
+		coo.push(1, 0, 2.0);
+		coo.push(1, 1, 1.0);
+		let a = coo.clone().into();
+		assert_eq!(
+			IncompleteCholesky::new(&a),
+			Err(DecompositionError::NotSymmetric)
+		);
+
+		coo.push(0, 1, 2.0);
+		let a = coo.into();
+		assert_eq!(
+			IncompleteCholesky::new(&a),
+			Err(DecompositionError::NotPositiveDefinite)
+		);
+	}
+}
@@ -0,0 +1,261 @@
+#![warn(missing_docs)]
+#![allow(dead_code)]
+
+//! Mathimatical Matrices
+//!
+//! This module contains a generic Matrix (Matrix<T, const R: usize, const C: usize>) stored as
+//! C column [`Vector`]s of length R and type defs of commonly used matrices i.e Mat4f64
+
+use std::array;
+use std::fmt::Display;
+
+use num_traits::{One, Zero};
+use orml_vector::Vector;
+
+pub mod arithmetic;
+pub mod decompositions;
+pub mod dynamic;
+pub mod index;
+pub mod iterative;
+pub mod lu;
+pub mod ordering;
+pub mod sparse;
+pub mod typedefs;
+
+/// A Mathimatical Matrix
+/// Takes in a type T as the underlying type, R as the number of rows and C as the number of
+/// columns
+#[derive(Debug)]
+pub struct Matrix<T, const R: usize, const C: usize> {
+	cols: [Vector<T, R>; C],
+}
+
+impl<T, const R: usize, const C: usize> Matrix<T, R, C> {
+	/// Creates a new Matrix<T,R,C> from its columns
+	#[inline]
+	pub fn from_cols(cols: [Vector<T, R>; C]) -> Self {
+		Self { cols }
+	}
+
+	/// Creates a new Matrix<T,R,C> from its rows
+	#[inline]
+	pub fn from_rows(rows: [Vector<T, C>; R]) -> Self {
+		let mut rows = rows.map(IntoIterator::into_iter);
+		Self {
+			cols: array::from_fn(|_| {
+				Vector::from_array(array::from_fn(|r| {
+					// Every row has exactly C elements so this is taken C times
+					rows[r].next().unwrap()
+				}))
+			}),
+		}
+	}
+
+	/// Returns the columns of the Matrix
+	#[inline]
+	pub fn to_cols(self) -> [Vector<T, R>; C] {
+		self.cols
+	}
+
+	/// Returns the rows of the Matrix
+	#[inline]
+	pub fn to_rows(self) -> [Vector<T, C>; R] {
+		self.transpose().cols
+	}
+
+	/// Returns a reference to the column at index c
+	#[inline]
+	pub fn col(&self, c: usize) -> &Vector<T, R> {
+		&self.cols[c]
+	}
+
+	/// Returns a mutable reference to the column at index c
+	#[inline]
+	pub fn col_mut(&mut self, c: usize) -> &mut Vector<T, R> {
+		&mut self.cols[c]
+	}
+
+	/// Returns a copy of the row at index r
+	#[inline]
+	pub fn row(&self, r: usize) -> Vector<T, C>
+	where
+		T: Clone,
+	{
+		Vector::from_array(array::from_fn(|c| self.cols[c][r].clone()))
+	}
+
+	/// Returns the Matrix with its rows and columns swapped
+	#[inline]
+	pub fn transpose(self) -> Matrix<T, C, R> {
+		Matrix::from_rows(self.cols)
+	}
+
+	/// Returns the number of rows of the Matrix
+	#[inline]
+	pub fn rows(&self) -> usize {
+		R
+	}
+
+	/// Returns the number of columns of the Matrix
+	#[inline]
+	pub fn cols(&self) -> usize {
+		C
+	}
+
+	/// Returns true if the Matrix is square
+	#[inline]
+	pub fn is_square(&self) -> bool {
+		R == C
+	}
+
+	/// Returns an iter over the columns of the Matrix
+	#[inline]
+	pub fn iter_cols(&self) -> std::slice::Iter<'_, Vector<T, R>> {
+		self.cols.iter()
+	}
+}
+
+impl<T, const R: usize, const C: usize> Matrix<T, R, C>
+where
+	T: Default,
+{
+	/// Create a new Matrix with every element set to the default
+	#[inline]
+	pub fn new() -> Self {
+		Self {
+			cols: array::from_fn(|_| Vector::new()),
+		}
+	}
+}
+
+impl<T, const N: usize> Matrix<T, N, N>
+where
+	T: Zero + One,
+{
+	/// Returns the N by N identity Matrix
+	#[inline]
+	pub fn identity() -> Self {
+		Self {
+			cols: array::from_fn(|c| {
+				Vector::from_array(array::from_fn(
+					|r| {
+						if r == c {
+							T::one()
+						} else {
+							T::zero()
+						}
+					},
+				))
+			}),
+		}
+	}
+}
+
+impl<T, const R: usize, const C: usize> Default for Matrix<T, R, C>
+where
+	T: Default,
+{
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+/// Creates a Matrix from rows of elements so that literals read as the Matrix is written
+impl<T, const R: usize, const C: usize> From<[[T; C]; R]> for Matrix<T, R, C> {
+	#[inline]
+	fn from(value: [[T; C]; R]) -> Self {
+		Self::from_rows(value.map(Vector::from_array))
+	}
+}
+
+impl<T, const R: usize, const C: usize> Clone for Matrix<T, R, C>
+where
+	T: Clone,
+{
+	#[inline]
+	fn clone(&self) -> Self {
+		Self {
+			cols: self.cols.clone(),
+		}
+	}
+}
+
+impl<T, const R: usize, const C: usize> Copy for Matrix<T, R, C> where T: Copy {}
+
+impl<T, const R: usize, const C: usize> Display for Matrix<T, R, C>
+where
+	T: Display,
+{
+	#[inline]
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		for r in 0..R {
+			if r > 0 {
+				writeln!(f)?;
+			}
+			write!(f, "[")?;
+			for col in &self.cols {
+				write!(f, " {},", col[r])?;
+			}
+			write!(f, " ]")?;
+		}
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use orml_vector::Vector;
+
+	use super::*;
+	use crate::typedefs::{Mat2i32, Mat3f64};
+
+	#[test]
+	fn new() {
+		let m1: Matrix<i32, 3, 5> = Matrix::new();
+		let m2: Matrix<i32, 3, 5> = [[0; 5]; 3].into();
+		assert_eq!(m1, m2);
+		assert_eq!(m1, Default::default());
+	}
+
+	#[test]
+	fn rows_and_cols() {
+		let m: Matrix<i32, 2, 3> = [[1, 2, 3], [4, 5, 6]].into();
+		assert_eq!(m.rows(), 2);
+		assert_eq!(m.cols(), 3);
+		assert!(!m.is_square());
+		assert_eq!(*m.col(1), Vector::from_array([2, 5]));
+		assert_eq!(m.row(1), Vector::from_array([4, 5, 6]));
+
+		let cols = [[1, 4].into(), [2, 5].into(), [3, 6].into()];
+		assert_eq!(Matrix::from_cols(cols), m);
+		assert_eq!(m.to_cols(), cols);
+		assert_eq!(m.to_rows(), [[1, 2, 3].into(), [4, 5, 6].into()]);
+	}
+
+	#[test]
+	fn transpose() {
+		let m: Matrix<i32, 2, 3> = [[1, 2, 3], [4, 5, 6]].into();
+		let expected: Matrix<i32, 3, 2> = [[1, 4], [2, 5], [3, 6]].into();
+		assert_eq!(m.transpose(), expected);
+		assert_eq!(m.transpose().transpose(), m);
+	}
+
+	#[test]
+	fn identity() {
+		let expected: Mat3f64 = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]].into();
+		assert_eq!(Mat3f64::identity(), expected);
+	}
+
+	#[test]
+	fn clone() {
+		let m: Matrix<String, 1, 2> = [["a".to_string(), "b".to_string()]].into();
+		assert_eq!(m, m.clone());
+	}
+
+	#[test]
+	fn display() {
+		let m: Mat2i32 = [[1, 2], [3, 4]].into();
+		assert_eq!(m.to_string(), "[ 1, 2, ]\n[ 3, 4, ]");
+	}
+}
@@ -0,0 +1,322 @@
+//! This module contains the LU decomposition of square Matrices and the determinant, inverse
+//! and linear solves built on it
+//!
+//! Everything here only needs field arithmetic and an ordering, so it works the same for
+//! floats, big floats and exact types like fractions. A matrix is treated as singular when a
+//! pivot is exactly zero, there is no tolerance for floats
+
+use std::array;
+use std::ops::{Div, Mul, Neg, Sub};
+
+use num_traits::{One, Zero};
+use orml_vector::Vector;
+
+use super::Matrix;
+
+/// The LU decomposition with partial pivoting P A = L U of a square Matrix A
+///
+/// L is unit lower triangular and U is upper triangular, they are stored together with the
+/// unit diagonal of L left implicit. Created by [`Matrix::lu`]
+#[derive(Debug, Clone)]
+pub struct Lu<T, const N: usize> {
+	lu: Matrix<T, N, N>,
+	// Row i of P A is row perm[i] of A
+	perm: [usize; N],
+	// Whether P is made of an even number of row swaps
+	even: bool,
+}
+
+/// Returns |x| using only the ordering so any signed field can be used
+#[inline]
+fn abs<T>(x: &T) -> T
+where
+	T: Clone + Zero + PartialOrd + Neg<Output = T>,
+{
+	if *x < T::zero() {
+		-x.clone()
+	} else {
+		x.clone()
+	}
+}
+
+impl<T, const N: usize> Matrix<T, N, N>
+where
+	T: Clone
+		+ Zero
+		+ One
+		+ PartialOrd
+		+ Neg<Output = T>
+		+ Sub<Output = T>
+		+ Mul<Output = T>
+		+ Div<Output = T>,
+{
+	/// Returns the LU decomposition with partial pivoting of the Matrix
+	///
+	/// This always succeeds, a singular Matrix gives a U with a zero on its diagonal
+	pub fn lu(&self) -> Lu<T, N> {
+		let mut lu = self.clone();
+		let mut perm = array::from_fn(|i| i);
+		let mut even = true;
+
+		for k in 0..N {
+			// Pivot on the largest magnitude in the column to keep floats stable
+			let mut p = k;
+			let mut max = abs(&lu[(k, k)]);
+			for r in k + 1..N {
+				let a = abs(&lu[(r, k)]);
+				if a > max {
+					p = r;
+					max = a;
+				}
+			}
+			if max.is_zero() {
+				// The column is already eliminated
+				continue;
+			}
+			if p != k {
+				for c in 0..N {
+					let temp = lu[(p, c)].clone();
+					lu[(p, c)] = std::mem::replace(&mut lu[(k, c)], temp);
+				}
+				perm.swap(p, k);
+				even = !even;
+			}
+
+			for r in k + 1..N {
+				let f = lu[(r, k)].clone() / lu[(k, k)].clone();
+				for c in k + 1..N {
+					lu[(r, c)] = lu[(r, c)].clone() - f.clone() * lu[(k, c)].clone();
+				}
+				lu[(r, k)] = f;
+			}
+		}
+
+		Lu { lu, perm, even }
+	}
+
+	/// Returns the determinant of the Matrix
+	#[inline]
+	pub fn determinant(&self) -> T {
+		self.lu().determinant()
+	}
+
+	/// Returns the inverse of the Matrix or None if it is singular
+	#[inline]
+	pub fn inverse(&self) -> Option<Self> {
+		self.lu().inverse()
+	}
+
+	/// Returns the x that solves A x = b for this Matrix A or None if it is singular
+	#[inline]
+	pub fn solve(&self, b: &Vector<T, N>) -> Option<Vector<T, N>> {
+		self.lu().solve(b)
+	}
+}
+
+impl<T, const N: usize> Lu<T, N>
+where
+	T: Clone
+		+ Zero
+		+ One
+		+ PartialOrd
+		+ Neg<Output = T>
+		+ Sub<Output = T>
+		+ Mul<Output = T>
+		+ Div<Output = T>,
+{
+	/// Returns the unit lower triangular factor L
+	pub fn l(&self) -> Matrix<T, N, N> {
+		let mut l = Matrix::identity();
+		for c in 0..N {
+			for r in c + 1..N {
+				l[(r, c)] = self.lu[(r, c)].clone();
+			}
+		}
+		l
+	}
+
+	/// Returns the upper triangular factor U
+	pub fn u(&self) -> Matrix<T, N, N> {
+		let mut u: Matrix<T, N, N> = Matrix::from_cols(array::from_fn(|_| {
+			Vector::from_array(array::from_fn(|_| T::zero()))
+		}));
+		for c in 0..N {
+			for r in 0..=c {
+				u[(r, c)] = self.lu[(r, c)].clone();
+			}
+		}
+		u
+	}
+
+	/// Returns the row permutation, row i of P A is row permutation()[i] of A
+	#[inline]
+	pub fn permutation(&self) -> &[usize; N] {
+		&self.perm
+	}
+
+	/// Returns true if the decomposed Matrix is singular
+	#[inline]
+	pub fn is_singular(&self) -> bool {
+		(0..N).any(|i| self.lu[(i, i)].is_zero())
+	}
+
+	/// Returns the determinant of the decomposed Matrix
+	pub fn determinant(&self) -> T {
+		let det = (0..N).fold(T::one(), |acc, i| acc * self.lu[(i, i)].clone());
+		if self.even {
+			det
+		} else {
+			-det
+		}
+	}
+
+	/// Returns the x that solves A x = b or None if A is singular
+	pub fn solve(&self, b: &Vector<T, N>) -> Option<Vector<T, N>> {
+		if self.is_singular() {
+			return None;
+		}
+
+		// Forward substitution L y = P b
+		let mut x: [T; N] = array::from_fn(|i| b[self.perm[i]].clone());
+		for i in 0..N {
+			for j in 0..i {
+				x[i] = x[i].clone() - self.lu[(i, j)].clone() * x[j].clone();
+			}
+		}
+
+		// Back substitution U x = y
+		for i in (0..N).rev() {
+			for j in i + 1..N {
+				x[i] = x[i].clone() - self.lu[(i, j)].clone() * x[j].clone();
+			}
+			x[i] = x[i].clone() / self.lu[(i, i)].clone();
+		}
+
+		Some(Vector::from_array(x))
+	}
+
+	/// Returns the inverse of the decomposed Matrix or None if it is singular
+	pub fn inverse(&self) -> Option<Matrix<T, N, N>> {
+		if self.is_singular() {
+			return None;
+		}
+
+		let id = Matrix::<T, N, N>::identity();
+		let mut cols = id.iter_cols().map(|e| self.solve(e));
+		// Every solve succeeds as the Matrix isn't singular
+		Some(Matrix::from_cols(array::from_fn(|_| {
+			cols.next().flatten().unwrap()
+		})))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::ops::Div;
+
+	use orml_easy_big_float::EasyBigFloat;
+	use orml_vector::Vector;
+
+	use crate::{
+		typedefs::{Mat2f64, Mat3f64, Mat4f64},
+		Matrix,
+	};
+
+	fn hilbert<T: From<f64> + Div<Output = T>, const N: usize>() -> Matrix<T, N, N> {
+		Matrix::from_cols(std::array::from_fn(|c| {
+			Vector::from_array(std::array::from_fn(|r| {
+				T::from(1.0) / T::from((r + c + 1) as f64)
+			}))
+		}))
+	}
+
+	#[test]
+	fn determinant() {
+		let m: Mat3f64 = [[2.0, -3.0, 1.0], [2.0, 0.0, -1.0], [1.0, 4.0, 5.0]].into();
+		assert!((m.determinant() - 49.0).abs() < 1e-12);
+
+		// A single swap flips the sign
+		let m: Mat2f64 = [[0.0, 1.0], [1.0, 0.0]].into();
+		assert_eq!(m.determinant(), -1.0);
+
+		let m: Mat3f64 = [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]].into();
+		assert!(m.determinant().abs() < 1e-12);
+		assert_eq!(Matrix::<f64, 0, 0>::new().determinant(), 1.0);
+	}
+
+	#[test]
+	fn decomposition() {
+		let m: Mat4f64 = [
+			[0.0, 2.0, -1.0, 4.0],
+			[3.0, 1.0, 0.0, -2.0],
+			[1.0, -1.0, 5.0, 1.0],
+			[2.0, 6.0, 1.0, 0.0],
+		]
+		.into();
+		let lu = m.lu();
+		let (l, u) = (lu.l(), lu.u());
+		let pm: Mat4f64 = Matrix::from_rows(lu.permutation().map(|i| m.row(i)));
+
+		let diff = l * u - pm;
+		assert!(diff.iter_cols().flatten().all(|e| e.abs() < 1e-12));
+		for r in 0..4 {
+			assert_eq!(l[(r, r)], 1.0);
+			for c in r + 1..4 {
+				assert_eq!(l[(r, c)], 0.0);
+				assert_eq!(u[(c, r)], 0.0);
+				// Partial pivoting keeps every multiplier at most 1
+				assert!(l[(c, r)].abs() <= 1.0);
+			}
+		}
+	}
+
+	#[test]
+	fn inverse() {
+		let m: Mat4f64 = [
+			[4.0, 7.0, 2.0, 0.0],
+			[3.0, 6.0, 1.0, 5.0],
+			[2.0, 5.0, 3.0, 1.0],
+			[0.0, 1.0, 8.0, 9.0],
+		]
+		.into();
+		let inv = m.inverse().unwrap();
+		let diff = m * inv - Mat4f64::identity();
+		assert!(diff.iter_cols().flatten().all(|e| e.abs() < 1e-12));
+
+		let m: Mat3f64 = [[1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [0.0, 1.0, 1.0]].into();
+		assert_eq!(m.inverse(), None);
+		assert!(m.lu().is_singular());
+	}
+
+	#[test]
+	fn solve() {
+		let m: Mat3f64 = [[1.0, 1.0, 1.0], [0.0, 2.0, 5.0], [2.0, 5.0, -1.0]].into();
+		let x = m.solve(&[6.0, -4.0, 27.0].into()).unwrap();
+		let expected = [5.0, 3.0, -2.0];
+		assert!((0..3).all(|i| (x[i] - expected[i]).abs() < 1e-12));
+
+		let m: Mat2f64 = [[1.0, 2.0], [2.0, 4.0]].into();
+		assert_eq!(m.solve(&[1.0, 2.0].into()), None);
+	}
+
+	#[test]
+	fn big_float() {
+		type BF = EasyBigFloat<256>;
+
+		// The Hilbert matrix is badly conditioned, its inverse has integer entries
+		let close = |a: &BF, b: f64| {
+			let d = a.clone() - BF::from(b);
+			d < BF::from(1e-50) && -d < BF::from(1e-50)
+		};
+		let h: Matrix<BF, 5, 5> = hilbert();
+		let inv = h.inverse().unwrap();
+		assert!(close(&inv[(0, 0)], 25.0));
+		assert!(close(&inv[(4, 4)], 44100.0));
+		assert!(close(&inv[(2, 3)], -117600.0));
+		assert!(close(&(h.determinant() * BF::from(266716800000.0)), 1.0));
+
+		// f64 only gets a few digits right
+		let inv = hilbert::<f64, 5>().inverse().unwrap();
+		assert!((inv[(4, 4)] - 44100.0).abs() < 1e-6 * 44100.0);
+	}
+}
@@ -0,0 +1,29 @@
+//! This module containts implementations for comparing Matrices
+
+use super::Matrix;
+
+impl<T, const R: usize, const C: usize> PartialEq for Matrix<T, R, C>
+where
+	T: PartialEq,
+{
+	#[inline]
+	fn eq(&self, other: &Self) -> bool {
+		self.cols == other.cols
+	}
+}
+
+impl<T, const R: usize, const C: usize> Eq for Matrix<T, R, C> where T: Eq {}
+
+#[cfg(test)]
+mod tests {
+	use crate::typedefs::Mat2i32;
+
+	#[test]
+	fn partial_eq() {
+		let a: Mat2i32 = [[1, 2], [3, 4]].into();
+		let b: Mat2i32 = [[1, 2], [3, 5]].into();
+		assert_eq!(a, a);
+		assert_ne!(a, b);
+		assert_ne!(a, a.transpose());
+	}
+}
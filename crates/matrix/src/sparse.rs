@@ -0,0 +1,291 @@
+//! This module contains sparse Matrices that only store their nonzero elements
+//!
+//! A [`CooMatrix`] collects (row, col, value) triplets in any order and is used to build the
+//! compressed formats. [`CsrMatrix`] stores each row's elements together and is the fast
+//! format for products, [`CscMatrix`] does the same for columns. Duplicate triplets are summed
+//! when compressed, which is how finite element assembly adds up element contributions
+
+use std::ops::{Add, Mul};
+
+use crate::dynamic::DMatrix;
+
+pub mod coo;
+pub mod csc;
+pub mod csr;
+
+pub use coo::CooMatrix;
+pub use csc::CscMatrix;
+pub use csr::CsrMatrix;
+
+/// The storage shared by the compressed formats
+///
+/// The elements of major line i are vals[ptr[i]..ptr[i + 1]], with their minor indices in idx
+/// sorted ascending. CSR has rows as its major lines and CSC has columns
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Compressed<T> {
+	major: usize,
+	minor: usize,
+	ptr: Vec<usize>,
+	idx: Vec<usize>,
+	vals: Vec<T>,
+}
+
+impl<T> Compressed<T> {
+	/// Compresses (major, minor, value) triplets, summing any duplicates
+	pub(crate) fn from_triplets(major: usize, minor: usize, entries: Vec<(usize, usize, T)>) -> Self
+	where
+		T: Add<Output = T>,
+	{
+		let mut entries = entries;
+		entries.sort_by_key(|e| (e.0, e.1));
+
+		let mut ptr = vec![0; major + 1];
+		let mut idx = Vec::with_capacity(entries.len());
+		let mut vals: Vec<T> = Vec::with_capacity(entries.len());
+		let mut last = None;
+		for (i, j, v) in entries {
+			if last == Some((i, j)) {
+				// Only reached after a push
+				let prev = vals.pop().unwrap();
+				vals.push(prev + v);
+			} else {
+				ptr[i + 1] += 1;
+				idx.push(j);
+				vals.push(v);
+				last = Some((i, j));
+			}
+		}
+		for i in 0..major {
+			ptr[i + 1] += ptr[i];
+		}
+
+		Self {
+			major,
+			minor,
+			ptr,
+			idx,
+			vals,
+		}
+	}
+
+	/// Returns the number of stored elements
+	#[inline]
+	pub(crate) fn nnz(&self) -> usize {
+		self.vals.len()
+	}
+
+	/// Returns the element at (major, minor) if it is stored
+	pub(crate) fn get(&self, i: usize, j: usize) -> Option<&T> {
+		let range = self.ptr[i]..self.ptr[i + 1];
+		let k = self.idx[range.clone()].binary_search(&j).ok()?;
+		Some(&self.vals[range.start + k])
+	}
+
+	/// Returns an iter over the stored elements of major line i as (minor, value)
+	pub(crate) fn line(&self, i: usize) -> impl Iterator<Item = (usize, &T)> {
+		(self.ptr[i]..self.ptr[i + 1]).map(move |k| (self.idx[k], &self.vals[k]))
+	}
+
+	/// Returns an iter over the stored elements as (major, minor, value) in order
+	pub(crate) fn iter(&self) -> impl Iterator<Item = (usize, usize, &T)> {
+		(0..self.major).flat_map(move |i| self.line(i).map(move |(j, v)| (i, j, v)))
+	}
+
+	/// Returns the dense Matrix with major lines as rows
+	pub(crate) fn to_dense(&self) -> DMatrix<T>
+	where
+		T: Clone + Default,
+	{
+		let mut dense = DMatrix::new(self.major, self.minor);
+		for (i, j, v) in self.iter() {
+			dense[(i, j)] = v.clone();
+		}
+		dense
+	}
+
+	/// Returns the stored elements as (major, minor, value) in order
+	pub(crate) fn into_triplets(self) -> Vec<(usize, usize, T)> {
+		let mut i = 0;
+		let (ptr, idx) = (self.ptr, self.idx);
+		self.vals
+			.into_iter()
+			.enumerate()
+			.map(|(k, v)| {
+				while ptr[i + 1] <= k {
+					i += 1;
+				}
+				(i, idx[k], v)
+			})
+			.collect()
+	}
+
+	/// Returns the same elements with major and minor swapped, in O(nnz) by a counting sort
+	pub(crate) fn transpose(self) -> Self {
+		let nnz = self.nnz();
+		let mut ptr = vec![0; self.minor + 1];
+		for &j in &self.idx {
+			ptr[j + 1] += 1;
+		}
+		for j in 0..self.minor {
+			ptr[j + 1] += ptr[j];
+		}
+
+		let mut next = ptr.clone();
+		let mut idx = vec![0; nnz];
+		let mut vals: Vec<Option<T>> = (0..nnz).map(|_| None).collect();
+		let (major, minor) = (self.major, self.minor);
+		for (i, j, v) in self.into_triplets() {
+			let k = next[j];
+			next[j] += 1;
+			idx[k] = i;
+			vals[k] = Some(v);
+		}
+
+		Self {
+			major: minor,
+			minor: major,
+			ptr,
+			idx,
+			// Every slot is filled exactly once as ptr counts them
+			vals: vals.into_iter().map(Option::unwrap).collect(),
+		}
+	}
+
+	/// Returns the element-wise sum, the shapes have to match
+	pub(crate) fn add(&self, rhs: &Self) -> Self
+	where
+		T: Clone + Add<Output = T>,
+	{
+		let mut ptr = Vec::with_capacity(self.major + 1);
+		let mut idx = Vec::with_capacity(self.nnz().max(rhs.nnz()));
+		let mut vals = Vec::with_capacity(idx.capacity());
+		ptr.push(0);
+
+		for i in 0..self.major {
+			// Merge the two sorted lines
+			let (mut a, a_end) = (self.ptr[i], self.ptr[i + 1]);
+			let (mut b, b_end) = (rhs.ptr[i], rhs.ptr[i + 1]);
+			while a < a_end || b < b_end {
+				let ja = if a < a_end { self.idx[a] } else { usize::MAX };
+				let jb = if b < b_end { rhs.idx[b] } else { usize::MAX };
+				if ja == jb {
+					idx.push(ja);
+					vals.push(self.vals[a].clone() + rhs.vals[b].clone());
+					a += 1;
+					b += 1;
+				} else if ja < jb {
+					idx.push(ja);
+					vals.push(self.vals[a].clone());
+					a += 1;
+				} else {
+					idx.push(jb);
+					vals.push(rhs.vals[b].clone());
+					b += 1;
+				}
+			}
+			ptr.push(idx.len());
+		}
+
+		Self {
+			major: self.major,
+			minor: self.minor,
+			ptr,
+			idx,
+			vals,
+		}
+	}
+
+	/// Returns the product with a dense vector when the major lines are rows
+	pub(crate) fn mul_rows<'a>(&self, x: impl Iterator<Item = &'a T>) -> Vec<T>
+	where
+		T: Add<Output = T> + Default + 'a,
+		for<'b> &'b T: Mul<&'b T, Output = T>,
+	{
+		let x: Vec<&T> = x.collect();
+		(0..self.major)
+			.map(|i| {
+				(self.ptr[i]..self.ptr[i + 1])
+					.fold(T::default(), |acc, k| acc + &self.vals[k] * x[self.idx[k]])
+			})
+			.collect()
+	}
+
+	/// Returns the product with a dense vector when the major lines are columns
+	pub(crate) fn mul_cols<'a>(&self, x: impl Iterator<Item = &'a T>) -> Vec<T>
+	where
+		T: Add<Output = T> + Default + 'a,
+		for<'b> &'b T: Mul<&'b T, Output = T>,
+	{
+		let mut y: Vec<Option<T>> = (0..self.minor).map(|_| None).collect();
+		for (i, x) in x.enumerate() {
+			for k in self.ptr[i]..self.ptr[i + 1] {
+				let e = &mut y[self.idx[k]];
+				let p = &self.vals[k] * x;
+				*e = Some(match e.take() {
+					Some(acc) => acc + p,
+					None => p,
+				});
+			}
+		}
+		y.into_iter().map(Option::unwrap_or_default).collect()
+	}
+}
+
+/// Implements sparse * dense products for each combination of references
+macro_rules! impl_mul_dense {
+	($lhs:ty,$rhs:ty,$method:ident$(,$dims:ident)*) => {
+		impl<T$(, const $dims: usize)*> Mul<$rhs> for $lhs
+		where
+			T: Add<Output = T> + Default,
+			for<'a> &'a T: Mul<&'a T, Output = T>,
+		{
+			type Output = Result<DVector<T>, DimensionError>;
+
+			#[inline]
+			fn mul(self, rhs: $rhs) -> Self::Output {
+				DimensionError::check(self.cols(), rhs.len())?;
+				Ok(self.0.$method(rhs.iter()).into())
+			}
+		}
+	};
+}
+
+macro_rules! impl_mul_dense_vers {
+	($tt:ty,$method:ident) => {
+		impl_mul_dense!($tt, DVector<T>, $method);
+		impl_mul_dense!($tt, &DVector<T>, $method);
+		impl_mul_dense!(&$tt, DVector<T>, $method);
+		impl_mul_dense!(&$tt, &DVector<T>, $method);
+		impl_mul_dense!($tt, Vector<T, N>, $method, N);
+		impl_mul_dense!($tt, &Vector<T, N>, $method, N);
+		impl_mul_dense!(&$tt, Vector<T, N>, $method, N);
+		impl_mul_dense!(&$tt, &Vector<T, N>, $method, N);
+	};
+}
+
+/// Implements sparse + sparse for each combination of references
+macro_rules! impl_add_vers {
+	($tt:ident) => {
+		impl_add_vers!($tt, $tt<T>, $tt<T>);
+		impl_add_vers!($tt, $tt<T>, &$tt<T>);
+		impl_add_vers!($tt, &$tt<T>, $tt<T>);
+		impl_add_vers!($tt, &$tt<T>, &$tt<T>);
+	};
+	($tt:ident,$lhs:ty,$rhs:ty) => {
+		impl<T> Add<$rhs> for $lhs
+		where
+			T: Clone + Add<Output = T>,
+		{
+			type Output = Result<$tt<T>, DimensionError>;
+
+			#[inline]
+			fn add(self, rhs: $rhs) -> Self::Output {
+				DimensionError::check(self.rows(), rhs.rows())?;
+				DimensionError::check(self.cols(), rhs.cols())?;
+				Ok($tt(self.0.add(&rhs.0)))
+			}
+		}
+	};
+}
+
+pub(crate) use {impl_add_vers, impl_mul_dense, impl_mul_dense_vers};
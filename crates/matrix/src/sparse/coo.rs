@@ -0,0 +1,174 @@
+//! This module contains the coordinate format used to build sparse Matrices
+
+use std::ops::Add;
+
+use super::{Compressed, CscMatrix, CsrMatrix};
+
+/// A sparse Matrix stored as a list of (row, col, value) triplets
+///
+/// Triplets can be pushed in any order and the same position can be pushed more than once,
+/// the values are summed when converted to [`CsrMatrix`] or [`CscMatrix`]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct CooMatrix<T> {
+	rows: usize,
+	cols: usize,
+	entries: Vec<(usize, usize, T)>,
+}
+
+impl<T> CooMatrix<T> {
+	/// Creates a new empty CooMatrix with rows rows and cols columns
+	#[inline]
+	pub fn new(rows: usize, cols: usize) -> Self {
+		Self::with_capacity(rows, cols, 0)
+	}
+
+	/// Creates a new empty CooMatrix with room for capacity triplets
+	#[inline]
+	pub fn with_capacity(rows: usize, cols: usize, capacity: usize) -> Self {
+		Self {
+			rows,
+			cols,
+			entries: Vec::with_capacity(capacity),
+		}
+	}
+
+	/// Adds the value at (row, col)
+	///
+	/// # Panics
+	/// If row or col is out of bounds
+	#[inline]
+	pub fn push(&mut self, row: usize, col: usize, val: T) {
+		assert!(
+			row < self.rows && col < self.cols,
+			"index ({row}, {col}) out of bounds for a {}x{} matrix",
+			self.rows,
+			self.cols
+		);
+		self.entries.push((row, col, val));
+	}
+
+	/// Returns the number of rows
+	#[inline]
+	pub fn rows(&self) -> usize {
+		self.rows
+	}
+
+	/// Returns the number of columns
+	#[inline]
+	pub fn cols(&self) -> usize {
+		self.cols
+	}
+
+	/// Returns the number of triplets, counting duplicates
+	#[inline]
+	pub fn nnz(&self) -> usize {
+		self.entries.len()
+	}
+
+	/// Returns an iter over the triplets in the order they were pushed
+	#[inline]
+	pub fn iter(&self) -> impl Iterator<Item = (usize, usize, &T)> {
+		self.entries.iter().map(|(r, c, v)| (*r, *c, v))
+	}
+
+	/// Returns the triplets in the order they were pushed
+	#[inline]
+	pub fn to_triplets(self) -> Vec<(usize, usize, T)> {
+		self.entries
+	}
+}
+
+impl<T> From<CooMatrix<T>> for CsrMatrix<T>
+where
+	T: Add<Output = T>,
+{
+	#[inline]
+	fn from(value: CooMatrix<T>) -> Self {
+		CsrMatrix(Compressed::from_triplets(
+			value.rows,
+			value.cols,
+			value.entries,
+		))
+	}
+}
+
+impl<T> From<CooMatrix<T>> for CscMatrix<T>
+where
+	T: Add<Output = T>,
+{
+	#[inline]
+	fn from(value: CooMatrix<T>) -> Self {
+		CscMatrix(Compressed::from_triplets(
+			value.cols,
+			value.rows,
+			value
+				.entries
+				.into_iter()
+				.map(|(r, c, v)| (c, r, v))
+				.collect(),
+		))
+	}
+}
+
+impl<T> From<CsrMatrix<T>> for CooMatrix<T> {
+	#[inline]
+	fn from(value: CsrMatrix<T>) -> Self {
+		Self {
+			rows: value.rows(),
+			cols: value.cols(),
+			entries: value.0.into_triplets(),
+		}
+	}
+}
+
+impl<T> From<CscMatrix<T>> for CooMatrix<T> {
+	#[inline]
+	fn from(value: CscMatrix<T>) -> Self {
+		Self {
+			rows: value.rows(),
+			cols: value.cols(),
+			entries: value
+				.0
+				.into_triplets()
+				.into_iter()
+				.map(|(c, r, v)| (r, c, v))
+				.collect(),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::CooMatrix;
+	use crate::sparse::{CscMatrix, CsrMatrix};
+
+	#[test]
+	fn duplicates() {
+		let mut coo = CooMatrix::new(2, 3);
+		coo.push(1, 2, 1);
+		coo.push(0, 1, 2);
+		coo.push(1, 2, 3);
+		coo.push(1, 0, 4);
+		assert_eq!(coo.nnz(), 4);
+
+		let csr = CsrMatrix::from(coo.clone());
+		assert_eq!(csr.nnz(), 3);
+		assert_eq!(csr.get(1, 2), Some(&4));
+		assert_eq!(csr.get(0, 0), None);
+
+		let csc = CscMatrix::from(coo);
+		assert_eq!(csc.nnz(), 3);
+		assert_eq!(csc.get(1, 2), Some(&4));
+
+		let back = CooMatrix::from(csc);
+		assert_eq!(back.to_triplets(), vec![(1, 0, 4), (0, 1, 2), (1, 2, 4)]);
+		let back = CooMatrix::from(csr);
+		assert_eq!(back.to_triplets(), vec![(0, 1, 2), (1, 0, 4), (1, 2, 4)]);
+	}
+
+	#[test]
+	#[should_panic]
+	fn out_of_bounds() {
+		CooMatrix::new(2, 2).push(2, 0, 1.0);
+	}
+}
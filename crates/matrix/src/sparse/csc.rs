@@ -0,0 +1,152 @@
+//! This module contains the compressed sparse column format
+
+use std::ops::{Add, Mul};
+
+use orml_vector::dynamic::{DVector, DimensionError};
+use orml_vector::Vector;
+
+use super::{impl_add_vers, impl_mul_dense, impl_mul_dense_vers, Compressed, CsrMatrix};
+use crate::dynamic::DMatrix;
+
+/// A sparse Matrix stored column by column, the format to use for column access
+///
+/// Build one from a [`super::CooMatrix`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CscMatrix<T>(pub(crate) Compressed<T>);
+
+impl<T> CscMatrix<T> {
+	/// Returns the number of rows
+	#[inline]
+	pub fn rows(&self) -> usize {
+		self.0.minor
+	}
+
+	/// Returns the number of columns
+	#[inline]
+	pub fn cols(&self) -> usize {
+		self.0.major
+	}
+
+	/// Returns the number of stored elements
+	#[inline]
+	pub fn nnz(&self) -> usize {
+		self.0.nnz()
+	}
+
+	/// Returns the element at (row, col) if it is stored
+	///
+	/// # Panics
+	/// If col is out of bounds
+	#[inline]
+	pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+		self.0.get(col, row)
+	}
+
+	/// Returns an iter over the stored elements of a column as (row, value)
+	///
+	/// # Panics
+	/// If col is out of bounds
+	#[inline]
+	pub fn col(&self, col: usize) -> impl Iterator<Item = (usize, &T)> {
+		self.0.line(col)
+	}
+
+	/// Returns an iter over the stored elements as (row, col, value) ordered by column
+	#[inline]
+	pub fn iter(&self) -> impl Iterator<Item = (usize, usize, &T)> {
+		self.0.iter().map(|(c, r, v)| (r, c, v))
+	}
+
+	/// Returns the transpose of the CscMatrix
+	#[inline]
+	pub fn transpose(self) -> Self {
+		Self(self.0.transpose())
+	}
+
+	/// Returns the dense DMatrix with the same elements, unstored elements are the default
+	#[inline]
+	pub fn to_dense(&self) -> DMatrix<T>
+	where
+		T: Clone + Default,
+	{
+		self.0.to_dense().transpose()
+	}
+}
+
+impl<T> From<CsrMatrix<T>> for CscMatrix<T> {
+	#[inline]
+	fn from(value: CsrMatrix<T>) -> Self {
+		Self(value.0.transpose())
+	}
+}
+
+impl_add_vers!(CscMatrix);
+impl_mul_dense_vers!(CscMatrix<T>, mul_cols);
+
+#[cfg(test)]
+mod tests {
+	// The reference versions of the operators are tested too
+	#![allow(clippy::op_ref)]
+
+	use orml_vector::dynamic::{DVector, DimensionError};
+	use orml_vector::Vector;
+
+	use super::CscMatrix;
+	use crate::sparse::{CooMatrix, CsrMatrix};
+
+	// [1.0 0.0 2.0 0.0]
+	// [0.0 3.0 0.0 0.5]
+	fn csc() -> CscMatrix<f64> {
+		let mut coo = CooMatrix::new(2, 4);
+		for (r, c, v) in [(1, 3, 0.5), (0, 2, 2.0), (1, 1, 3.0), (0, 0, 1.0)] {
+			coo.push(r, c, v);
+		}
+		coo.into()
+	}
+
+	#[test]
+	fn structure() {
+		let csc = csc();
+		assert_eq!((csc.rows(), csc.cols(), csc.nnz()), (2, 4, 4));
+		assert_eq!(csc.get(1, 3), Some(&0.5));
+		assert_eq!(csc.get(0, 3), None);
+		assert_eq!(csc.col(2).collect::<Vec<_>>(), vec![(0, &2.0)]);
+		assert_eq!(
+			csc.iter().collect::<Vec<_>>(),
+			vec![(0, 0, &1.0), (1, 1, &3.0), (0, 2, &2.0), (1, 3, &0.5)]
+		);
+
+		let csr = CsrMatrix::from(csc.clone());
+		assert_eq!(csr.to_dense(), csc.to_dense());
+		assert_eq!(CscMatrix::from(csr), csc);
+		assert_eq!(
+			csc.clone().transpose().to_dense(),
+			csc.to_dense().transpose()
+		);
+	}
+
+	#[test]
+	fn products() {
+		let csc = csc();
+		let x = Vector::from_array([1.0, 2.0, 3.0, 4.0]);
+		let expected = DVector::from_vec(vec![7.0, 8.0]);
+		assert_eq!((&csc * x).unwrap(), expected);
+		assert_eq!((&csc * &DVector::from(x)).unwrap(), expected);
+		assert_eq!(
+			csc * Vector::from_array([1.0, 2.0]),
+			Err(DimensionError {
+				expected: 4,
+				found: 2
+			})
+		);
+	}
+
+	#[test]
+	fn addition() {
+		let a = csc();
+		let b = a.clone().transpose().transpose();
+		let sum = (a + &b).unwrap();
+		assert_eq!(sum.nnz(), 4);
+		assert_eq!(sum.get(1, 1), Some(&6.0));
+	}
+}
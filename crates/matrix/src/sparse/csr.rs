@@ -0,0 +1,162 @@
+//! This module contains the compressed sparse row format
+
+use std::ops::{Add, Mul};
+
+use orml_vector::dynamic::{DVector, DimensionError};
+use orml_vector::Vector;
+
+use super::{impl_add_vers, impl_mul_dense, impl_mul_dense_vers, Compressed, CscMatrix};
+use crate::dynamic::DMatrix;
+
+/// A sparse Matrix stored row by row, the format to use for Matrix * vector products
+///
+/// Build one from a [`super::CooMatrix`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CsrMatrix<T>(pub(crate) Compressed<T>);
+
+impl<T> CsrMatrix<T> {
+	/// Returns the number of rows
+	#[inline]
+	pub fn rows(&self) -> usize {
+		self.0.major
+	}
+
+	/// Returns the number of columns
+	#[inline]
+	pub fn cols(&self) -> usize {
+		self.0.minor
+	}
+
+	/// Returns the number of stored elements
+	#[inline]
+	pub fn nnz(&self) -> usize {
+		self.0.nnz()
+	}
+
+	/// Returns the element at (row, col) if it is stored
+	///
+	/// # Panics
+	/// If row is out of bounds
+	#[inline]
+	pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+		self.0.get(row, col)
+	}
+
+	/// Returns an iter over the stored elements of a row as (col, value)
+	///
+	/// # Panics
+	/// If row is out of bounds
+	#[inline]
+	pub fn row(&self, row: usize) -> impl Iterator<Item = (usize, &T)> {
+		self.0.line(row)
+	}
+
+	/// Returns an iter over the stored elements as (row, col, value) ordered by row
+	#[inline]
+	pub fn iter(&self) -> impl Iterator<Item = (usize, usize, &T)> {
+		self.0.iter()
+	}
+
+	/// Returns the transpose of the CsrMatrix
+	#[inline]
+	pub fn transpose(self) -> Self {
+		Self(self.0.transpose())
+	}
+
+	/// Returns the dense DMatrix with the same elements, unstored elements are the default
+	#[inline]
+	pub fn to_dense(&self) -> DMatrix<T>
+	where
+		T: Clone + Default,
+	{
+		self.0.to_dense()
+	}
+}
+
+impl<T> From<CscMatrix<T>> for CsrMatrix<T> {
+	#[inline]
+	fn from(value: CscMatrix<T>) -> Self {
+		Self(value.0.transpose())
+	}
+}
+
+impl_add_vers!(CsrMatrix);
+impl_mul_dense_vers!(CsrMatrix<T>, mul_rows);
+
+#[cfg(test)]
+mod tests {
+	// The reference versions of the operators are tested too
+	#![allow(clippy::op_ref)]
+
+	use orml_vector::dynamic::{DVector, DimensionError};
+	use orml_vector::Vector;
+
+	use super::CsrMatrix;
+	use crate::dynamic::DMatrix;
+	use crate::sparse::{CooMatrix, CscMatrix};
+
+	// [1 0 2]
+	// [0 0 3]
+	// [4 5 0]
+	// [0 0 0]
+	fn csr() -> CsrMatrix<i32> {
+		let mut coo = CooMatrix::new(4, 3);
+		for (r, c, v) in [(2, 1, 5), (0, 0, 1), (1, 2, 3), (2, 0, 4), (0, 2, 2)] {
+			coo.push(r, c, v);
+		}
+		coo.into()
+	}
+
+	#[test]
+	fn structure() {
+		let csr = csr();
+		assert_eq!((csr.rows(), csr.cols(), csr.nnz()), (4, 3, 5));
+		assert_eq!(csr.row(2).collect::<Vec<_>>(), vec![(0, &4), (1, &5)]);
+		assert_eq!(csr.row(3).count(), 0);
+		let dense: DMatrix<i32> = vec![vec![1, 0, 2], vec![0, 0, 3], vec![4, 5, 0], vec![0, 0, 0]]
+			.try_into()
+			.unwrap();
+		assert_eq!(csr.to_dense(), dense);
+		assert_eq!(csr.clone().transpose().to_dense(), dense.transpose());
+		assert_eq!(csr.clone().transpose().transpose(), csr);
+		assert_eq!(CsrMatrix::from(CscMatrix::from(csr.clone())), csr);
+	}
+
+	#[test]
+	fn products() {
+		let csr = csr();
+		let x = Vector::from_array([1, 2, 3]);
+		let expected = DVector::from_vec(vec![7, 9, 14, 0]);
+		assert_eq!((&csr * &x).unwrap(), expected);
+		assert_eq!((&csr * DVector::from(x)).unwrap(), expected);
+		assert_eq!(
+			csr * DVector::from_vec(vec![1, 2]),
+			Err(DimensionError {
+				expected: 3,
+				found: 2
+			})
+		);
+	}
+
+	#[test]
+	fn addition() {
+		let a = csr();
+		let mut coo = CooMatrix::new(4, 3);
+		coo.push(0, 0, -1);
+		coo.push(0, 1, 6);
+		coo.push(3, 2, 7);
+		let b = CsrMatrix::from(coo);
+
+		let sum = (&a + &b).unwrap();
+		assert_eq!(sum.nnz(), 7);
+		assert_eq!(sum.get(0, 0), Some(&0));
+		assert_eq!(sum.to_dense(), (a.to_dense() + b.to_dense()).unwrap());
+		assert_eq!(
+			a + b.transpose(),
+			Err(DimensionError {
+				expected: 4,
+				found: 3
+			})
+		);
+	}
+}
@@ -0,0 +1,22 @@
+//! This module contains definitions of useful sizes and types
+
+use crate::Matrix;
+
+/// Matrix2x2 i32
+pub type Mat2i32 = Matrix<i32, 2, 2>;
+/// Matrix3x3 i32
+pub type Mat3i32 = Matrix<i32, 3, 3>;
+/// Matrix4x4 i32
+pub type Mat4i32 = Matrix<i32, 4, 4>;
+/// Matrix2x2 f32
+pub type Mat2f32 = Matrix<f32, 2, 2>;
+/// Matrix3x3 f32
+pub type Mat3f32 = Matrix<f32, 3, 3>;
+/// Matrix4x4 f32
+pub type Mat4f32 = Matrix<f32, 4, 4>;
+/// Matrix2x2 f64
+pub type Mat2f64 = Matrix<f64, 2, 2>;
+/// Matrix3x3 f64
+pub type Mat3f64 = Matrix<f64, 3, 3>;
+/// Matrix4x4 f64
+pub type Mat4f64 = Matrix<f64, 4, 4>;
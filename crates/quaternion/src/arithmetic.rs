@@ -38,20 +38,39 @@ where
 
 impl<T> Quaternion<T>
 where
-	T: Div<Output = T> + One,
+	T: Neg<Output = T>,
 {
-	/// Returns the reciprocal of the vector. ie 1/x for each element x of the vector
+	/// Returns the conjugate of the Quaternion, which is its inverse when it has length 1
 	#[inline]
-	pub fn reciprocal(self) -> Self {
+	pub fn conjugate(self) -> Self {
 		Self {
-			x: T::one() / self.x,
-			y: T::one() / self.y,
-			z: T::one() / self.z,
-			w: T::one() / self.w,
+			x: -self.x,
+			y: -self.y,
+			z: -self.z,
+			w: self.w,
 		}
 	}
 }
 
+impl<T> Quaternion<T>
+where
+	T: Add<Output = T> + Neg<Output = T>,
+	for<'a> &'a T: Mul<&'a T, Output = T> + Div<&'a T, Output = T>,
+{
+	/// Returns the inverse of a nonzero Quaternion, so q * q.inverse() is the identity
+	#[inline]
+	pub fn inverse(self) -> Self {
+		let len2 = self.length_squared();
+		self.conjugate() / len2
+	}
+
+	/// Returns the reciprocal 1/q of the Quaternion, the same as [`Quaternion::inverse`]
+	#[inline]
+	pub fn reciprocal(self) -> Self {
+		self.inverse()
+	}
+}
+
 impl<T> Quaternion<T>
 where
 	T: Add<Output = T> + SquareRoot + One + Div<Output = T>,
@@ -153,7 +172,7 @@ where
 	}
 }
 
-impl<'b, T> Mul<Quaternion<T>> for &'b Quaternion<T>
+impl<T> Mul<Quaternion<T>> for &Quaternion<T>
 where
 	T: Add<Output = T> + Sub<Output = T>,
 	for<'a> &'a T: Mul<&'a T, Output = T>,
@@ -354,11 +373,18 @@ mod tests {
 	}
 
 	#[test]
-	fn reciprocal() {
-		let expected1: Quaternion<f32> = [2.0, 2.0, 2.0, 1.0].into();
-		assert_eq!(expected1.reciprocal().to_array(), [0.5, 0.5, 0.5, 1.0]);
+	fn conjugate() {
+		let q: Quaternion<f32> = [1.0, -2.0, 3.0, 4.0].into();
+		assert_eq!(q.conjugate().to_array(), [-1.0, 2.0, -3.0, 4.0]);
+	}
+
+	#[test]
+	fn inverse() {
+		let q: Quaternion<f32> = [1.0, 1.0, 1.0, 1.0].into();
+		assert_eq!(q.inverse().to_array(), [-0.25, -0.25, -0.25, 0.25]);
+		assert_eq!((q * q.inverse()).to_array(), [0.0, 0.0, 0.0, 1.0]);
 
-		let expected2: Quaternion<f32> = [2.0, 4.0, 8.0, 1.0].into();
-		assert_eq!(expected2.reciprocal().to_array(), [0.5, 0.25, 0.125, 1.0]);
+		let q: Quaternion<f32> = [0.0, 0.0, 2.0, 0.0].into();
+		assert_eq!(q.reciprocal().to_array(), [0.0, 0.0, -0.5, 0.0]);
 	}
 }
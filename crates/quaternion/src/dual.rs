@@ -0,0 +1,462 @@
+//! This module contains dual Quaternions, which represent rigid body transforms
+//!
+//! A dual Quaternion real + ε dual with ε² = 0 and a unit real part is a rotation followed by a
+//! translation. Unlike a separate rotation and translation, unit dual Quaternions compose by
+//! multiplication and interpolate along screw motions
+
+use std::ops::{Add, Mul, Neg, Sub};
+
+use num_traits::{One, Zero};
+use orml_traits::Float;
+use orml_vector::Vector;
+
+use crate::Quaternion;
+
+/// A dual Quaternion real + ε dual
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DualQuaternion<T> {
+	/// The real part, the rotation of a unit dual Quaternion
+	pub real: Quaternion<T>,
+	/// The dual part, half the translation times the rotation for a unit dual Quaternion
+	pub dual: Quaternion<T>,
+}
+
+impl<T> DualQuaternion<T> {
+	/// Creates a new DualQuaternion from its real and dual parts
+	#[inline]
+	pub fn new(real: Quaternion<T>, dual: Quaternion<T>) -> Self {
+		Self { real, dual }
+	}
+}
+
+impl<T> DualQuaternion<T>
+where
+	T: Zero + One,
+{
+	/// Returns the identity DualQuaternion, which does not move anything
+	#[inline]
+	pub fn identity() -> Self {
+		Self {
+			real: Quaternion::identity(),
+			dual: Quaternion {
+				x: T::zero(),
+				y: T::zero(),
+				z: T::zero(),
+				w: T::zero(),
+			},
+		}
+	}
+}
+
+impl<T> DualQuaternion<T>
+where
+	T: Neg<Output = T>,
+{
+	/// Returns the Quaternion conjugate of both parts, (real*, dual*)
+	///
+	/// For a unit DualQuaternion this is the inverse transform
+	#[inline]
+	pub fn conjugate(self) -> Self {
+		Self {
+			real: self.real.conjugate(),
+			dual: self.dual.conjugate(),
+		}
+	}
+
+	/// Returns the dual number conjugate, (real, -dual)
+	#[inline]
+	pub fn dual_conjugate(self) -> Self {
+		Self {
+			real: self.real,
+			dual: -self.dual,
+		}
+	}
+
+	/// Returns both conjugates at once, (real*, -dual*), which is used to transform points
+	#[inline]
+	pub fn combined_conjugate(self) -> Self {
+		Self {
+			real: self.real.conjugate(),
+			dual: -self.dual.conjugate(),
+		}
+	}
+}
+
+impl<T> Add<DualQuaternion<T>> for DualQuaternion<T>
+where
+	T: Add<Output = T>,
+{
+	type Output = Self;
+
+	fn add(self, rhs: DualQuaternion<T>) -> Self::Output {
+		Self {
+			real: self.real + rhs.real,
+			dual: self.dual + rhs.dual,
+		}
+	}
+}
+
+impl<T> Sub<DualQuaternion<T>> for DualQuaternion<T>
+where
+	T: Sub<Output = T>,
+{
+	type Output = Self;
+
+	fn sub(self, rhs: DualQuaternion<T>) -> Self::Output {
+		Self {
+			real: self.real - rhs.real,
+			dual: self.dual - rhs.dual,
+		}
+	}
+}
+
+impl<T> Neg for DualQuaternion<T>
+where
+	T: Neg<Output = T>,
+{
+	type Output = Self;
+
+	fn neg(self) -> Self::Output {
+		Self {
+			real: -self.real,
+			dual: -self.dual,
+		}
+	}
+}
+
+impl<T> Mul<T> for DualQuaternion<T>
+where
+	T: Clone,
+	for<'a> &'a T: Mul<&'a T, Output = T>,
+{
+	type Output = Self;
+
+	fn mul(self, rhs: T) -> Self::Output {
+		Self {
+			real: self.real * rhs.clone(),
+			dual: self.dual * rhs,
+		}
+	}
+}
+
+/// The product of two DualQuaternions, which is rhs followed by self as transforms
+impl<T> Mul<&DualQuaternion<T>> for &DualQuaternion<T>
+where
+	T: Add<Output = T> + Sub<Output = T>,
+	for<'a> &'a T: Mul<&'a T, Output = T>,
+{
+	type Output = DualQuaternion<T>;
+
+	fn mul(self, rhs: &DualQuaternion<T>) -> Self::Output {
+		DualQuaternion {
+			real: &self.real * &rhs.real,
+			dual: (&self.real * &rhs.dual) + (&self.dual * &rhs.real),
+		}
+	}
+}
+
+impl<T> Mul<DualQuaternion<T>> for DualQuaternion<T>
+where
+	T: Add<Output = T> + Sub<Output = T>,
+	for<'a> &'a T: Mul<&'a T, Output = T>,
+{
+	type Output = Self;
+
+	#[inline]
+	fn mul(self, rhs: DualQuaternion<T>) -> Self::Output {
+		&self * &rhs
+	}
+}
+
+/// Returns the pure Quaternion with the vector part v
+fn pure<T: Zero>([x, y, z]: [T; 3]) -> Quaternion<T> {
+	Quaternion {
+		x,
+		y,
+		z,
+		w: T::zero(),
+	}
+}
+
+/// Returns the vector part of a Quaternion
+fn vector_part<T>(q: Quaternion<T>) -> [T; 3] {
+	[q.x, q.y, q.z]
+}
+
+impl<T> DualQuaternion<T>
+where
+	T: Float + Zero + One + PartialOrd + Clone,
+	for<'a> &'a T: Mul<&'a T, Output = T>,
+{
+	/// Creates a new unit DualQuaternion that rotates by a unit Quaternion and then translates
+	pub fn from_rotation_translation(rotation: Quaternion<T>, translation: Vector<T, 3>) -> Self {
+		let half = T::one() / (T::one() + T::one());
+		let dual = pure(translation.to_array()) * &rotation * half;
+		Self {
+			real: rotation,
+			dual,
+		}
+	}
+
+	/// Creates a new unit DualQuaternion that only translates
+	pub fn from_translation(translation: Vector<T, 3>) -> Self {
+		Self::from_rotation_translation(Quaternion::identity(), translation)
+	}
+
+	/// Returns the rotation of a unit DualQuaternion
+	#[inline]
+	pub fn rotation(&self) -> Quaternion<T> {
+		self.real.clone()
+	}
+
+	/// Returns the translation of a unit DualQuaternion
+	pub fn translation(&self) -> Vector<T, 3> {
+		let two = T::one() + T::one();
+		let t = vector_part(&self.dual * &self.real.clone().conjugate());
+		Vector::from_array(t.map(|e| e * two.clone()))
+	}
+
+	/// Returns the DualQuaternion scaled to a unit DualQuaternion
+	///
+	/// The real part is scaled to length 1 and the part of the dual that is not perpendicular to
+	/// it is removed, so rounding errors built up by long chains of products go away
+	pub fn normalise(self) -> Self {
+		let inv = T::one() / self.real.length();
+		let real = self.real * inv.clone();
+		let dual = self.dual * inv;
+		let along = real.dot(&dual);
+		Self {
+			dual: dual - real.clone() * along,
+			real,
+		}
+	}
+
+	/// Returns the point p moved by the rotation and translation of a unit DualQuaternion
+	pub fn transform_point(&self, p: Vector<T, 3>) -> Vector<T, 3> {
+		let [x, y, z] = self.transform_vector(p).to_array();
+		let [tx, ty, tz] = self.translation().to_array();
+		Vector::from_array([x + tx, y + ty, z + tz])
+	}
+
+	/// Returns the direction v turned by the rotation of a unit DualQuaternion
+	///
+	/// Directions are not moved by the translation
+	pub fn transform_vector(&self, v: Vector<T, 3>) -> Vector<T, 3> {
+		let r = &self.real;
+		Vector::from_array(vector_part(r * &pure(v.to_array()) * r.clone().conjugate()))
+	}
+
+	/// Returns the normalised linear blend of self at t = 0 and other at t = 1 along the shorter
+	/// path
+	///
+	/// It is cheaper than [`DualQuaternion::sclerp`] and close to it when the rotations are
+	/// close, which is why it is the usual choice for blending skinning transforms
+	pub fn nlerp(&self, other: &Self, t: T) -> Self {
+		let other = if self.real.dot(&other.real) < T::zero() {
+			-other.clone()
+		} else {
+			other.clone()
+		};
+		(self.clone() * (T::one() - t.clone()) + other * t).normalise()
+	}
+
+	/// Returns the screw linear interpolation from self at t = 0 to other at t = 1 along the
+	/// shorter path
+	///
+	/// The transform from self to other is a screw motion, a rotation about an axis together
+	/// with a translation along it. The interpolation turns and moves along that screw at a
+	/// constant speed, so the path of every point is a helix. When the rotations are nearly the
+	/// same it falls back to [`DualQuaternion::nlerp`]
+	pub fn sclerp(&self, other: &Self, t: T) -> Self {
+		let one = T::one();
+		let two = one.clone() + one.clone();
+		let diff = &self.clone().conjugate() * other;
+		let diff = if diff.real.w < T::zero() { -diff } else { diff };
+		// Past about 0.9995 the screw axis is too badly conditioned to be worth finding
+		if diff.real.w > one.clone() - one.clone() / two.powi(11) {
+			return self.nlerp(other, t);
+		}
+
+		// The screw of diff: the angle and axis of its rotation, the distance moved along the
+		// axis and the moment of the axis about the origin
+		let DualQuaternion { real: r, dual: d } = diff;
+		let s = (&r.x * &r.x + &r.y * &r.y + &r.z * &r.z).sqrt();
+		let angle = two.clone() * s.atan2(&r.w);
+		let axis = [r.x, r.y, r.z].map(|e| e / s.clone());
+		let distance = -two.clone() * d.w / s.clone();
+		let along = distance.clone() * r.w / two.clone();
+		let [dx, dy, dz] = [d.x, d.y, d.z];
+		let moment = [dx, dy, dz]
+			.into_iter()
+			.zip(axis.clone())
+			.map(|(d, a)| (d - a * along.clone()) / s.clone());
+
+		let half_angle = t.clone() * angle / two.clone();
+		let half_distance = t * distance / two;
+		let (sin, cos) = (half_angle.sin(), half_angle.cos());
+		let mut dual = [T::zero(), T::zero(), T::zero()];
+		for (i, m) in moment.enumerate() {
+			dual[i] = m * sin.clone() + axis[i].clone() * half_distance.clone() * cos.clone();
+		}
+		let [x, y, z] = axis.map(|a| a * sin.clone());
+		let [dx, dy, dz] = dual;
+		let step = Self {
+			real: Quaternion { x, y, z, w: cos },
+			dual: Quaternion {
+				x: dx,
+				y: dy,
+				z: dz,
+				w: -half_distance * sin,
+			},
+		};
+		self * &step
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use glam::{Affine3A, Quat, Vec3};
+	use orml_vector::Vector;
+
+	use super::DualQuaternion;
+	use crate::Quaternion;
+
+	fn from_glam(q: Quat, t: Vec3) -> DualQuaternion<f32> {
+		DualQuaternion::<f32>::from_rotation_translation(
+			q.to_array().into(),
+			Vector::from_array(t.to_array()),
+		)
+	}
+
+	fn close(a: Vector<f32, 3>, b: Vec3) -> bool {
+		let a = a.to_array();
+		(a[0] - b.x).abs() < 1e-5 && (a[1] - b.y).abs() < 1e-5 && (a[2] - b.z).abs() < 1e-5
+	}
+
+	fn transforms() -> Vec<(Quat, Vec3)> {
+		vec![
+			(
+				Quat::from_axis_angle(Vec3::Z, 0.5),
+				Vec3::new(1.0, 2.0, 3.0),
+			),
+			(
+				Quat::from_axis_angle(Vec3::new(1.0, -1.0, 0.5).normalize(), 2.2),
+				Vec3::new(-0.5, 0.0, 4.0),
+			),
+			(Quat::IDENTITY, Vec3::new(0.0, -3.0, 0.0)),
+		]
+	}
+
+	#[test]
+	fn transform() {
+		let p = Vec3::new(0.3, -1.2, 2.0);
+		for (q, t) in transforms() {
+			let dq = from_glam(q, t);
+			let affine = Affine3A::from_rotation_translation(q, t);
+			let v = Vector::from_array(p.to_array());
+			assert!(close(dq.transform_point(v), affine.transform_point3(p)));
+			assert!(close(dq.transform_vector(v), affine.transform_vector3(p)));
+			assert!(close(dq.translation(), t));
+			assert_eq!(dq.rotation(), Quaternion::from_array(q.to_array()));
+		}
+	}
+
+	#[test]
+	fn compose() {
+		let p = Vec3::new(1.0, 0.5, -2.0);
+		let ts = transforms();
+		for (qa, ta) in &ts {
+			for (qb, tb) in &ts {
+				let dq = from_glam(*qa, *ta) * from_glam(*qb, *tb);
+				let affine = Affine3A::from_rotation_translation(*qa, *ta)
+					* Affine3A::from_rotation_translation(*qb, *tb);
+				let v = Vector::from_array(p.to_array());
+				assert!(close(dq.transform_point(v), affine.transform_point3(p)));
+			}
+		}
+	}
+
+	#[test]
+	fn conjugates() {
+		let (q, t) = transforms()[1];
+		let dq = from_glam(q, t);
+		// The conjugate of a unit DualQuaternion is its inverse
+		let id = dq * dq.conjugate();
+		assert!((id.real - Quaternion::identity()).length() < 1e-6);
+		assert!(id.dual.length() < 1e-6);
+
+		assert_eq!(dq.dual_conjugate().dual, -dq.dual);
+		assert_eq!(dq.combined_conjugate().real, dq.real.conjugate());
+		assert_eq!(dq.combined_conjugate().dual, -dq.dual.conjugate());
+	}
+
+	#[test]
+	fn normalise() {
+		let dq64 = DualQuaternion::<f64>::from_rotation_translation(
+			Quaternion::from_axis_angle([0.6, 0.0, -0.8], 2.2),
+			Vector::from_array([-0.5, 0.0, 4.0]),
+		);
+		// Scaling and a dual part along the real part are both removed
+		let noisy = DualQuaternion::new(dq64.real * 3.0, dq64.dual * 3.0 + dq64.real * 0.1);
+		let n = noisy.normalise();
+		assert!((n.real.length() - 1.0).abs() < 1e-15);
+		assert!(n.real.dot(&n.dual).abs() < 1e-15);
+		assert!((n.real - dq64.real).length() < 1e-15);
+		assert!((n.dual - dq64.dual).length() < 1e-15);
+	}
+
+	#[test]
+	fn sclerp_ends() {
+		let ts = transforms();
+		let (a, b) = (from_glam(ts[0].0, ts[0].1), from_glam(ts[1].0, ts[1].1));
+		let start = a.sclerp(&b, 0.0);
+		let end = a.sclerp(&b, 1.0);
+		assert!((start.real - a.real).length() < 1e-5 && (start.dual - a.dual).length() < 1e-5);
+		assert!((end.real - b.real).length() < 1e-5 && (end.dual - b.dual).length() < 1e-5);
+		// -b is the same transform
+		let mid = a.sclerp(&b, 0.4);
+		let flipped = a.sclerp(&-b, 0.4);
+		assert!((mid.real - flipped.real).length() < 1e-5);
+		assert!((mid.dual - flipped.dual).length() < 1e-5);
+	}
+
+	#[test]
+	fn sclerp_screw() {
+		// A screw about the Z axis through (1, 0, 0) by π/2 and 4 along the axis
+		let axis_point = Vector::from_array([1.0, 0.0, 0.0]);
+		let screw = |angle: f64, distance: f64| {
+			let r = Quaternion::from_axis_angle([0.0, 0.0, 1.0], angle);
+			let to = DualQuaternion::<f64>::from_translation(axis_point);
+			let from = DualQuaternion::<f64>::from_translation(-axis_point);
+			let along =
+				DualQuaternion::<f64>::from_translation(Vector::from_array([0.0, 0.0, distance]));
+			along
+				* to * DualQuaternion::<f64>::from_rotation_translation(r, Vector::from_array([0.0; 3]))
+				* from
+		};
+		let a = DualQuaternion::<f64>::identity();
+		let b = screw(std::f64::consts::FRAC_PI_2, 4.0);
+		for t in [0.25, 0.5, 0.8] {
+			let expected = screw(std::f64::consts::FRAC_PI_2 * t, 4.0 * t);
+			let q = a.sclerp(&b, t);
+			assert!((q.real - expected.real).length() < 1e-14, "{t}");
+			assert!((q.dual - expected.dual).length() < 1e-14, "{t}");
+			// Points on the axis only move along it
+			let p = q
+				.transform_point(Vector::from_array([1.0, 0.0, 7.0]))
+				.to_array();
+			assert!((p[0] - 1.0).abs() < 1e-14 && p[1].abs() < 1e-14);
+			assert!((p[2] - 7.0 - 4.0 * t).abs() < 1e-14);
+		}
+	}
+
+	#[test]
+	fn sclerp_translation() {
+		// Pure translations take the nlerp path and move in a straight line
+		let a = DualQuaternion::<f64>::from_translation(Vector::from_array([1.0, 0.0, 0.0]));
+		let b = DualQuaternion::<f64>::from_translation(Vector::from_array([3.0, -2.0, 8.0]));
+		let mid = a.sclerp(&b, 0.25).translation().to_array();
+		let expected = [1.5, -0.5, 2.0];
+		assert!((0..3).all(|i| (mid[i] - expected[i]).abs() < 1e-15));
+	}
+}
@@ -0,0 +1,261 @@
+//! This module contains conversions between unit Quaternions and Euler angles
+
+use std::ops::Mul;
+
+use num_traits::{One, Zero};
+use orml_traits::Float;
+
+use crate::Quaternion;
+
+/// The axes of the three rotations of a set of Euler angles, in order
+///
+/// The rotations are intrinsic, each one about the axes left by the ones before it. So XYZ
+/// rotates about X, then the new Y, then the newest Z, which is the Quaternion Rx * Ry * Rz and
+/// the same as extrinsic rotations about Z, Y then X
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EulerOrder {
+	/// Tait-Bryan X, Y, Z
+	XYZ,
+	/// Tait-Bryan X, Z, Y
+	XZY,
+	/// Tait-Bryan Y, X, Z
+	YXZ,
+	/// Tait-Bryan Y, Z, X
+	YZX,
+	/// Tait-Bryan Z, X, Y
+	ZXY,
+	/// Tait-Bryan Z, Y, X
+	ZYX,
+	/// Proper Euler X, Y, X
+	XYX,
+	/// Proper Euler X, Z, X
+	XZX,
+	/// Proper Euler Y, X, Y
+	YXY,
+	/// Proper Euler Y, Z, Y
+	YZY,
+	/// Proper Euler Z, X, Z
+	ZXZ,
+	/// Proper Euler Z, Y, Z
+	ZYZ,
+}
+
+impl EulerOrder {
+	/// Every EulerOrder
+	pub const ALL: [EulerOrder; 12] = [
+		EulerOrder::XYZ,
+		EulerOrder::XZY,
+		EulerOrder::YXZ,
+		EulerOrder::YZX,
+		EulerOrder::ZXY,
+		EulerOrder::ZYX,
+		EulerOrder::XYX,
+		EulerOrder::XZX,
+		EulerOrder::YXY,
+		EulerOrder::YZY,
+		EulerOrder::ZXZ,
+		EulerOrder::ZYZ,
+	];
+
+	/// Returns the axes in order with 0, 1 and 2 for X, Y and Z
+	pub fn axes(self) -> [usize; 3] {
+		match self {
+			EulerOrder::XYZ => [0, 1, 2],
+			EulerOrder::XZY => [0, 2, 1],
+			EulerOrder::YXZ => [1, 0, 2],
+			EulerOrder::YZX => [1, 2, 0],
+			EulerOrder::ZXY => [2, 0, 1],
+			EulerOrder::ZYX => [2, 1, 0],
+			EulerOrder::XYX => [0, 1, 0],
+			EulerOrder::XZX => [0, 2, 0],
+			EulerOrder::YXY => [1, 0, 1],
+			EulerOrder::YZY => [1, 2, 1],
+			EulerOrder::ZXZ => [2, 0, 2],
+			EulerOrder::ZYZ => [2, 1, 2],
+		}
+	}
+
+	/// Returns whether the first and last axes are the same
+	pub fn is_proper(self) -> bool {
+		let [i, _, k] = self.axes();
+		i == k
+	}
+}
+
+impl<T> Quaternion<T>
+where
+	T: Float + Zero + One + PartialOrd + Clone,
+	for<'a> &'a T: Mul<&'a T, Output = T>,
+{
+	/// Creates a new unit Quaternion rotating by angle about the X, Y or Z axis
+	fn from_axis_index(axis: usize, angle: T) -> Self {
+		let half = angle / (T::one() + T::one());
+		let (s, c) = (half.sin(), half.cos());
+		let mut v = [T::zero(), T::zero(), T::zero()];
+		v[axis] = s;
+		let [x, y, z] = v;
+		Self { x, y, z, w: c }
+	}
+
+	/// Creates a new unit Quaternion from Euler angles a, b and c about the axes of order
+	pub fn from_euler(order: EulerOrder, a: T, b: T, c: T) -> Self {
+		let [i, j, k] = order.axes();
+		Self::from_axis_index(i, a) * Self::from_axis_index(j, b) * Self::from_axis_index(k, c)
+	}
+
+	/// Returns the Euler angles about the axes of order of a unit Quaternion
+	///
+	/// The first and last angles are in [-π, π]. The middle one is in [0, π] for proper Euler
+	/// orders and [-π/2, π/2] for Tait-Bryan orders. At gimbal lock, when the first and last
+	/// axes line up, the rotation about them is split evenly between the two angles
+	pub fn to_euler(&self, order: EulerOrder) -> (T, T, T) {
+		// Bernardes and Viollet's direct method, which is written for extrinsic rotations, so it
+		// is run on the reversed axes and the angles are reversed back
+		let [k0, j, i] = order.axes();
+		let proper = order.is_proper();
+		let k = if proper { 3 - i - j } else { k0 };
+		let q = [&self.x, &self.y, &self.z];
+		let w = self.w.clone();
+		// The parity of the permutation (i, j, k)
+		let qk = if (i + 1) % 3 == j {
+			q[k].clone()
+		} else {
+			-q[k].clone()
+		};
+
+		let (a, b, c, d) = if proper {
+			(w, q[i].clone(), q[j].clone(), qk)
+		} else {
+			(
+				w.clone() - q[j].clone(),
+				q[i].clone() + qk.clone(),
+				q[j].clone() + w,
+				qk - q[i].clone(),
+			)
+		};
+
+		let one = T::one();
+		let two = one.clone() + one.clone();
+		let pi = (-one).acos();
+		let mut middle = two.clone()
+			* c.clone()
+				.hypot(d.clone())
+				.atan2(&a.clone().hypot(b.clone()));
+		let half_sum = b.atan2(&a);
+		let half_diff = d.atan2(&c);
+
+		let tau = two.clone() * pi.clone();
+		let wrap = |angle: T| {
+			if angle > pi {
+				angle - tau.clone()
+			} else if angle < -pi.clone() {
+				angle + tau.clone()
+			} else {
+				angle
+			}
+		};
+		let first = wrap(half_sum.clone() - half_diff.clone());
+		let mut last = wrap(half_sum + half_diff);
+		if !proper {
+			if (i + 1) % 3 != j {
+				last = -last;
+			}
+			middle -= pi.clone() / two;
+		}
+
+		(last, middle, first)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use glam::{EulerRot, Quat, Vec3};
+
+	use super::EulerOrder;
+	use crate::Quaternion;
+
+	const ANGLES: [(f64, f64, f64); 5] = [
+		(0.1, 0.2, 0.3),
+		(-2.5, 1.2, 0.7),
+		(3.0, -0.4, -1.9),
+		(0.8, 1.5, -3.1),
+		(-0.3, 0.05, 2.2),
+	];
+
+	fn same_rotation(a: &Quaternion<f64>, b: &Quaternion<f64>) -> bool {
+		// q and -q are the same rotation
+		(a.dot(b).abs() - 1.0).abs() < 1e-12
+	}
+
+	#[test]
+	fn round_trip() {
+		for order in EulerOrder::ALL {
+			for (a, b, c) in ANGLES {
+				// Proper Euler angles need a positive middle angle to be unique
+				let b = if order.is_proper() { b.abs() } else { b };
+				let q = Quaternion::<f64>::from_euler(order, a, b, c);
+				let (a2, b2, c2) = q.to_euler(order);
+				assert!(
+					(a - a2).abs() < 1e-12 && (b - b2).abs() < 1e-12 && (c - c2).abs() < 1e-12,
+					"{order:?} {:?} {:?}",
+					(a, b, c),
+					(a2, b2, c2)
+				);
+			}
+		}
+	}
+
+	#[test]
+	fn gimbal_lock() {
+		for order in EulerOrder::ALL {
+			let middle = if order.is_proper() {
+				0.0
+			} else {
+				std::f64::consts::FRAC_PI_2
+			};
+			let q = Quaternion::<f64>::from_euler(order, 0.4, middle, 0.2);
+			let (a, b, c) = q.to_euler(order);
+			assert!(same_rotation(
+				&Quaternion::<f64>::from_euler(order, a, b, c),
+				&q
+			));
+		}
+	}
+
+	#[test]
+	fn glam() {
+		let orders = [
+			(EulerOrder::XYZ, EulerRot::XYZ),
+			(EulerOrder::XZY, EulerRot::XZY),
+			(EulerOrder::YXZ, EulerRot::YXZ),
+			(EulerOrder::YZX, EulerRot::YZX),
+			(EulerOrder::ZXY, EulerRot::ZXY),
+			(EulerOrder::ZYX, EulerRot::ZYX),
+		];
+		for (order, rot) in orders {
+			for (a, b, c) in ANGLES {
+				let expected = Quat::from_euler(rot, a as f32, b as f32, c as f32);
+				let q = Quaternion::<f32>::from_euler(order, a as f32, b as f32, c as f32);
+				let d = q.x * expected.x + q.y * expected.y + q.z * expected.z + q.w * expected.w;
+				assert!((d.abs() - 1.0).abs() < 1e-6, "{order:?}");
+
+				let (a2, b2, c2) = Quaternion::from_array(expected.to_array()).to_euler(order);
+				let (ga, gb, gc) = expected.to_euler(rot);
+				assert!((a2 - ga).abs() < 1e-4 && (b2 - gb).abs() < 1e-4 && (c2 - gc).abs() < 1e-4);
+			}
+		}
+
+		// glam has no proper Euler orders, so build them from its axis rotations
+		for order in EulerOrder::ALL.into_iter().filter(|o| o.is_proper()) {
+			let axes = order.axes().map(|i| [Vec3::X, Vec3::Y, Vec3::Z][i]);
+			let (a, b, c) = (0.7f32, 1.1f32, -2.0f32);
+			let expected = Quat::from_axis_angle(axes[0], a)
+				* Quat::from_axis_angle(axes[1], b)
+				* Quat::from_axis_angle(axes[2], c);
+			let q = Quaternion::<f32>::from_euler(order, a, b, c);
+			let d = q.x * expected.x + q.y * expected.y + q.z * expected.z + q.w * expected.w;
+			assert!((d.abs() - 1.0).abs() < 1e-6, "{order:?}");
+		}
+	}
+}
@@ -0,0 +1,266 @@
+//! This module contains the exponential, logarithm and powers of Quaternions
+//!
+//! A Quaternion is split into its real part w and its vector part v = (x, y, z). Every function
+//! here works on that split like the complex functions do on a + bi, with v / |v| in place of i
+
+use std::ops::{Add, Div, Mul, Sub};
+
+use num_traits::{One, Zero};
+use orml_traits::fns::exp::Exp;
+use orml_traits::fns::log::Ln;
+use orml_traits::fns::trig::{Atan2, Cos, Sin};
+use orml_traits::fns::SquareRoot;
+
+use crate::Quaternion;
+
+impl<T> Quaternion<T>
+where
+	T: Add<Output = T> + SquareRoot,
+	for<'a> &'a T: Mul<&'a T, Output = T>,
+{
+	/// Returns the length of the vector part
+	fn vector_length(&self) -> T {
+		(&self.x * &self.x + &self.y * &self.y + &self.z * &self.z).sqrt()
+	}
+}
+
+impl<T> Quaternion<T>
+where
+	T: Sin + Cos + Exp + SquareRoot + Zero + Add<Output = T> + Div<Output = T> + Clone,
+	for<'a> &'a T: Mul<&'a T, Output = T>,
+{
+	/// Returns the exponential e^q of the Quaternion
+	///
+	/// It is e^w (cos |v| + v / |v| sin |v|), so a Quaternion with w = 0 goes to a unit Quaternion
+	pub fn exp(self) -> Self {
+		let s = self.vector_length();
+		let ew = self.w.exp();
+		if s.is_zero() {
+			return Self {
+				x: T::zero(),
+				y: T::zero(),
+				z: T::zero(),
+				w: ew,
+			};
+		}
+		let k = &ew * &(s.sin() / s.clone());
+		Self {
+			x: &self.x * &k,
+			y: &self.y * &k,
+			z: &self.z * &k,
+			w: &ew * &s.cos(),
+		}
+	}
+}
+
+impl<T> Quaternion<T>
+where
+	T: Atan2 + Ln + SquareRoot + Zero + Add<Output = T> + Div<Output = T> + Clone,
+	for<'a> &'a T: Mul<&'a T, Output = T>,
+{
+	/// Returns the principal natural logarithm of the Quaternion
+	///
+	/// It is ln |q| + v / |v| atan2(|v|, w), the inverse of [`Quaternion::exp`] for vector parts
+	/// shorter than π. A negative real Quaternion has no single v / |v|, so the X axis is used
+	pub fn ln(self) -> Self {
+		let s = self.vector_length();
+		let n = (&s * &s + &self.w * &self.w).sqrt();
+		// acos(w / |q|) would lose a short vector part to the rounding of w / |q| near 1
+		let angle = s.atan2(&self.w);
+		let ln_n = n.ln();
+		if s.is_zero() {
+			// atan2 gives 0 for positive and π for negative real Quaternions
+			return Self {
+				x: angle,
+				y: T::zero(),
+				z: T::zero(),
+				w: ln_n,
+			};
+		}
+		let k = angle / s;
+		Self {
+			x: &self.x * &k,
+			y: &self.y * &k,
+			z: &self.z * &k,
+			w: ln_n,
+		}
+	}
+}
+
+impl<T> Quaternion<T>
+where
+	T: Sin + Cos + Atan2 + Ln + Exp + SquareRoot + Zero + Add<Output = T> + Div<Output = T> + Clone,
+	for<'a> &'a T: Mul<&'a T, Output = T>,
+{
+	/// Returns the Quaternion raised to the real power t, e^(t ln q)
+	///
+	/// For a unit Quaternion this is the rotation about the same axis by t times the angle
+	pub fn pow(self, t: T) -> Self {
+		(self.ln() * t).exp()
+	}
+}
+
+impl<T> Quaternion<T>
+where
+	T: SquareRoot
+		+ Zero
+		+ One
+		+ Add<Output = T>
+		+ Sub<Output = T>
+		+ Div<Output = T>
+		+ PartialOrd
+		+ Clone,
+	for<'a> &'a T: Mul<&'a T, Output = T>,
+{
+	/// Returns the principal square root of the Quaternion, the one with w >= 0
+	///
+	/// It is the same as [`Quaternion::pow`] by 1/2, but needs only square roots. A negative real
+	/// Quaternion has its root on the X axis, as with [`Quaternion::ln`]
+	pub fn sqrt(self) -> Self {
+		let n = self.length();
+		let two = T::one() + T::one();
+		if self.w >= T::zero() {
+			let w = ((n + self.w.clone()) / two.clone()).sqrt();
+			if w.is_zero() {
+				return self;
+			}
+			let k = T::one() / (&two * &w);
+			return Self {
+				x: &self.x * &k,
+				y: &self.y * &k,
+				z: &self.z * &k,
+				w,
+			};
+		}
+		// n + w cancels for negative w, so the length of the vector part of the root is found
+		// from n - w instead
+		let s = self.vector_length();
+		let t = ((n - self.w.clone()) / two.clone()).sqrt();
+		if s.is_zero() {
+			return Self {
+				x: t,
+				y: T::zero(),
+				z: T::zero(),
+				w: T::zero(),
+			};
+		}
+		let w = s.clone() / (&two * &t);
+		let k = t / s;
+		Self {
+			x: &self.x * &k,
+			y: &self.y * &k,
+			z: &self.z * &k,
+			w,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use orml_easy_big_float::EasyBigFloat;
+
+	use crate::Quaternion;
+
+	fn quats() -> Vec<Quaternion<f64>> {
+		[
+			[0.1, -0.2, 0.3, 0.9],
+			[1.5, 0.5, -2.0, -3.0],
+			[0.0, 0.0, 0.0, 2.0],
+			[-0.01, 0.02, 0.0, -0.5],
+			[0.7, 0.1, 0.4, 0.0],
+		]
+		.map(Quaternion::from_array)
+		.to_vec()
+	}
+
+	#[test]
+	fn exp_ln() {
+		for q in quats() {
+			let back = q.ln().exp();
+			assert!((back - q).length() < 1e-14 * q.length(), "{q:?}");
+		}
+		// exp of a vector part shorter than π is undone by ln
+		let p = Quaternion::from_array([0.4, -1.1, 0.9, -0.3]);
+		assert!((p.exp().ln() - p).length() < 1e-14);
+
+		// e^(θ/2 axis) is the rotation by θ about axis
+		let axis = [0.0, 0.6, 0.8];
+		let pure = Quaternion::from_array([0.0, 0.6 * 0.35, 0.8 * 0.35, 0.0]);
+		let expected = Quaternion::from_axis_angle(axis, 0.7);
+		assert!((pure.exp() - expected).length() < 1e-15);
+
+		assert_eq!(
+			Quaternion::from_array([0.0, 0.0, 0.0, 1.0]).exp(),
+			Quaternion::from_array([0.0, 0.0, 0.0, std::f64::consts::E])
+		);
+		assert_eq!(
+			Quaternion::from_array([0.0, 0.0, 0.0, 1.0]).ln(),
+			Quaternion::from_array([0.0, 0.0, 0.0, 0.0])
+		);
+	}
+
+	#[test]
+	fn small_angle() {
+		// The vector part is far below the rounding of |q| but still has to survive ln
+		for x in [1e-9f64, 3e-8, -2e-12] {
+			let q = Quaternion::from_array([x, 0.0, 0.0, 1.0]);
+			let ln = q.ln();
+			assert!((ln.x - x).abs() < 1e-15 * x.abs(), "{x}");
+			let back = ln.exp();
+			assert!((back.x - q.x).abs() < 1e-15 * x.abs(), "{x}");
+			assert_eq!(back.w, 1.0);
+		}
+	}
+
+	#[test]
+	fn negative_real() {
+		let q = Quaternion::from_array([0.0, 0.0, 0.0, -4.0]);
+		let ln = q.ln();
+		assert_eq!(
+			ln,
+			Quaternion::from_array([std::f64::consts::PI, 0.0, 0.0, 4.0f64.ln()])
+		);
+		assert!((ln.exp() - q).length() < 1e-14);
+		let root = q.sqrt();
+		assert_eq!(root, Quaternion::from_array([2.0, 0.0, 0.0, 0.0]));
+		assert_eq!(root * root, q);
+		assert!((q.pow(0.5) - root).length() < 1e-15);
+	}
+
+	#[test]
+	fn powers() {
+		for q in quats() {
+			let scale = q.length_squared();
+			assert!((q.pow(1.0) - q).length() < 1e-14 * q.length());
+			assert!((q.pow(2.0) - q * q).length() < 1e-14 * scale);
+			assert!((q.pow(3.0) - q * q * q).length() < 1e-13 * scale * q.length());
+			assert!((q.pow(0.0) - Quaternion::identity()).length() < 1e-15);
+
+			let root = q.sqrt();
+			assert!(root.w >= 0.0);
+			assert!((root * root - q).length() < 1e-14 * scale.max(1.0), "{q:?}");
+			assert!((q.pow(0.5) - root).length() < 1e-14 * q.length().max(1.0));
+		}
+
+		// A power of a unit Quaternion scales its angle
+		let q = Quaternion::from_axis_angle([0.6, 0.0, 0.8], 1.2);
+		let expected = Quaternion::from_axis_angle([0.6, 0.0, 0.8], 1.2 * 0.3);
+		assert!((q.pow(0.3) - expected).length() < 1e-15);
+	}
+
+	#[test]
+	fn big_float() {
+		type BF = EasyBigFloat<256>;
+
+		let [x, y, z, w] = [0.1, -0.2, 0.3, 0.9].map(BF::from);
+		let q = Quaternion { x, y, z, w };
+		let eps = BF::from(1e-70);
+		let small = |q: Quaternion<BF>| q.length() < eps;
+
+		assert!(small(q.clone().ln().exp() - q.clone()));
+		let root = q.clone().sqrt();
+		assert!(small(root.clone() * root.clone() - q.clone()));
+		assert!(small(q.clone().pow(BF::from(0.5)) - root));
+		assert!(small(q.clone().pow(BF::from(2.0)) - q.clone() * q));
+	}
+}
@@ -0,0 +1,281 @@
+//! This module contains interpolation between unit Quaternions
+
+use std::ops::Mul;
+
+use num_traits::{One, Zero};
+use orml_traits::Float;
+
+use crate::Quaternion;
+
+impl<T> Quaternion<T>
+where
+	T: Float + Zero + One + PartialOrd + Clone,
+	for<'a> &'a T: Mul<&'a T, Output = T>,
+{
+	/// Returns other or -other, whichever is the same rotation along the shorter path from self
+	fn shortest(&self, other: &Self) -> Self {
+		if self.dot(other) < T::zero() {
+			-other.clone()
+		} else {
+			other.clone()
+		}
+	}
+
+	/// Returns the normalised linear interpolation without picking the shorter path
+	fn nlerp_direct(&self, other: &Self, t: T) -> Self {
+		(self.clone() * (T::one() - t.clone()) + other.clone() * t).normalise()
+	}
+
+	/// Returns the spherical linear interpolation without picking the shorter path
+	fn slerp_direct(&self, other: &Self, t: T) -> Self {
+		let one = T::one();
+		let two = one.clone() + one.clone();
+		// About 0.9995, past which sin θ is too small to divide by and nlerp is as good
+		let threshold = one.clone() - one.clone() / two.powi(11);
+		let d = self.dot(other).max(-one.clone());
+		if d > threshold {
+			return self.nlerp_direct(other, t);
+		}
+		let theta = d.acos();
+		let sin = theta.sin();
+		let a = ((one - t.clone()) * theta.clone()).sin() / sin.clone();
+		let b = (t * theta).sin() / sin;
+		self.clone() * a + other.clone() * b
+	}
+
+	/// Returns the normalised linear interpolation from self at t = 0 to other at t = 1 along the
+	/// shorter path
+	///
+	/// It is cheaper than [`Quaternion::slerp`] and follows the same path, but not at a constant
+	/// angular speed
+	pub fn nlerp(&self, other: &Self, t: T) -> Self {
+		self.nlerp_direct(&self.shortest(other), t)
+	}
+
+	/// Returns the spherical linear interpolation from self at t = 0 to other at t = 1 along the
+	/// shorter path
+	///
+	/// The rotation turns at a constant angular speed. When self and other are nearly parallel it
+	/// falls back to [`Quaternion::nlerp`]
+	pub fn slerp(&self, other: &Self, t: T) -> Self {
+		self.slerp_direct(&self.shortest(other), t)
+	}
+
+	/// Returns the squad control Quaternion of the keyframe current between prev and next
+	///
+	/// It is current * exp(-(ln(current⁻¹ next) + ln(current⁻¹ prev)) / 4), which makes the
+	/// [`Quaternion::squad`] curves on either side of current meet with the same tangent.
+	/// prev and next are first moved to the side of current, so the signs of the keyframes do
+	/// not matter
+	pub fn squad_control(prev: &Self, current: &Self, next: &Self) -> Self {
+		let inv = current.clone().conjugate();
+		let to_next = (&inv * &current.shortest(next)).ln();
+		let to_prev = (&inv * &current.shortest(prev)).ln();
+		let four = (T::one() + T::one()) * (T::one() + T::one());
+		let tangent = (to_next + to_prev) * (-T::one() / four);
+		current * tangent.exp()
+	}
+
+	/// Returns the squad control Quaternion of every keyframe of a spline through keys
+	///
+	/// The first and last keys have only one neighbour, so they are used as their own missing
+	/// one. Segment i of the spline is
+	/// `Quaternion::<f64>::squad(&keys[i], &keys[i + 1], &controls[i], &controls[i + 1], t)`
+	pub fn squad_controls(keys: &[Self]) -> Vec<Self> {
+		(0..keys.len())
+			.map(|i| {
+				let prev = &keys[i.saturating_sub(1)];
+				let next = &keys[(i + 1).min(keys.len() - 1)];
+				Self::squad_control(prev, &keys[i], next)
+			})
+			.collect()
+	}
+
+	/// Returns the spherical quadrangle interpolation from q1 at t = 0 to q2 at t = 1 with the
+	/// control Quaternions s1 and s2
+	///
+	/// With the controls of [`Quaternion::squad_controls`] the segments of a spline through a
+	/// sequence of keyframes join smoothly. It follows the shorter path from q1 to q2
+	pub fn squad(q1: &Self, q2: &Self, s1: &Self, s2: &Self, t: T) -> Self {
+		// The control of -q2 is -s2
+		let (q2, s2) = if q1.dot(q2) < T::zero() {
+			(-q2.clone(), -s2.clone())
+		} else {
+			(q2.clone(), s2.clone())
+		};
+		let one = T::one();
+		let h = (one.clone() + one.clone()) * t.clone() * (one - t.clone());
+		let a = q1.slerp_direct(&q2, t.clone());
+		let b = s1.slerp_direct(&s2, t);
+		a.slerp_direct(&b, h)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use glam::{Quat, Vec3};
+
+	use crate::Quaternion;
+
+	fn from_glam(q: Quat) -> Quaternion<f32> {
+		q.to_array().into()
+	}
+
+	fn same_rotation(a: &Quaternion<f64>, b: &Quaternion<f64>, eps: f64) -> bool {
+		// q and -q are the same rotation
+		(a.dot(b).abs() - 1.0).abs() < eps
+	}
+
+	fn keys() -> Vec<Quaternion<f64>> {
+		[
+			([1.0, 0.0, 0.0], 0.0),
+			([0.0, 1.0, 0.0], 1.0),
+			([0.6, 0.0, 0.8], 2.5),
+			([0.0, 0.6, -0.8], -0.7),
+			([1.0, 0.0, 0.0], 0.4),
+		]
+		.map(|(axis, angle)| Quaternion::from_axis_angle(axis, angle))
+		.to_vec()
+	}
+
+	#[test]
+	fn slerp() {
+		let a = Quat::from_axis_angle(Vec3::new(1.0, 2.0, -0.5).normalize(), 0.8);
+		let b = Quat::from_axis_angle(Vec3::new(-0.3, 0.2, 1.0).normalize(), 2.6);
+		let (qa, qb) = (from_glam(a), from_glam(b));
+		for t in [0.0, 0.1, 0.5, 0.77, 1.0] {
+			let q = qa.slerp(&qb, t);
+			let expected = a.slerp(b, t);
+			assert!((q - from_glam(expected)).length() < 1e-5, "{t}");
+		}
+		// The angle grows linearly with t
+		let (a, b) = (keys()[1], keys()[2]);
+		for t in [0.0, 0.1, 0.5, 0.77, 1.0] {
+			let q = a.slerp(&b, t);
+			assert!((a.angle_between(&q) - t * a.angle_between(&b)).abs() < 1e-7);
+		}
+		// -b is the same rotation, so the path is the same
+		for t in [0.2, 0.6] {
+			let q = qa.slerp(&-qb, t);
+			assert!((q - qa.slerp(&qb, t)).length() < 1e-6);
+		}
+	}
+
+	#[test]
+	fn slerp_nearly_parallel() {
+		let a = Quaternion::<f64>::from_axis_angle([0.0, 0.0, 1.0], 0.3);
+		let b = Quaternion::from_axis_angle([0.0, 0.0, 1.0], 0.3 + 1e-9);
+		let q = a.slerp(&b, 0.5);
+		assert!((q.length() - 1.0).abs() < 1e-15);
+		assert!((q - Quaternion::from_axis_angle([0.0, 0.0, 1.0], 0.3 + 5e-10)).length() < 1e-15);
+		assert!((a.slerp(&a, 0.4) - a).length() < 1e-15);
+	}
+
+	#[test]
+	fn nlerp() {
+		let a = Quat::from_axis_angle(Vec3::Y, 0.5);
+		let b = Quat::from_axis_angle(Vec3::new(1.0, 1.0, 0.0).normalize(), -1.5);
+		let (qa, qb) = (from_glam(a), from_glam(b));
+		for t in [0.0, 0.3, 0.5, 1.0] {
+			// glam's lerp is the normalised lerp along the shorter path
+			let expected = from_glam(a.lerp(b, t));
+			assert!((qa.nlerp(&qb, t) - expected).length() < 1e-6);
+			assert!((qa.nlerp(&-qb, t) - expected).length() < 1e-6);
+		}
+		// Both take the same path, at a different speed
+		let (q, r) = (qa.nlerp(&qb, 0.3), qa.slerp(&qb, 0.3));
+		let axis = |q: Quaternion<f32>| q.to_axis_angle().0;
+		assert!((q.angle_between(&r)) > 1e-4);
+		let n = from_glam(a.inverse()) * q;
+		let s = from_glam(a.inverse()) * r;
+		let (an, as_) = (axis(n), axis(s));
+		assert!((0..3).all(|i| (an[i] - as_[i]).abs() < 1e-4));
+	}
+
+	#[test]
+	fn squad_through_keys() {
+		let keys = keys();
+		let controls = Quaternion::<f64>::squad_controls(&keys);
+		assert_eq!(controls.len(), keys.len());
+		for i in 0..keys.len() - 1 {
+			let start = Quaternion::<f64>::squad(
+				&keys[i],
+				&keys[i + 1],
+				&controls[i],
+				&controls[i + 1],
+				0.0,
+			);
+			let end = Quaternion::<f64>::squad(
+				&keys[i],
+				&keys[i + 1],
+				&controls[i],
+				&controls[i + 1],
+				1.0,
+			);
+			assert!(same_rotation(&start, &keys[i], 1e-12));
+			assert!(same_rotation(&end, &keys[i + 1], 1e-12));
+		}
+	}
+
+	#[test]
+	fn squad_smooth() {
+		let keys = keys();
+		let controls = Quaternion::<f64>::squad_controls(&keys);
+		let at = |i: usize, t: f64| {
+			Quaternion::<f64>::squad(&keys[i], &keys[i + 1], &controls[i], &controls[i + 1], t)
+		};
+		// The one sided derivatives at each inner key agree
+		let h = 1e-5;
+		for i in 1..keys.len() - 1 {
+			let before = (at(i - 1, 1.0) - at(i - 1, 1.0 - h)) * (1.0 / h);
+			let after = (at(i, h) - at(i, 0.0)) * (1.0 / h);
+			assert!((before - after).length() < 1e-4, "{i}");
+		}
+	}
+
+	#[test]
+	fn squad_signs() {
+		let keys = keys();
+		let flipped: Vec<_> = keys
+			.iter()
+			.enumerate()
+			.map(|(i, k)| if i % 2 == 1 { -*k } else { *k })
+			.collect();
+		let controls = Quaternion::<f64>::squad_controls(&keys);
+		let flipped_controls = Quaternion::<f64>::squad_controls(&flipped);
+		for i in 0..keys.len() - 1 {
+			for t in [0.25, 0.5, 0.9] {
+				let q = Quaternion::<f64>::squad(
+					&keys[i],
+					&keys[i + 1],
+					&controls[i],
+					&controls[i + 1],
+					t,
+				);
+				let f = Quaternion::<f64>::squad(
+					&flipped[i],
+					&flipped[i + 1],
+					&flipped_controls[i],
+					&flipped_controls[i + 1],
+					t,
+				);
+				assert!(same_rotation(&q, &f, 1e-12));
+			}
+		}
+	}
+
+	#[test]
+	fn squad_about_one_axis() {
+		// Evenly spaced rotations about one axis are their own controls, so squad is slerp
+		let axis = [0.0, 0.6, 0.8];
+		let keys: Vec<_> = (0..4)
+			.map(|i| Quaternion::<f64>::from_axis_angle(axis, 0.7 * i as f64))
+			.collect();
+		let controls = Quaternion::<f64>::squad_controls(&keys);
+		for i in 1..3 {
+			assert!((controls[i] - keys[i]).length() < 1e-12);
+		}
+		let q = Quaternion::<f64>::squad(&keys[1], &keys[2], &controls[1], &controls[2], 0.3);
+		assert!((q - keys[1].slerp(&keys[2], 0.3)).length() < 1e-12);
+	}
+}
@@ -6,12 +6,13 @@
 //!
 //! This module contains a generic Quaternion that can be any type or size
 
-use std::ops::{Add, Mul};
-
-use num_traits::One;
-use orml_traits::fns::trig::{Cos, Sin};
-
 pub mod arithmetic;
+pub mod dual;
+pub mod euler;
+pub mod exponential;
+pub mod interpolation;
+pub mod matrix;
+pub mod rotation;
 //#[cfg(feature = "vector")]
 pub mod vector;
 
@@ -113,23 +114,3 @@ where
 }
 
 impl<T> Copy for Quaternion<T> where T: Copy {}
-
-impl<T> Quaternion<T>
-where
-	T: Sin + Cos + One + Add<Output = T> + Mul<Output = T> + Clone,
-	//for<'a> &'a T: Mul<&'a T, Output = T>,
-{
-	/// uh
-	#[inline]
-	pub fn from_axis_angle(axis: [T; 3], angle: T) -> Self {
-		let a5 = angle * (T::one() + T::one());
-		let s = a5.sin();
-		let c = a5.cos();
-		Self {
-			x: axis[0].clone() * s.clone(),
-			y: axis[1].clone() * s.clone(),
-			z: axis[2].clone() * s.clone(),
-			w: c,
-		}
-	}
-}
@@ -0,0 +1,195 @@
+//! This module contains conversions between unit Quaternions and rotation Matrices
+
+use std::array;
+use std::ops::{Add, Mul, Sub};
+
+use num_traits::{One, Zero};
+use orml_matrix::Matrix;
+use orml_traits::Float;
+use orml_vector::Vector;
+
+use crate::Quaternion;
+
+impl<T> Quaternion<T>
+where
+	T: Add<Output = T> + Sub<Output = T> + Zero + One + Clone,
+	for<'a> &'a T: Mul<&'a T, Output = T>,
+{
+	/// Returns the 3x3 rotation Matrix of a unit Quaternion
+	pub fn to_rotation_matrix(&self) -> Matrix<T, 3, 3> {
+		let Self { x, y, z, w } = self;
+		let two = T::one() + T::one();
+		let (xx, yy, zz) = (x * x, y * y, z * z);
+		let (xy, xz, yz) = (x * y, x * z, y * z);
+		let (xw, yw, zw) = (x * w, y * w, z * w);
+		let diag = |a: &T, b: &T| T::one() - &two * &(a.clone() + b.clone());
+		let off = |a: T| &two * &a;
+		[
+			[
+				diag(&yy, &zz),
+				off(xy.clone() - zw.clone()),
+				off(xz.clone() + yw.clone()),
+			],
+			[off(xy + zw), diag(&xx, &zz), off(yz.clone() - xw.clone())],
+			[off(xz - yw), off(yz + xw), diag(&xx, &yy)],
+		]
+		.into()
+	}
+
+	/// Returns the 4x4 homogeneous rotation Matrix of a unit Quaternion
+	pub fn to_rotation_matrix4(&self) -> Matrix<T, 4, 4> {
+		let m = self.to_rotation_matrix();
+		array::from_fn(|r| {
+			array::from_fn(|c| match (r, c) {
+				(0..=2, 0..=2) => m[(r, c)].clone(),
+				_ if r == c => T::one(),
+				_ => T::zero(),
+			})
+		})
+		.into()
+	}
+}
+
+impl<T> Quaternion<T>
+where
+	T: Float + Zero + One + PartialOrd + Clone,
+{
+	/// Creates a new unit Quaternion from a 3x3 rotation Matrix by Shepperd's method
+	///
+	/// The largest of w, x, y and z is found from the diagonal and computed first, so the others
+	/// are only ever divided by a number of at least 1/2
+	pub fn from_rotation_matrix(m: &Matrix<T, 3, 3>) -> Self {
+		let e = |r: usize, c: usize| m[(r, c)].clone();
+		let one = T::one();
+		let half = one.clone() / (one.clone() + one.clone());
+		let trace = e(0, 0) + e(1, 1) + e(2, 2);
+
+		let candidates = [trace.clone(), e(0, 0), e(1, 1), e(2, 2)];
+		let mut largest = 0;
+		for i in 1..4 {
+			if candidates[i] > candidates[largest] {
+				largest = i;
+			}
+		}
+
+		// s is twice the largest component and k turns the sums below into the others
+		match largest {
+			0 => {
+				let s = (one + trace).sqrt();
+				let k = half.clone() / s.clone();
+				Self {
+					x: (e(2, 1) - e(1, 2)) * k.clone(),
+					y: (e(0, 2) - e(2, 0)) * k.clone(),
+					z: (e(1, 0) - e(0, 1)) * k,
+					w: s * half,
+				}
+			}
+			1 => {
+				let s = (one + e(0, 0) - e(1, 1) - e(2, 2)).sqrt();
+				let k = half.clone() / s.clone();
+				Self {
+					x: s * half,
+					y: (e(0, 1) + e(1, 0)) * k.clone(),
+					z: (e(0, 2) + e(2, 0)) * k.clone(),
+					w: (e(2, 1) - e(1, 2)) * k,
+				}
+			}
+			2 => {
+				let s = (one - e(0, 0) + e(1, 1) - e(2, 2)).sqrt();
+				let k = half.clone() / s.clone();
+				Self {
+					x: (e(0, 1) + e(1, 0)) * k.clone(),
+					y: s * half,
+					z: (e(1, 2) + e(2, 1)) * k.clone(),
+					w: (e(0, 2) - e(2, 0)) * k,
+				}
+			}
+			_ => {
+				let s = (one - e(0, 0) - e(1, 1) + e(2, 2)).sqrt();
+				let k = half.clone() / s.clone();
+				Self {
+					x: (e(0, 2) + e(2, 0)) * k.clone(),
+					y: (e(1, 2) + e(2, 1)) * k.clone(),
+					z: s * half,
+					w: (e(1, 0) - e(0, 1)) * k,
+				}
+			}
+		}
+	}
+
+	/// Creates a new unit Quaternion turning the X, Y and Z axes to the orthonormal columns of cols
+	pub(crate) fn from_basis(cols: [[T; 3]; 3]) -> Self {
+		Self::from_rotation_matrix(&Matrix::from_cols(cols.map(Vector::from_array)))
+	}
+
+	/// Creates a new unit Quaternion from the rotation in the top left 3x3 of a 4x4 Matrix
+	pub fn from_rotation_matrix4(m: &Matrix<T, 4, 4>) -> Self {
+		let m3: Matrix<T, 3, 3> = array::from_fn(|r| array::from_fn(|c| m[(r, c)].clone())).into();
+		Self::from_rotation_matrix(&m3)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use glam::{Mat3, Mat4, Quat, Vec3};
+	use orml_matrix::Matrix;
+
+	use crate::Quaternion;
+
+	fn quats() -> Vec<Quat> {
+		// Includes rotations by about pi so every branch of Shepperd's method is taken
+		[
+			(Vec3::X, 0.3),
+			(Vec3::new(1.0, 2.0, 3.0), 1.2),
+			(Vec3::new(-1.0, 0.5, 0.2), 3.1),
+			(Vec3::new(0.1, -1.0, 0.3), 3.0),
+			(Vec3::new(0.2, 0.1, 1.0), -2.9),
+			(Vec3::Z, 0.0),
+		]
+		.map(|(axis, angle)| Quat::from_axis_angle(axis.normalize(), angle))
+		.to_vec()
+	}
+
+	fn from_glam(q: Quat) -> Quaternion<f32> {
+		q.to_array().into()
+	}
+
+	fn same_rotation(a: &Quaternion<f32>, b: Quat) -> bool {
+		// q and -q are the same rotation
+		let d = a.x * b.x + a.y * b.y + a.z * b.z + a.w * b.w;
+		(d.abs() - 1.0).abs() < 1e-5
+	}
+
+	#[test]
+	fn to_matrix() {
+		for q in quats() {
+			let m = from_glam(q).to_rotation_matrix();
+			let expected = Mat3::from_quat(q);
+			let m4 = from_glam(q).to_rotation_matrix4();
+			let expected4 = Mat4::from_quat(q);
+			for c in 0..3 {
+				for r in 0..3 {
+					assert!((m[(r, c)] - expected.col(c)[r]).abs() < 1e-6);
+				}
+			}
+			for c in 0..4 {
+				for r in 0..4 {
+					assert!((m4[(r, c)] - expected4.col(c)[r]).abs() < 1e-6);
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn from_matrix() {
+		for q in quats() {
+			let g = Mat3::from_quat(q);
+			let m: Matrix<f32, 3, 3> =
+				std::array::from_fn(|r| std::array::from_fn(|c| g.col(c)[r])).into();
+			assert!(same_rotation(&Quaternion::from_rotation_matrix(&m), q));
+
+			let m4 = from_glam(q).to_rotation_matrix4();
+			assert!(same_rotation(&Quaternion::from_rotation_matrix4(&m4), q));
+		}
+	}
+}
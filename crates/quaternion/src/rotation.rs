@@ -0,0 +1,249 @@
+//! This module contains the rotation API of unit Quaternions
+
+use std::ops::{Add, Div, Mul};
+
+use num_traits::{One, Zero};
+use orml_traits::fns::trig::{Cos, Sin};
+use orml_traits::Float;
+use orml_vector::Vector;
+
+use crate::Quaternion;
+
+impl<T> Quaternion<T>
+where
+	T: Zero + One,
+{
+	/// Returns the identity Quaternion, the rotation by 0
+	#[inline]
+	pub fn identity() -> Self {
+		Self {
+			x: T::zero(),
+			y: T::zero(),
+			z: T::zero(),
+			w: T::one(),
+		}
+	}
+}
+
+impl<T> Quaternion<T>
+where
+	T: Sin + Cos + One + Add<Output = T> + Mul<Output = T> + Div<Output = T> + Clone,
+{
+	/// Creates a new unit Quaternion rotating by angle radians about a unit length axis
+	#[inline]
+	pub fn from_axis_angle(axis: [T; 3], angle: T) -> Self {
+		let half = angle / (T::one() + T::one());
+		let s = half.sin();
+		let [x, y, z] = axis.map(|a| a * s.clone());
+		Self {
+			x,
+			y,
+			z,
+			w: half.cos(),
+		}
+	}
+}
+
+/// Returns the cross product of two 3D vectors
+fn cross<T: Float + Clone>(a: &[T; 3], b: &[T; 3]) -> [T; 3] {
+	let e = |i: usize, j: usize| a[i].clone() * b[j].clone() - a[j].clone() * b[i].clone();
+	[e(1, 2), e(2, 0), e(0, 1)]
+}
+
+/// Returns the 3D vector scaled to length 1
+fn normalise<T: Float + Clone>(a: [T; 3]) -> [T; 3] {
+	let len =
+		(a[0].clone() * a[0].clone() + a[1].clone() * a[1].clone() + a[2].clone() * a[2].clone())
+			.sqrt();
+	a.map(|e| e / len.clone())
+}
+
+impl<T> Quaternion<T>
+where
+	T: Float + Zero + One + PartialOrd + Clone,
+	for<'a> &'a T: Mul<&'a T, Output = T>,
+{
+	/// Returns the unit axis and the angle in [0, 2π] of the rotation of a unit Quaternion
+	///
+	/// The identity has no axis of its own, so it returns the X axis and 0
+	pub fn to_axis_angle(&self) -> ([T; 3], T) {
+		let s = (&self.x * &self.x + &self.y * &self.y + &self.z * &self.z).sqrt();
+		if s.is_zero() {
+			return ([T::one(), T::zero(), T::zero()], T::zero());
+		}
+		let angle = (T::one() + T::one()) * s.atan2(&self.w);
+		let axis = [&self.x, &self.y, &self.z].map(|e| e.clone() / s.clone());
+		(axis, angle)
+	}
+
+	/// Creates a new unit Quaternion for the shortest rotation taking the unit vector from to the
+	/// unit vector to
+	///
+	/// When they point in opposite directions any axis perpendicular to from is used
+	pub fn from_rotation_arc(from: Vector<T, 3>, to: Vector<T, 3>) -> Self {
+		let (a, b) = (from.to_array(), to.to_array());
+		let w = T::one()
+			+ a[0].clone() * b[0].clone()
+			+ a[1].clone() * b[1].clone()
+			+ a[2].clone() * b[2].clone();
+		let c = cross(&a, &b);
+		let c2 =
+			c[0].clone() * c[0].clone() + c[1].clone() * c[1].clone() + c[2].clone() * c[2].clone();
+		// For unit vectors |c|^2 = (1 - a.b) w, which is at least 2w past a right angle. Far below
+		// that, w is only the rounding of 1 + a.b and the vectors are opposite
+		if w < T::one() && (c2.clone() + c2) <= w {
+			// Rotate by π about the cross product with the axis least aligned with from
+			let mut least = 0;
+			for i in 1..3 {
+				if a[i].clone().abs() < a[least].clone().abs() {
+					least = i;
+				}
+			}
+			let mut basis = [T::zero(), T::zero(), T::zero()];
+			basis[least] = T::one();
+			let [x, y, z] = normalise(cross(&a, &basis));
+			return Self {
+				x,
+				y,
+				z,
+				w: T::zero(),
+			};
+		}
+		let [x, y, z] = c;
+		Self { x, y, z, w }.normalise()
+	}
+
+	/// Creates a new unit Quaternion that turns +Z to forward and +Y as close to up as it can
+	///
+	/// forward and up must not be parallel
+	pub fn look_rotation(forward: Vector<T, 3>, up: Vector<T, 3>) -> Self {
+		let z = normalise(forward.to_array());
+		let x = normalise(cross(&up.to_array(), &z));
+		let y = cross(&z, &x);
+		Self::from_basis([x, y, z])
+	}
+
+	/// Returns the angle in [0, π] of the rotation taking one unit Quaternion to the other
+	pub fn angle_between(&self, other: &Self) -> T {
+		let d = self.dot(other).abs().min(T::one());
+		(T::one() + T::one()) * d.acos()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use glam::{Quat, Vec3};
+	use orml_vector::Vector;
+	use proptest::prelude::*;
+
+	use crate::Quaternion;
+
+	fn close(a: [f32; 3], b: Vec3) -> bool {
+		(a[0] - b.x).abs() < 1e-5 && (a[1] - b.y).abs() < 1e-5 && (a[2] - b.z).abs() < 1e-5
+	}
+
+	#[test]
+	fn axis_angle() {
+		let axis = Vec3::new(1.0, -2.0, 0.5).normalize();
+		for angle in [0.0, 0.4, -1.3, 2.9, 4.0] {
+			let q = Quaternion::from_axis_angle(axis.to_array(), angle);
+			let expected = Quat::from_axis_angle(axis, angle);
+			assert_eq!(q.to_array(), expected.to_array());
+
+			let (a, angle2) = q.to_axis_angle();
+			let (ga, gangle) = expected.to_axis_angle();
+			assert!((angle2 - gangle).abs() < 1e-5);
+			if angle != 0.0 {
+				assert!(close(a, ga));
+			}
+		}
+		assert_eq!(
+			Quaternion::<f32>::identity().to_axis_angle(),
+			([1.0, 0.0, 0.0], 0.0)
+		);
+	}
+
+	#[test]
+	fn rotation_arc() {
+		let pairs = [
+			(Vec3::X, Vec3::Y),
+			(Vec3::new(1.0, 2.0, 3.0), Vec3::new(-3.0, 0.5, 1.0)),
+			(Vec3::Z, Vec3::Z),
+			(Vec3::new(1.0, 1.0, 0.0), Vec3::new(-1.0, -1.0, 0.0)),
+			(Vec3::Y, -Vec3::Y),
+		];
+		for (from, to) in pairs {
+			let (from, to) = (from.normalize(), to.normalize());
+			let q = Quaternion::<f32>::from_rotation_arc(
+				Vector::from_array(from.to_array()),
+				Vector::from_array(to.to_array()),
+			);
+			assert!((q.length() - 1.0).abs() < 1e-6);
+			let rotated = q * Vector::from_array(from.to_array());
+			assert!(close(rotated.to_array(), to), "{from} {to}");
+		}
+	}
+
+	#[test]
+	fn look_rotation() {
+		let forward = Vec3::new(1.0, 0.5, -2.0);
+		let up = Vec3::new(0.2, 1.0, 0.1);
+		let q = Quaternion::<f32>::look_rotation(
+			Vector::from_array(forward.to_array()),
+			Vector::from_array(up.to_array()),
+		);
+		let z = q * Vector::from_array([0.0, 0.0, 1.0]);
+		assert!(close(z.to_array(), forward.normalize()));
+
+		// +Y goes to up with its forward part removed
+		let y = q * Vector::from_array([0.0, 1.0, 0.0]);
+		let f = forward.normalize();
+		let expected = (up - f * up.dot(f)).normalize();
+		assert!(close(y.to_array(), expected));
+
+		assert_eq!(
+			Quaternion::<f32>::look_rotation(
+				Vector::from_array([0.0, 0.0, 1.0]),
+				Vector::from_array([0.0, 1.0, 0.0]),
+			),
+			Quaternion::identity()
+		);
+	}
+
+	#[test]
+	fn angle_between() {
+		let a = Quat::from_axis_angle(Vec3::X, 0.3);
+		let b = Quat::from_axis_angle(Vec3::new(0.0, 0.6, 0.8), -2.0);
+		let (qa, qb): (Quaternion<f32>, Quaternion<f32>) =
+			(a.to_array().into(), b.to_array().into());
+		assert!((qa.angle_between(&qb) - a.angle_between(b)).abs() < 1e-5);
+		assert_eq!(qa.angle_between(&qa), 0.0);
+		// q and -q are the same rotation
+		assert!(qa.angle_between(&-qa) < 1e-3);
+	}
+
+	proptest! {
+		#[test]
+		fn inverse_is_identity(x in -10.0..10.0f64, y in -10.0..10.0f64, z in -10.0..10.0f64, w in -10.0..10.0f64) {
+			let q = Quaternion { x, y, z, w };
+			prop_assume!(q.length() > 1e-3);
+			let i = q * q.inverse();
+			let id = Quaternion::identity();
+			prop_assert!((i - id).length() < 1e-12);
+		}
+
+		#[test]
+		fn unit_inverse_is_conjugate(x in -1.0..1.0f64, y in -1.0..1.0f64, z in -1.0..1.0f64, angle in -6.0..6.0f64) {
+			let len = (x * x + y * y + z * z).sqrt();
+			prop_assume!(len > 1e-2);
+			let axis = [x / len, y / len, z / len];
+			let q = Quaternion::from_axis_angle(axis, angle);
+			prop_assert!((q.inverse() - q.conjugate()).length() < 1e-12);
+			prop_assert!((q * q.conjugate() - Quaternion::identity()).length() < 1e-12);
+
+			let (axis2, angle2) = q.to_axis_angle();
+			let q2 = Quaternion::from_axis_angle(axis2, angle2);
+			prop_assert!((q2 - q).length() < 1e-12);
+		}
+	}
+}
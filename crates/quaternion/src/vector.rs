@@ -12,7 +12,7 @@ where
 {
 	#[inline]
 	fn from(value: Vector<T, 4>) -> Self {
-		value.into()
+		value.to_array().into()
 	}
 }
 
@@ -49,6 +49,14 @@ where
 mod tests {
 	use crate::{vector::Vector, Quaternion};
 
+	#[test]
+	fn from_vec4() {
+		let v = Vector::from_array([1.0, 2.0, 3.0, 4.0]);
+		let q: Quaternion<f64> = v.into();
+		assert_eq!(q, Quaternion::from_array([1.0, 2.0, 3.0, 4.0]));
+		assert_eq!(Vector::from(q), v);
+	}
+
 	#[test]
 	fn mul_vec3_id() {
 		let q_arr = [0.0, 0.0, 0.0, 1.0];
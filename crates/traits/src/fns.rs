@@ -10,6 +10,10 @@ pub mod abs;
 
 pub mod pow;
 
+pub mod integer;
+
+pub mod special;
+
 pub trait CubeRoot {
 	fn cbrt(self) -> Self;
 }
@@ -5,5 +5,5 @@ pub trait Abs {
 }
 
 pub trait AbsSub {
-	fn abs_sub(self) -> Self;
+	fn abs_sub(self, other: Self) -> Self;
 }
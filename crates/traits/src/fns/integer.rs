@@ -0,0 +1,61 @@
+pub trait IntegerFns:
+	Gcd + Lcm + ExtendedGcd + DivFloor + ModFloor + CheckedOps + WrappingOps + ISqrt + IsPowerOfTwo
+{
+}
+
+pub trait Gcd {
+	/// The greatest common divisor, always non-negative and zero only if both are zero
+	fn gcd(self, other: Self) -> Self;
+}
+
+pub trait Lcm {
+	/// The least common multiple, always non-negative and zero if either is zero
+	///
+	/// Panics if the result doesn't fit in the type
+	fn lcm(self, other: Self) -> Self;
+}
+
+pub trait DivFloor {
+	/// Division rounding the quotient towards negative infinity
+	fn div_floor(self, rhs: Self) -> Self;
+}
+
+pub trait ModFloor {
+	/// The remainder of [`DivFloor::div_floor`], which takes the sign of rhs
+	fn mod_floor(self, rhs: Self) -> Self;
+}
+
+pub trait CheckedOps: std::marker::Sized {
+	fn checked_add(self, rhs: Self) -> Option<Self>;
+	fn checked_sub(self, rhs: Self) -> Option<Self>;
+	fn checked_mul(self, rhs: Self) -> Option<Self>;
+	fn checked_div(self, rhs: Self) -> Option<Self>;
+	fn checked_rem(self, rhs: Self) -> Option<Self>;
+	fn checked_neg(self) -> Option<Self>;
+}
+
+pub trait WrappingOps {
+	fn wrapping_add(self, rhs: Self) -> Self;
+	fn wrapping_sub(self, rhs: Self) -> Self;
+	fn wrapping_mul(self, rhs: Self) -> Self;
+	fn wrapping_div(self, rhs: Self) -> Self;
+	fn wrapping_rem(self, rhs: Self) -> Self;
+	fn wrapping_neg(self) -> Self;
+}
+
+pub trait ISqrt {
+	/// The square root rounded down, panics for negative values
+	fn isqrt(self) -> Self;
+}
+
+pub trait IsPowerOfTwo {
+	fn is_power_of_two(&self) -> bool;
+}
+
+pub trait ExtendedGcd: std::marker::Sized {
+	/// Returns (g, x, y) with g the [`Gcd::gcd`] and self * x + other * y = g
+	///
+	/// Unsigned integers can't hold a negative coefficient, so theirs wrap and the identity holds
+	/// with wrapping arithmetic
+	fn extended_gcd(self, other: Self) -> (Self, Self, Self);
+}
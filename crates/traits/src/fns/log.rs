@@ -13,9 +13,9 @@ pub trait Log {
 }
 
 pub trait Log10 {
-	fn log10(self, base: Self) -> Self;
+	fn log10(self) -> Self;
 }
 
 pub trait Log2 {
-	fn log2(self, base: Self) -> Self;
+	fn log2(self) -> Self;
 }
@@ -0,0 +1,54 @@
+pub trait SpecialFns:
+	Erf + Erfc + Gamma + LnGamma + Beta + Digamma + BesselJ0 + BesselJ1 + BesselY0 + BesselY1
+{
+}
+
+pub trait Erf {
+	/// The error function
+	fn erf(self) -> Self;
+}
+
+pub trait Erfc {
+	/// The complementary error function 1 - erf(x), without the cancellation for large x
+	fn erfc(self) -> Self;
+}
+
+pub trait Gamma {
+	/// The gamma function, NaN at the poles on the non-positive integers
+	fn gamma(self) -> Self;
+}
+
+pub trait LnGamma {
+	/// The natural logarithm of the absolute value of the gamma function
+	fn ln_gamma(self) -> Self;
+}
+
+pub trait Beta {
+	/// The beta function B(self, other) = Γ(self)Γ(other) / Γ(self + other)
+	fn beta(self, other: Self) -> Self;
+}
+
+pub trait Digamma {
+	/// The digamma function, the logarithmic derivative of the gamma function
+	fn digamma(self) -> Self;
+}
+
+pub trait BesselJ0 {
+	/// The Bessel function of the first kind of order zero
+	fn j0(self) -> Self;
+}
+
+pub trait BesselJ1 {
+	/// The Bessel function of the first kind of order one
+	fn j1(self) -> Self;
+}
+
+pub trait BesselY0 {
+	/// The Bessel function of the second kind of order zero, NaN for negative arguments
+	fn y0(self) -> Self;
+}
+
+pub trait BesselY1 {
+	/// The Bessel function of the second kind of order one, NaN for negative arguments
+	fn y1(self) -> Self;
+}
@@ -0,0 +1,406 @@
+//! This module contains the integer traits for the primitive integers
+
+use crate::fns::integer::*;
+use crate::Integer;
+
+/// Stein's binary GCD on unsigned values
+macro_rules! binary_gcd {
+	($a:expr, $b:expr) => {{
+		let (mut a, mut b) = ($a, $b);
+		if a == 0 || b == 0 {
+			a | b
+		} else {
+			let shift = (a | b).trailing_zeros();
+			a >>= a.trailing_zeros();
+			loop {
+				b >>= b.trailing_zeros();
+				if a > b {
+					std::mem::swap(&mut a, &mut b);
+				}
+				b -= a;
+				if b == 0 {
+					break a << shift;
+				}
+			}
+		}
+	}};
+}
+
+/// The extended Euclidean algorithm, returning (r, s, t) with a * s + b * t = r up to sign
+///
+/// Everything wraps, so the only quotient that can overflow, MIN / -1, doesn't panic. The
+/// results are still exact whenever they fit in the type
+macro_rules! extended_euclid {
+	($a:expr, $b:expr) => {{
+		let (mut r0, mut r1) = ($a, $b);
+		let (mut s0, mut s1) = (1, 0);
+		let (mut t0, mut t1) = (0, 1);
+		while r1 != 0 {
+			let q = r0.wrapping_div(r1);
+			(r0, r1) = (r1, r0.wrapping_sub(q.wrapping_mul(r1)));
+			(s0, s1) = (s1, s0.wrapping_sub(q.wrapping_mul(s1)));
+			(t0, t1) = (t1, t0.wrapping_sub(q.wrapping_mul(t1)));
+		}
+		(r0, s0, t0)
+	}};
+}
+
+/// Implements the traits that forward straight to the inherent methods
+macro_rules! impl_int_common {
+	($tt:ty) => {
+		impl CheckedOps for $tt {
+			fn checked_add(self, rhs: Self) -> Option<Self> {
+				<$tt>::checked_add(self, rhs)
+			}
+
+			fn checked_sub(self, rhs: Self) -> Option<Self> {
+				<$tt>::checked_sub(self, rhs)
+			}
+
+			fn checked_mul(self, rhs: Self) -> Option<Self> {
+				<$tt>::checked_mul(self, rhs)
+			}
+
+			fn checked_div(self, rhs: Self) -> Option<Self> {
+				<$tt>::checked_div(self, rhs)
+			}
+
+			fn checked_rem(self, rhs: Self) -> Option<Self> {
+				<$tt>::checked_rem(self, rhs)
+			}
+
+			fn checked_neg(self) -> Option<Self> {
+				<$tt>::checked_neg(self)
+			}
+		}
+
+		impl WrappingOps for $tt {
+			fn wrapping_add(self, rhs: Self) -> Self {
+				<$tt>::wrapping_add(self, rhs)
+			}
+
+			fn wrapping_sub(self, rhs: Self) -> Self {
+				<$tt>::wrapping_sub(self, rhs)
+			}
+
+			fn wrapping_mul(self, rhs: Self) -> Self {
+				<$tt>::wrapping_mul(self, rhs)
+			}
+
+			fn wrapping_div(self, rhs: Self) -> Self {
+				<$tt>::wrapping_div(self, rhs)
+			}
+
+			fn wrapping_rem(self, rhs: Self) -> Self {
+				<$tt>::wrapping_rem(self, rhs)
+			}
+
+			fn wrapping_neg(self) -> Self {
+				<$tt>::wrapping_neg(self)
+			}
+		}
+
+		impl ISqrt for $tt {
+			fn isqrt(self) -> Self {
+				<$tt>::isqrt(self)
+			}
+		}
+
+		impl IntegerFns for $tt {}
+
+		impl Integer for $tt {}
+	};
+}
+
+// Only some of the integer features may be enabled
+#[allow(unused_macros)]
+macro_rules! impl_int_unsigned {
+	($tt:ty) => {
+		impl_int_common!($tt);
+
+		impl Gcd for $tt {
+			fn gcd(self, other: Self) -> Self {
+				binary_gcd!(self, other)
+			}
+		}
+
+		impl DivFloor for $tt {
+			fn div_floor(self, rhs: Self) -> Self {
+				self / rhs
+			}
+		}
+
+		impl ModFloor for $tt {
+			fn mod_floor(self, rhs: Self) -> Self {
+				self % rhs
+			}
+		}
+
+		impl Lcm for $tt {
+			/// Panics if the result overflows
+			fn lcm(self, other: Self) -> Self {
+				if self == 0 || other == 0 {
+					return 0;
+				}
+				(self / self.gcd(other))
+					.checked_mul(other)
+					.expect("attempt to take lcm with overflow")
+			}
+		}
+
+		impl IsPowerOfTwo for $tt {
+			fn is_power_of_two(&self) -> bool {
+				<$tt>::is_power_of_two(*self)
+			}
+		}
+
+		impl ExtendedGcd for $tt {
+			/// The coefficients wrap, so self * x + other * y = g holds with wrapping arithmetic
+			fn extended_gcd(self, other: Self) -> (Self, Self, Self) {
+				extended_euclid!(self, other)
+			}
+		}
+	};
+}
+
+#[allow(unused_macros)]
+macro_rules! impl_int_signed {
+	($tt:ty) => {
+		impl_int_common!($tt);
+
+		impl Gcd for $tt {
+			/// Panics if the result overflows, which only happens when it would be -MIN
+			fn gcd(self, other: Self) -> Self {
+				let g = binary_gcd!(self.unsigned_abs(), other.unsigned_abs());
+				<$tt>::try_from(g).expect("attempt to take gcd with overflow")
+			}
+		}
+
+		impl DivFloor for $tt {
+			fn div_floor(self, rhs: Self) -> Self {
+				let q = self / rhs;
+				if self % rhs != 0 && (self < 0) != (rhs < 0) {
+					q - 1
+				} else {
+					q
+				}
+			}
+		}
+
+		impl ModFloor for $tt {
+			fn mod_floor(self, rhs: Self) -> Self {
+				let r = self % rhs;
+				if r != 0 && (r < 0) != (rhs < 0) {
+					r + rhs
+				} else {
+					r
+				}
+			}
+		}
+
+		impl Lcm for $tt {
+			/// Panics if the result overflows, which it always does when either is MIN
+			fn lcm(self, other: Self) -> Self {
+				if self == 0 || other == 0 {
+					return 0;
+				}
+				let (a, b) = (self.unsigned_abs(), other.unsigned_abs());
+				(a / binary_gcd!(a, b))
+					.checked_mul(b)
+					.and_then(|l| <$tt>::try_from(l).ok())
+					.expect("attempt to take lcm with overflow")
+			}
+		}
+
+		impl IsPowerOfTwo for $tt {
+			fn is_power_of_two(&self) -> bool {
+				*self > 0 && *self & (*self - 1) == 0
+			}
+		}
+
+		impl ExtendedGcd for $tt {
+			/// Panics if the gcd overflows, which only happens when it would be -MIN
+			fn extended_gcd(self, other: Self) -> (Self, Self, Self) {
+				let (r, s, t) = extended_euclid!(self, other);
+				if r < 0 {
+					let g = r.checked_neg().expect("attempt to take gcd with overflow");
+					(g, s.wrapping_neg(), t.wrapping_neg())
+				} else {
+					(r, s, t)
+				}
+			}
+		}
+
+		impl crate::SignedInteger for $tt {}
+	};
+}
+
+#[cfg(feature = "impl-i8")]
+impl_int_signed!(i8);
+#[cfg(feature = "impl-i16")]
+impl_int_signed!(i16);
+#[cfg(feature = "impl-i32")]
+impl_int_signed!(i32);
+#[cfg(feature = "impl-i64")]
+impl_int_signed!(i64);
+#[cfg(feature = "impl-i128")]
+impl_int_signed!(i128);
+#[cfg(feature = "impl-isize")]
+impl_int_signed!(isize);
+
+#[cfg(feature = "impl-u8")]
+impl_int_unsigned!(u8);
+#[cfg(feature = "impl-u16")]
+impl_int_unsigned!(u16);
+#[cfg(feature = "impl-u32")]
+impl_int_unsigned!(u32);
+#[cfg(feature = "impl-u64")]
+impl_int_unsigned!(u64);
+#[cfg(feature = "impl-u128")]
+impl_int_unsigned!(u128);
+#[cfg(feature = "impl-usize")]
+impl_int_unsigned!(usize);
+
+#[cfg(all(test, feature = "impl-int"))]
+mod tests {
+	use crate::fns::integer::*;
+	use crate::{Integer, SignedInteger};
+
+	/// Reduces a 2D integer direction to its primitive form, generic over the integer type
+	fn primitive_direction<T: Integer + Copy>(x: T, y: T) -> (T, T) {
+		let g = x.gcd(y);
+		(x / g, y / g)
+	}
+
+	/// Returns the inverse of a modulo m if it exists
+	fn mod_inverse<T: SignedInteger + Copy + From<i8>>(a: T, m: T) -> Option<T> {
+		let (g, x, _) = a.extended_gcd(m);
+		(g == T::from(1)).then(|| x.mod_floor(m))
+	}
+
+	#[test]
+	fn gcd_lcm() {
+		assert_eq!(Gcd::gcd(12u32, 18), 6);
+		assert_eq!(Gcd::gcd(-12i64, 18), 6);
+		assert_eq!(Gcd::gcd(0i8, -7), 7);
+		assert_eq!(Gcd::gcd(0u128, 0), 0);
+		assert_eq!(Gcd::gcd(i32::MIN, 6), 2);
+		assert_eq!(Gcd::gcd(u64::MAX, u64::MAX - 1), 1);
+		assert_eq!(Lcm::lcm(4u16, 6), 12);
+		assert_eq!(Lcm::lcm(-4i32, 6), 12);
+		assert_eq!(Lcm::lcm(0isize, 6), 0);
+		assert_eq!(primitive_direction(-6i32, 9), (-2, 3));
+		assert_eq!(primitive_direction(8usize, 12), (2, 3));
+	}
+
+	#[test]
+	#[should_panic]
+	fn gcd_overflow() {
+		Gcd::gcd(i8::MIN, 0);
+	}
+
+	#[test]
+	fn floor_division() {
+		for a in -20i32..20 {
+			for b in (-7i32..7).filter(|&b| b != 0) {
+				let q = DivFloor::div_floor(a, b);
+				let r = ModFloor::mod_floor(a, b);
+				assert_eq!(q, (a as f64 / b as f64).floor() as i32);
+				assert_eq!(q * b + r, a);
+				assert!(r == 0 || (r < 0) == (b < 0));
+			}
+		}
+		assert_eq!(DivFloor::div_floor(7u8, 2), 3);
+		assert_eq!(ModFloor::mod_floor(7u8, 2), 1);
+	}
+
+	#[test]
+	fn extended_gcd() {
+		for a in -30i64..30 {
+			for b in -30i64..30 {
+				let (g, x, y) = a.extended_gcd(b);
+				assert_eq!(g, Gcd::gcd(a, b));
+				assert_eq!(a * x + b * y, g);
+			}
+		}
+		assert_eq!(mod_inverse(3i32, 11), Some(4));
+		assert_eq!(mod_inverse(-3i32, 11), Some(7));
+		assert_eq!(mod_inverse(4i16, 6), None);
+
+		for a in 0u8..=255 {
+			for b in [0u8, 1, 7, 12, 128, 255] {
+				let (g, x, y) = a.extended_gcd(b);
+				assert_eq!(g, Gcd::gcd(a, b));
+				assert_eq!(a.wrapping_mul(x).wrapping_add(b.wrapping_mul(y)), g);
+			}
+		}
+	}
+
+	#[test]
+	fn integer_limits() {
+		// MIN / -1 would overflow in the first step of Euclid's algorithm
+		assert_eq!(i32::MIN.extended_gcd(-1), (1, 0, -1));
+		assert_eq!((-1i32).extended_gcd(i32::MIN), (1, -1, 0));
+		for (a, b) in [
+			(i64::MIN, i64::MAX),
+			(i64::MAX, i64::MIN + 2),
+			(i64::MIN, 3),
+		] {
+			let (g, x, y) = a.extended_gcd(b);
+			assert_eq!(g, Gcd::gcd(a, b));
+			assert_eq!(a as i128 * x as i128 + b as i128 * y as i128, g as i128);
+		}
+		let (g, x, y) = u64::MAX.extended_gcd(u64::MAX - 1);
+		assert_eq!(g, 1);
+		assert_eq!(
+			u64::MAX
+				.wrapping_mul(x)
+				.wrapping_add((u64::MAX - 1).wrapping_mul(y)),
+			1
+		);
+
+		assert_eq!(Lcm::lcm(i32::MAX, -1), i32::MAX);
+		assert_eq!(Lcm::lcm(u8::MAX, 5), u8::MAX);
+		assert_eq!(Lcm::lcm(128u8, 64), 128);
+	}
+
+	#[test]
+	#[should_panic]
+	fn lcm_overflow() {
+		Lcm::lcm(i16::MAX, i16::MAX - 1);
+	}
+
+	#[test]
+	#[should_panic]
+	fn lcm_min() {
+		Lcm::lcm(i32::MIN, 1);
+	}
+
+	#[test]
+	#[should_panic]
+	fn lcm_overflow_unsigned() {
+		Lcm::lcm(u32::MAX, 2);
+	}
+
+	#[test]
+	#[should_panic]
+	fn extended_gcd_overflow() {
+		i8::MIN.extended_gcd(0);
+	}
+
+	#[test]
+	fn other_ops() {
+		assert_eq!(ISqrt::isqrt(99u32), 9);
+		assert_eq!(ISqrt::isqrt(100i64), 10);
+		assert_eq!(ISqrt::isqrt(u128::MAX), u64::MAX as u128);
+		assert!(IsPowerOfTwo::is_power_of_two(&64i32));
+		assert!(!IsPowerOfTwo::is_power_of_two(&-64i32));
+		assert!(!IsPowerOfTwo::is_power_of_two(&0u8));
+		assert!(IsPowerOfTwo::is_power_of_two(&i128::MIN.unsigned_abs()));
+		assert_eq!(CheckedOps::checked_add(i8::MAX, 1), None);
+		assert_eq!(CheckedOps::checked_neg(1u8), None);
+		assert_eq!(CheckedOps::checked_div(1u8, 0), None);
+		assert_eq!(WrappingOps::wrapping_neg(i16::MIN), i16::MIN);
+		assert_eq!(WrappingOps::wrapping_mul(200u8, 2), 144);
+	}
+}
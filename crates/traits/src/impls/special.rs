@@ -0,0 +1,594 @@
+//! This module contains pure Rust f64 implementations of the special functions
+//!
+//! The f32 implementations evaluate these in f64 and round the result
+
+use std::f64::consts::{FRAC_2_SQRT_PI, PI, SQRT_2};
+
+const EULER_GAMMA: f64 = 0.577_215_664_901_532_9;
+const LN_SQRT_2PI: f64 = 0.918_938_533_204_672_8;
+const SQRT_2PI: f64 = 2.506_628_274_631_000_7;
+
+/// Lanczos coefficients for g = 7, n = 9
+const LANCZOS_G: f64 = 7.0;
+const LANCZOS: [f64; 9] = [
+	0.999_999_999_999_809_9,
+	676.520_368_121_885_1,
+	-1_259.139_216_722_402_8,
+	771.323_428_777_653_1,
+	-176.615_029_162_140_6,
+	12.507_343_278_686_905,
+	-0.138_571_095_265_720_12,
+	9.984_369_578_019_572e-6,
+	1.505_632_735_149_311_6e-7,
+];
+
+/// Returns e^(-x^2), splitting x so the rounding error of x^2 doesn't get magnified
+#[inline]
+fn exp_neg_sq(x: f64) -> f64 {
+	let hi = f64::from_bits(x.to_bits() & 0xffff_ffff_0000_0000);
+	let lo = x - hi;
+	(-hi * hi).exp() * (-lo * (x + hi)).exp()
+}
+
+/// Returns sin(πx) with the argument reduced exactly
+fn sin_pi(x: f64) -> f64 {
+	let mut y = x % 2.0;
+	if y > 1.0 {
+		y -= 2.0;
+	} else if y < -1.0 {
+		y += 2.0;
+	}
+	if y > 0.5 {
+		y = 1.0 - y;
+	} else if y < -0.5 {
+		y = -1.0 - y;
+	}
+	(PI * y).sin()
+}
+
+/// Returns cos(πx) with the argument reduced exactly
+fn cos_pi(x: f64) -> f64 {
+	let mut y = x.abs() % 2.0;
+	if y > 1.0 {
+		y = 2.0 - y;
+	}
+	(PI * (0.5 - y)).sin()
+}
+
+#[inline]
+fn is_pole(x: f64) -> bool {
+	x <= 0.0 && x == x.floor()
+}
+
+pub(crate) fn erf(x: f64) -> f64 {
+	if x.is_nan() {
+		return x;
+	}
+	let ax = x.abs();
+	if ax >= 2.5 {
+		return (1.0 - erfc(ax)).copysign(x);
+	}
+	// erf(x) = 2/√π x e^(-x^2) Σ (2x^2)^n / (1·3·5···(2n+1)), every term is positive
+	let x2 = 2.0 * x * x;
+	let mut term = 1.0;
+	let mut sum = 1.0;
+	let mut n = 0.0;
+	while term > sum * f64::EPSILON * 0.25 {
+		n += 1.0;
+		term *= x2 / (2.0 * n + 1.0);
+		sum += term;
+	}
+	FRAC_2_SQRT_PI * x * exp_neg_sq(x) * sum
+}
+
+pub(crate) fn erfc(x: f64) -> f64 {
+	if x.is_nan() {
+		return x;
+	}
+	if x < 1.25 {
+		return if x < -1.25 {
+			2.0 - erfc(-x)
+		} else {
+			1.0 - erf(x)
+		};
+	}
+	if x > 27.3 {
+		return 0.0;
+	}
+	// Continued fraction for the upper incomplete gamma function Γ(1/2, x^2) using
+	// the modified Lentz method, erfc(x) = Γ(1/2, x^2)/√π
+	let x2 = x * x;
+	let tiny = f64::MIN_POSITIVE / f64::EPSILON;
+	let mut b = x2 + 0.5;
+	let mut c = 1.0 / tiny;
+	let mut d = 1.0 / b;
+	let mut h = d;
+	for i in 1..1000 {
+		let i = i as f64;
+		let an = -i * (i - 0.5);
+		b += 2.0;
+		d = an * d + b;
+		if d.abs() < tiny {
+			d = tiny;
+		}
+		c = b + an / c;
+		if c.abs() < tiny {
+			c = tiny;
+		}
+		d = 1.0 / d;
+		let delta = d * c;
+		h *= delta;
+		if (delta - 1.0).abs() <= f64::EPSILON {
+			break;
+		}
+	}
+	FRAC_2_SQRT_PI * 0.5 * x * exp_neg_sq(x) * h
+}
+
+/// Returns Γ(x) for x >= 0.5
+fn gamma_positive(x: f64) -> f64 {
+	if x >= 15.0 {
+		// Stirling's series, x is exact so the power and exponential are correctly rounded.
+		// Split the power so it doesn't overflow before e^-x brings it back down
+		let p = x.powf((x - 0.5) * 0.5);
+		return SQRT_2PI * p * (-x).exp() * p * stirling_correction(x).exp();
+	}
+	// The Lanczos approximation
+	let x = x - 1.0;
+	let mut a = LANCZOS[0];
+	for (i, c) in LANCZOS.iter().enumerate().skip(1) {
+		a += c / (x + i as f64);
+	}
+	let t = x + LANCZOS_G + 0.5;
+	SQRT_2PI * a * t.powf(x + 0.5) * (-t).exp()
+}
+
+pub(crate) fn gamma(x: f64) -> f64 {
+	if x.is_nan() || x == f64::INFINITY {
+		return x;
+	}
+	if x == 0.0 {
+		return 1.0 / x;
+	}
+	if is_pole(x) {
+		return f64::NAN;
+	}
+	if x > 171.7 {
+		return f64::INFINITY;
+	}
+	if x == x.floor() {
+		// Exact up to 23! and within a few ulps after that
+		let mut acc = 1.0;
+		let mut i = 2.0;
+		while i < x {
+			acc *= i;
+			i += 1.0;
+		}
+		return acc;
+	}
+	if x < 0.5 {
+		// Reflection, Γ(x)Γ(1-x) = π / sin(πx)
+		if x < -190.0 {
+			return 0.0 * sin_pi(x).signum();
+		}
+		return PI / (sin_pi(x) * gamma_positive(1.0 - x));
+	}
+	gamma_positive(x)
+}
+
+pub(crate) fn ln_gamma(x: f64) -> f64 {
+	if x.is_nan() {
+		return x;
+	}
+	if x.is_infinite() || is_pole(x) {
+		return f64::INFINITY;
+	}
+	if x < 0.5 {
+		return PI.ln() - sin_pi(x).abs().ln() - ln_gamma(1.0 - x);
+	}
+	if x < 15.0 {
+		return gamma(x).abs().ln();
+	}
+	(x - 0.5) * x.ln() - x + LN_SQRT_2PI + stirling_correction(x)
+}
+
+/// The asymptotic series of ln Γ(x) - ((x - 1/2) ln x - x + ln √(2π)) for x >= 15
+fn stirling_correction(x: f64) -> f64 {
+	// B_2k / (2k (2k - 1)) for k = 1..=6
+	const C: [f64; 6] = [
+		1.0 / 12.0,
+		-1.0 / 360.0,
+		1.0 / 1260.0,
+		-1.0 / 1680.0,
+		1.0 / 1188.0,
+		-691.0 / 360_360.0,
+	];
+	let r = 1.0 / x;
+	let r2 = r * r;
+	C.iter().rev().fold(0.0, |acc, c| acc * r2 + c) * r
+}
+
+pub(crate) fn beta(a: f64, b: f64) -> f64 {
+	if a.is_nan() || b.is_nan() {
+		return f64::NAN;
+	}
+	if is_pole(a) || is_pole(b) {
+		return f64::NAN;
+	}
+	if is_pole(a + b) {
+		return 0.0;
+	}
+	if a <= 0.0 || b <= 0.0 {
+		return gamma(a) * gamma(b) / gamma(a + b);
+	}
+	let (a, b) = if a < b { (b, a) } else { (a, b) };
+	if a + b < 171.0 {
+		return gamma(a) / gamma(a + b) * gamma(b);
+	}
+	// a is large so ln Γ(a) - ln Γ(a + b) can be expanded without the cancellation,
+	// (a - 1/2) ln a - (a + b - 1/2) ln(a + b) = -(a - 1/2) ln(1 + b/a) - b ln(a + b)
+	let diff = -(a - 0.5) * (b / a).ln_1p() - b * (a + b).ln() + b + stirling_correction(a)
+		- stirling_correction(a + b);
+	if b < 15.0 {
+		gamma(b) * diff.exp()
+	} else {
+		(ln_gamma(b) + diff).exp()
+	}
+}
+
+pub(crate) fn digamma(x: f64) -> f64 {
+	if x.is_nan() || x == f64::INFINITY {
+		return x;
+	}
+	if x == f64::NEG_INFINITY || is_pole(x) {
+		return f64::NAN;
+	}
+	if x < 0.0 {
+		// Reflection, ψ(1 - x) - ψ(x) = π cot(πx)
+		return digamma(1.0 - x) - PI * cos_pi(x) / sin_pi(x);
+	}
+	// Use the recurrence ψ(x + 1) = ψ(x) + 1/x to move x into range of the asymptotic series
+	let mut x = x;
+	let mut acc = 0.0;
+	while x < 10.0 {
+		acc -= 1.0 / x;
+		x += 1.0;
+	}
+	// B_2k / 2k for k = 1..=7
+	const C: [f64; 7] = [
+		1.0 / 12.0,
+		-1.0 / 120.0,
+		1.0 / 252.0,
+		-1.0 / 240.0,
+		1.0 / 132.0,
+		-691.0 / 32_760.0,
+		1.0 / 12.0,
+	];
+	let r2 = 1.0 / (x * x);
+	let series = C.iter().rev().fold(0.0, |acc, c| acc * r2 + c) * r2;
+	acc + x.ln() - 0.5 / x - series
+}
+
+/// The results of evaluating J0, J1, Y0 and Y1 at the same point
+struct Bessel {
+	j0: f64,
+	j1: f64,
+	y0: f64,
+	y1: f64,
+}
+
+/// Evaluates the Bessel functions for 0 < x < 25 with Miller's backward recurrence
+///
+/// J0 and J1 are normalised with J0 + 2 Σ J2k = 1, and the same sequence gives the
+/// Neumann series Y0 = 2/π ((ln(x/2) + γ) J0 - 2 Σ (-1)^k J2k / k) and its derivative
+fn bessel_miller(x: f64) -> Bessel {
+	const BIG: f64 = 1e250;
+	let m = 2 * ((x as usize + 40) / 2);
+	let mut j_next = 0.0;
+	let mut j = 1e-30;
+	let mut sum_even = 0.0;
+	let mut sum_y0 = 0.0;
+	let mut sum_y1 = 0.0;
+	for k in (1..=m).rev() {
+		// j is J_k and j_next is J_(k+1)
+		let half = k / 2;
+		let sign = |n: usize| if n & 1 == 0 { 1.0 } else { -1.0 };
+		if k & 1 == 0 {
+			sum_even += j;
+			sum_y0 += sign(half) * j / half as f64;
+		} else {
+			// J_k is J_(2n-1) for n = half + 1 and J_(2n+1) for n = half
+			sum_y1 += sign(half + 1) * j / (half + 1) as f64;
+			if half > 0 {
+				sum_y1 -= sign(half) * j / half as f64;
+			}
+		}
+		let j_prev = 2.0 * k as f64 / x * j - j_next;
+		j_next = j;
+		j = j_prev;
+		if j.abs() > BIG {
+			j /= BIG;
+			j_next /= BIG;
+			sum_even /= BIG;
+			sum_y0 /= BIG;
+			sum_y1 /= BIG;
+		}
+	}
+	let norm = j + 2.0 * sum_even;
+	let j0 = j / norm;
+	let j1 = j_next / norm;
+	let l = (0.5 * x).ln() + EULER_GAMMA;
+	Bessel {
+		j0,
+		j1,
+		y0: 2.0 / PI * (l * j0 - 2.0 * sum_y0 / norm),
+		y1: 2.0 / PI * (l * j1 - j0 / x + sum_y1 / norm),
+	}
+}
+
+/// Returns the Hankel asymptotic series (P, Q) of order nu for large x
+fn hankel_pq(nu: f64, x: f64) -> (f64, f64) {
+	let mu = 4.0 * nu * nu;
+	let mut p = 1.0;
+	let mut q = 0.0;
+	let mut term = 1.0;
+	let mut last = f64::INFINITY;
+	for k in 1..100 {
+		let odd = (2 * k - 1) as f64;
+		term *= (mu - odd * odd) / (k as f64 * 8.0 * x);
+		// The series is asymptotic, stop at the smallest term
+		if term.abs() >= last || term.abs() < f64::EPSILON * 1e-3 {
+			break;
+		}
+		last = term.abs();
+		let t = if (k / 2) % 2 == 0 { term } else { -term };
+		if k & 1 == 0 {
+			p += t;
+		} else {
+			q += t;
+		}
+	}
+	(p, q)
+}
+
+fn bessel_asymptotic(x: f64) -> Bessel {
+	let scale = (2.0 / (PI * x)).sqrt() / SQRT_2;
+	let (s, c) = x.sin_cos();
+	let (p0, q0) = hankel_pq(0.0, x);
+	let (p1, q1) = hankel_pq(1.0, x);
+	// For order 0, ω = x - π/4 so √2 cos ω = c + s and √2 sin ω = s - c
+	// For order 1, ω = x - 3π/4 so √2 cos ω = s - c and √2 sin ω = -(s + c)
+	Bessel {
+		j0: scale * (p0 * (c + s) - q0 * (s - c)),
+		y0: scale * (p0 * (s - c) + q0 * (c + s)),
+		j1: scale * (p1 * (s - c) + q1 * (s + c)),
+		y1: scale * (-p1 * (s + c) + q1 * (s - c)),
+	}
+}
+
+fn bessel(x: f64) -> Bessel {
+	if x < 25.0 {
+		bessel_miller(x)
+	} else {
+		bessel_asymptotic(x)
+	}
+}
+
+pub(crate) fn j0(x: f64) -> f64 {
+	let x = x.abs();
+	if x.is_nan() {
+		return x;
+	}
+	if x.is_infinite() {
+		return 0.0;
+	}
+	if x == 0.0 {
+		return 1.0;
+	}
+	bessel(x).j0
+}
+
+pub(crate) fn j1(x: f64) -> f64 {
+	if x.is_nan() {
+		return x;
+	}
+	if x.is_infinite() {
+		return 0.0;
+	}
+	if x == 0.0 {
+		return x;
+	}
+	let j1 = bessel(x.abs()).j1;
+	if x < 0.0 {
+		-j1
+	} else {
+		j1
+	}
+}
+
+pub(crate) fn y0(x: f64) -> f64 {
+	if x.is_nan() || x < 0.0 {
+		return f64::NAN;
+	}
+	if x.is_infinite() {
+		return 0.0;
+	}
+	if x == 0.0 {
+		return f64::NEG_INFINITY;
+	}
+	bessel(x).y0
+}
+
+pub(crate) fn y1(x: f64) -> f64 {
+	if x.is_nan() || x < 0.0 {
+		return f64::NAN;
+	}
+	if x.is_infinite() {
+		return 0.0;
+	}
+	if x == 0.0 {
+		return f64::NEG_INFINITY;
+	}
+	bessel(x).y1
+}
+
+#[cfg(test)]
+mod tests {
+	#![allow(clippy::excessive_precision)]
+
+	use super::*;
+
+	fn assert_close(a: f64, b: f64, tol: f64) {
+		assert!(
+			(a - b).abs() <= tol * b.abs().max(f64::MIN_POSITIVE),
+			"{} != {}",
+			a,
+			b
+		);
+	}
+
+	#[test]
+	fn erf_erfc() {
+		let cases = [
+			(0.1, 0.1124629160182848922),
+			(0.5, 0.52049987781304653768),
+			(1.0, 0.84270079294971486934),
+			(2.0, 0.99532226501895273416),
+			(3.0, 0.99997790950300141456),
+		];
+		for (x, e) in cases {
+			assert_close(erf(x), e, 1e-15);
+			assert_close(erf(-x), -e, 1e-15);
+		}
+		let cases = [
+			(0.5, 0.47950012218695346232),
+			(1.0, 0.15729920705028513066),
+			(2.5, 0.00040695201744495893956),
+			(3.0, 0.000022090496998585441373),
+			(5.0, 1.5374597944280348502e-12),
+			(10.0, 2.088487583762544757e-45),
+			(26.0, 5.6631924088561428465e-296),
+		];
+		for (x, e) in cases {
+			assert_close(erfc(x), e, 2e-15);
+		}
+		assert_eq!(erfc(-30.0), 2.0);
+		assert_eq!(erfc(30.0), 0.0);
+		assert_eq!(erf(f64::INFINITY), 1.0);
+		assert!(erf(f64::NAN).is_nan());
+	}
+
+	#[test]
+	fn gamma_fns() {
+		assert_eq!(gamma(5.0), 24.0);
+		assert_eq!(gamma(1.0), 1.0);
+		assert_close(gamma(0.5), PI.sqrt(), 4e-16);
+		assert_close(gamma(-1.5), 2.3632718012073547031, 4e-15);
+		assert_close(gamma(10.1), 454760.75144158595087, 1e-15);
+		assert_close(gamma(170.5), 5.5620924145599996107e305, 1e-13);
+		assert_close(gamma(1e-10), 9999999999.4227843352, 4e-16);
+		assert!(gamma(-3.0).is_nan());
+		assert_eq!(gamma(-0.0), f64::NEG_INFINITY);
+		assert_eq!(gamma(200.0), f64::INFINITY);
+
+		assert_close(ln_gamma(0.5), 0.57236494292470008707, 4e-16);
+		assert_close(ln_gamma(-2.5), -0.056243716497674050673, 1e-14);
+		assert_close(ln_gamma(100.0), 359.13420536957539878, 4e-16);
+		assert_close(ln_gamma(1e10), 220258509288.81058147, 4e-16);
+		assert_eq!(ln_gamma(1.0), 0.0);
+		assert_eq!(ln_gamma(-2.0), f64::INFINITY);
+
+		assert_close(beta(2.0, 3.0), 1.0 / 12.0, 4e-16);
+		assert_close(beta(0.5, 0.5), PI, 4e-16);
+		assert_close(beta(1e10, 1.5), 8.8622692541952450395e-16, 1e-13);
+		assert_close(beta(200.0, 300.0), 1.6485491608664745973e-147, 1e-12);
+		assert_close(beta(-0.5, 2.5), -4.7123889803846898577, 4e-15);
+		assert_eq!(beta(-0.5, -0.5), 0.0);
+
+		assert_close(digamma(1.0), -EULER_GAMMA, 2e-15);
+		assert_close(digamma(0.1), -10.423754940411076795, 1e-15);
+		assert_close(digamma(100.0), 4.6001618527380874002, 4e-16);
+		assert_close(digamma(-2.5), 1.1031566406452431872, 1e-15);
+		assert!(digamma(-2.0).is_nan());
+	}
+
+	#[test]
+	fn bessel() {
+		let cases = [
+			(
+				0.1,
+				[
+					0.99750156206604003228,
+					0.049937526036241997556,
+					-1.5342386513503668441,
+					-6.4589510947020269877,
+				],
+			),
+			(
+				1.0,
+				[
+					0.76519768655796655145,
+					0.44005058574493351596,
+					0.088256964215676957983,
+					-0.78121282130028871655,
+				],
+			),
+			(
+				7.5,
+				[
+					0.26633965788037839687,
+					0.13524842757970550518,
+					0.11731328614820863084,
+					-0.2591285104861162518,
+				],
+			),
+			(
+				24.0,
+				[
+					-0.056230274166859267015,
+					-0.15403806518312122128,
+					-0.15283402879758777874,
+					0.053059776121202168863,
+				],
+			),
+			(
+				30.0,
+				[
+					-0.086367983581040211336,
+					-0.11875106261662293652,
+					-0.11729573168666402525,
+					0.084425570661747234891,
+				],
+			),
+			(
+				1000.0,
+				[
+					0.024786686152420174561,
+					0.0047283119070895239176,
+					0.0047159179776228133998,
+					-0.024784331292351778915,
+				],
+			),
+		];
+		for (x, [ej0, ej1, ey0, ey1]) in cases {
+			assert!((j0(x) - ej0).abs() < 1e-15, "j0({})", x);
+			assert!((j1(x) - ej1).abs() < 1e-15, "j1({})", x);
+			assert!(
+				(y0(x) - ey0).abs() < 1e-15 * ey0.abs().max(1.0),
+				"y0({})",
+				x
+			);
+			assert!(
+				(y1(x) - ey1).abs() < 1e-15 * ey1.abs().max(1.0),
+				"y1({})",
+				x
+			);
+			assert_eq!(j0(-x), j0(x));
+			assert_eq!(j1(-x), -j1(x));
+		}
+		assert_eq!(j0(0.0), 1.0);
+		assert_eq!(j1(0.0), 0.0);
+		assert_eq!(y0(0.0), f64::NEG_INFINITY);
+		assert!(y1(-1.0).is_nan());
+	}
+}
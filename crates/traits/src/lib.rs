@@ -3,22 +3,23 @@ use std::ops::{
 };
 
 use fns::{
-	abs::AbsFns, exp::ExpFns, float::FloatFns, log::LogFns, pow::Pow, trig::TrigFns, Ceil, Clamp,
-	CubeRoot, Degrees, DivEuclid, Floor, Fract, Hypot, Max, Min, MulAdd, Recip, RemEuclid, Round,
-	Signed, SquareRoot, Trunc,
+	abs::AbsFns, exp::ExpFns, float::FloatFns, integer::IntegerFns, log::LogFns, pow::Pow,
+	trig::TrigFns, Ceil, Clamp, CubeRoot, Degrees, DivEuclid, Floor, Fract, Hypot, Max, Min,
+	MulAdd, Recip, RemEuclid, Round, Signed, SquareRoot, Trunc,
 };
 
+/// The arithmetic operators, each with Self as its Output
 pub trait Ops:
-	Add
+	Add<Output = Self>
 	+ AddAssign
-	+ Div
+	+ Div<Output = Self>
 	+ DivAssign
-	+ Mul
+	+ Mul<Output = Self>
 	+ MulAssign
-	+ Neg
-	+ Rem
+	+ Neg<Output = Self>
+	+ Rem<Output = Self>
 	+ RemAssign
-	+ Sub
+	+ Sub<Output = Self>
 	+ SubAssign
 	+ std::marker::Sized
 {
@@ -52,39 +53,393 @@ pub trait Float:
 {
 }
 
+pub trait Integer:
+	Add<Output = Self>
+	+ AddAssign
+	+ Div<Output = Self>
+	+ DivAssign
+	+ Mul<Output = Self>
+	+ MulAssign
+	+ Rem<Output = Self>
+	+ RemAssign
+	+ Sub<Output = Self>
+	+ SubAssign
+	+ Ord
+	+ IntegerFns
+{
+}
+
+/// An [`Integer`] that can be negated, so its Bézout coefficients from
+/// [`ExtendedGcd`](fns::integer::ExtendedGcd) don't wrap
+pub trait SignedInteger: Integer + Neg<Output = Self> {}
+
 pub mod fns;
 
 #[cfg(feature = "impls")]
 pub mod impls {
-	#[cfg(feature = "impl-f64")]
-	pub mod f64 {
-		use crate::fns::{trig::*, SquareRoot};
+	/// Implements a trait with a single `fn(self) -> Self` by forwarding to the inherent method
+	#[cfg(any(feature = "impl-f64", feature = "impl-f32"))]
+	macro_rules! impl_trivial {
+		($bound:ident,$fn:ident,$tt:ty) => {
+			impl $bound for $tt {
+				fn $fn(self) -> Self {
+					<$tt>::$fn(self)
+				}
+			}
+		};
+	}
+
+	/// Implements a trait with a single `fn(self, Self) -> Self` by forwarding to the inherent method
+	#[cfg(any(feature = "impl-f64", feature = "impl-f32"))]
+	macro_rules! impl_trivial_binary {
+		($bound:ident,$fn:ident,$tt:ty) => {
+			impl $bound for $tt {
+				fn $fn(self, other: Self) -> Self {
+					<$tt>::$fn(self, other)
+				}
+			}
+		};
+	}
+
+	/// Implements [`Float`](crate::Float) and every trait it bundles for a primitive float
+	#[cfg(any(feature = "impl-f64", feature = "impl-f32"))]
+	macro_rules! impl_float_prim {
+		($tt:ty) => {
+			use std::num::FpCategory;
+
+			use crate::fns::{
+				abs::*, exp::*, float::*, log::*, pow::*, trig::*, Ceil, Clamp, CubeRoot, Degrees,
+				DivEuclid, Floor, Fract, Hypot, Max, Min, MulAdd, Recip, RemEuclid, Round, Signed,
+				SquareRoot, Trunc,
+			};
+			use crate::{Float, Ops};
+
+			use crate::impl_trivial_trig;
+			use crate::impl_trivial_trig_all;
+
+			impl_trivial_trig_all!($tt);
+
+			impl Atan2 for $tt {
+				fn atan2(&self, other: &Self) -> Self {
+					<$tt>::atan2(*self, *other)
+				}
+			}
+
+			impl SinCos for $tt {
+				fn sin_cos(&self) -> (Self, Self) {
+					<$tt>::sin_cos(*self)
+				}
+			}
+
+			impl TrigFns for $tt {}
 
-		use crate::impl_trivial_trig;
-		use crate::impl_trivial_trig_all;
+			impl_trivial!(Ln, ln, $tt);
+			impl_trivial!(Ln1P, ln_1p, $tt);
+			impl_trivial_binary!(Log, log, $tt);
 
-		impl_trivial_trig_all!(f64);
+			impl_trivial!(Log10, log10, $tt);
+			impl_trivial!(Log2, log2, $tt);
 
-		impl SquareRoot for f64 {
-			fn sqrt(&self) -> Self {
-				f64::sqrt(*self)
+			impl LogFns for $tt {}
+
+			impl Infinite for $tt {
+				fn is_finite(&self) -> bool {
+					<$tt>::is_finite(*self)
+				}
+
+				fn is_infinite(&self) -> bool {
+					<$tt>::is_infinite(*self)
+				}
+			}
+
+			impl Nan for $tt {
+				fn is_nan(&self) -> bool {
+					<$tt>::is_nan(*self)
+				}
+			}
+
+			impl Normal for $tt {
+				fn is_normal(&self) -> bool {
+					<$tt>::is_normal(*self)
+				}
+
+				fn is_subnormal(&self) -> bool {
+					<$tt>::is_subnormal(*self)
+				}
 			}
-		}
+
+			impl Classify for $tt {
+				fn classify(self) -> FpCategory {
+					<$tt>::classify(self)
+				}
+			}
+
+			impl FloatFns for $tt {}
+
+			impl_trivial!(Exp, exp, $tt);
+			impl_trivial!(Exp2, exp2, $tt);
+			impl_trivial!(ExpM1, exp_m1, $tt);
+
+			impl ExpFns for $tt {}
+
+			impl_trivial!(Abs, abs, $tt);
+
+			impl AbsSub for $tt {
+				/// The positive difference max(self - other, 0)
+				fn abs_sub(self, other: Self) -> Self {
+					if self <= other {
+						0.0
+					} else {
+						self - other
+					}
+				}
+			}
+
+			impl AbsFns for $tt {}
+
+			/// Raises x to the power n, squaring first while n is too large for the inherent powi
+			fn powu(x: $tt, n: usize) -> $tt {
+				match i32::try_from(n) {
+					Ok(n) => x.powi(n),
+					Err(_) => {
+						let half = powu(x * x, n / 2);
+						if n % 2 == 0 {
+							half
+						} else {
+							half * x
+						}
+					}
+				}
+			}
+
+			impl Pow for $tt {
+				fn pow(&self, n: &Self) -> Self {
+					<$tt>::powf(*self, *n)
+				}
+
+				fn powi(&self, n: usize) -> Self {
+					powu(*self, n)
+				}
+			}
+
+			impl Powi for $tt {
+				fn powi(self, n: usize) -> Self {
+					powu(self, n)
+				}
+			}
+
+			impl Signed for $tt {
+				fn is_sign_positive(&self) -> bool {
+					<$tt>::is_sign_positive(*self)
+				}
+
+				fn is_sign_negative(&self) -> bool {
+					<$tt>::is_sign_negative(*self)
+				}
+
+				fn signum(&self) -> Self {
+					<$tt>::signum(*self)
+				}
+
+				fn copysign(&self, sign: &Self) -> Self {
+					<$tt>::copysign(*self, *sign)
+				}
+			}
+
+			impl Clamp for $tt {
+				fn clamp(self, min: Self, max: Self) -> Self {
+					<$tt>::clamp(self, min, max)
+				}
+			}
+
+			impl MulAdd for $tt {
+				fn mul_add(self, a: Self, b: Self) -> Self {
+					<$tt>::mul_add(self, a, b)
+				}
+			}
+
+			impl Degrees for $tt {
+				fn to_degrees(self) -> Self {
+					<$tt>::to_degrees(self)
+				}
+
+				fn to_radians(self) -> Self {
+					<$tt>::to_radians(self)
+				}
+			}
+
+			impl SquareRoot for $tt {
+				fn sqrt(&self) -> Self {
+					<$tt>::sqrt(*self)
+				}
+			}
+
+			impl_trivial!(CubeRoot, cbrt, $tt);
+			impl_trivial!(Ceil, ceil, $tt);
+			impl_trivial!(Floor, floor, $tt);
+			impl_trivial!(Fract, fract, $tt);
+			impl_trivial!(Recip, recip, $tt);
+			impl_trivial!(Round, round, $tt);
+			impl_trivial!(Trunc, trunc, $tt);
+			impl_trivial_binary!(DivEuclid, div_euclid, $tt);
+			impl_trivial_binary!(Hypot, hypot, $tt);
+			impl_trivial_binary!(Max, max, $tt);
+			impl_trivial_binary!(Min, min, $tt);
+			impl_trivial_binary!(RemEuclid, rem_euclid, $tt);
+
+			impl Ops for $tt {}
+
+			impl Float for $tt {}
+		};
+	}
+
+	/// Implements [`SpecialFns`](crate::fns::special::SpecialFns) for a primitive float by
+	/// evaluating in f64
+	#[cfg(any(feature = "impl-f64", feature = "impl-f32"))]
+	macro_rules! impl_special_prim {
+		($tt:ty) => {
+			use crate::fns::special::*;
+
+			use super::special;
+
+			macro_rules! impl_special {
+				($bound:ident,$fn:ident) => {
+					impl $bound for $tt {
+						fn $fn(self) -> Self {
+							special::$fn(self as f64) as $tt
+						}
+					}
+				};
+			}
+
+			impl_special!(Erf, erf);
+			impl_special!(Erfc, erfc);
+			impl_special!(Gamma, gamma);
+			impl_special!(LnGamma, ln_gamma);
+			impl_special!(Digamma, digamma);
+			impl_special!(BesselJ0, j0);
+			impl_special!(BesselJ1, j1);
+			impl_special!(BesselY0, y0);
+			impl_special!(BesselY1, y1);
+
+			impl Beta for $tt {
+				fn beta(self, other: Self) -> Self {
+					special::beta(self as f64, other as f64) as $tt
+				}
+			}
+
+			impl SpecialFns for $tt {}
+		};
+	}
+
+	#[cfg(any(feature = "impl-f64", feature = "impl-f32"))]
+	mod special;
+
+	#[cfg(any(
+		feature = "impl-i8",
+		feature = "impl-i16",
+		feature = "impl-i32",
+		feature = "impl-i64",
+		feature = "impl-i128",
+		feature = "impl-isize",
+		feature = "impl-u8",
+		feature = "impl-u16",
+		feature = "impl-u32",
+		feature = "impl-u64",
+		feature = "impl-u128",
+		feature = "impl-usize"
+	))]
+	mod integer;
+
+	#[cfg(feature = "impl-f64")]
+	pub mod f64 {
+		impl_float_prim!(f64);
+		impl_special_prim!(f64);
 	}
 
 	#[cfg(feature = "impl-f32")]
 	pub mod f32 {
-		use crate::fns::{trig::*, SquareRoot};
+		impl_float_prim!(f32);
+		impl_special_prim!(f32);
+	}
+}
 
-		use crate::impl_trivial_trig;
-		use crate::impl_trivial_trig_all;
+#[cfg(all(test, feature = "impl-float"))]
+mod tests {
+	use std::num::FpCategory;
 
-		impl_trivial_trig_all!(f32);
+	use crate::fns::{
+		abs::{Abs, AbsSub},
+		float::Classify,
+		log::{Log10, Log2},
+		pow::{Pow, Powi},
+		special::*,
+		Hypot, Signed,
+	};
+	use crate::Float;
 
-		impl SquareRoot for f32 {
-			fn sqrt(&self) -> Self {
-				f32::sqrt(*self)
-			}
-		}
+	fn to_polar<T: Float + Copy>(x: T, y: T) -> (T, T) {
+		(x.hypot(y), y.atan2(&x))
+	}
+
+	fn from_polar<T: Float + Copy>(r: T, theta: T) -> (T, T) {
+		let (s, c) = theta.sin_cos();
+		(r * c, r * s)
+	}
+
+	fn classify<T: Float>(x: T) -> FpCategory {
+		Classify::classify(x)
+	}
+
+	#[test]
+	fn generic_float() {
+		let (r, theta) = to_polar(3.0f64, -4.0);
+		assert_eq!(r, 5.0);
+		let (x, y) = from_polar(r, theta);
+		assert!(Abs::abs(x - 3.0) < 1e-12 && Abs::abs(y + 4.0) < 1e-12);
+
+		let (r, theta) = to_polar(3.0f32, -4.0);
+		assert_eq!(r, 5.0);
+		let (x, y) = from_polar(r, theta);
+		assert!(Abs::abs(x - 3.0) < 1e-5 && Abs::abs(y + 4.0) < 1e-5);
+
+		assert_eq!(classify(0.0f64), FpCategory::Zero);
+		assert_eq!(classify(f32::NAN), FpCategory::Nan);
+		assert_eq!(Signed::copysign(&2.0f64, &-0.0), -2.0);
+		assert_eq!(Hypot::hypot(5.0f32, 12.0), 13.0);
+	}
+
+	#[test]
+	fn fixed_signatures() {
+		assert_eq!(Log10::log10(1000.0f64), 3.0);
+		assert_eq!(Log2::log2(8.0f32), 3.0);
+		assert_eq!(AbsSub::abs_sub(5.0f64, 3.0), 2.0);
+		assert_eq!(AbsSub::abs_sub(3.0f32, 5.0), 0.0);
+		assert!(AbsSub::abs_sub(f64::NAN, 1.0).is_nan());
+	}
+
+	#[test]
+	fn large_powers() {
+		// Past i32::MAX the exponent must not wrap to a negative one
+		let n = i32::MAX as usize + 2;
+		assert_eq!(Pow::powi(&-1.0f64, n), -1.0);
+		assert_eq!(Pow::powi(&-1.0f32, n + 1), 1.0);
+		assert_eq!(Powi::powi(2.0f64, n), f64::INFINITY);
+		assert_eq!(Powi::powi(0.5f32, n), 0.0);
+		assert_eq!(Pow::powi(&1.5f64, 10), 1.5f64.powi(10));
+	}
+
+	fn special<T: SpecialFns + Copy>(x: T, y: T) -> [T; 2] {
+		[x.erf(), y.beta(x)]
+	}
+
+	#[test]
+	fn special_fns() {
+		let [erf, beta] = special(0.5f32, 2.0);
+		assert!(Abs::abs(erf - 0.520_499_9) < 1e-6);
+		assert!(Abs::abs(beta - 4.0 / 3.0) < 1e-6);
+		assert_eq!(Gamma::gamma(5.0f32), 24.0);
+		assert!(Abs::abs(BesselJ0::j0(1.0f32) - 0.765_197_7) < 1e-6);
+		assert!(BesselY1::y1(-1.0f32).is_nan());
 	}
 }
@@ -0,0 +1,507 @@
+//! This module contains a heap backed Vector (DVector<T>) for sizes only known at runtime
+//!
+//! It has the same operators as [`Vector`], but as the dimensions of two DVectors can differ
+//! the binary operators between them return a [`DimensionError`] instead of panicking. The
+//! assignment operators only take scalars as they can't return the error
+
+use std::fmt::Display;
+use std::ops::{
+	Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Not, Sub, SubAssign,
+};
+
+use num_traits::identities::One;
+use orml_traits::fns::SquareRoot;
+
+use super::Vector;
+
+/// The error returned when the dimensions of two operands don't match
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct DimensionError {
+	/// The dimension that was needed
+	pub expected: usize,
+	/// The dimension that was given
+	pub found: usize,
+}
+
+impl DimensionError {
+	/// Returns Ok if the dimensions match
+	#[inline]
+	pub fn check(expected: usize, found: usize) -> Result<(), Self> {
+		if expected == found {
+			Ok(())
+		} else {
+			Err(Self { expected, found })
+		}
+	}
+}
+
+impl Display for DimensionError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(
+			f,
+			"expected dimension {} but found {}",
+			self.expected, self.found
+		)
+	}
+}
+
+impl std::error::Error for DimensionError {}
+
+/// A Mathimatical Vector with its number of dimensions chosen at runtime
+#[derive(Debug, Clone, Default)]
+pub struct DVector<T> {
+	vals: Vec<T>,
+}
+
+impl<T> DVector<T> {
+	/// Creates a new DVector<T> from a Vec<T>
+	#[inline]
+	pub fn from_vec(vec: Vec<T>) -> Self {
+		Self { vals: vec }
+	}
+
+	/// Returns the elements as a Vec<T>
+	#[inline]
+	pub fn to_vec(self) -> Vec<T> {
+		self.vals
+	}
+
+	/// Returns the elements as a slice
+	#[inline]
+	pub fn as_slice(&self) -> &[T] {
+		&self.vals
+	}
+
+	/// Returns the numer of dimensions of the DVector
+	#[inline]
+	pub fn len(&self) -> usize {
+		self.vals.len()
+	}
+
+	/// Returns true if the DVector is 0 dimensional
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.vals.is_empty()
+	}
+
+	/// Returns an iter over the elements
+	#[inline]
+	pub fn iter(&self) -> std::slice::Iter<'_, T> {
+		self.vals.iter()
+	}
+
+	/// Returns a mutable iter over the elements
+	#[inline]
+	pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+		self.vals.iter_mut()
+	}
+}
+
+impl<T> DVector<T>
+where
+	T: Default,
+{
+	/// Create a new DVector of dims dimensions with every element set to the default
+	#[inline]
+	pub fn new(dims: usize) -> Self {
+		Self {
+			vals: (0..dims).map(|_| T::default()).collect(),
+		}
+	}
+}
+
+impl<T> DVector<T>
+where
+	T: Add<Output = T> + Default,
+	for<'a> &'a T: Mul<&'a T, Output = T>,
+{
+	/// The dot product between 2 DVectors of the same dimension. Also know as the scalar product
+	#[inline]
+	pub fn dot(&self, rhs: &Self) -> Result<T, DimensionError> {
+		DimensionError::check(self.len(), rhs.len())?;
+		Ok(std::iter::zip(self.iter(), rhs.iter())
+			.fold(Default::default(), |acc: T, x| acc + (x.0 * x.1)))
+	}
+
+	/// Returns the length of the DVector squared
+	#[inline]
+	pub fn length_squared(&self) -> T {
+		self.iter()
+			.fold(Default::default(), |acc: T, x| acc + (x * x))
+	}
+}
+
+impl<T> DVector<T>
+where
+	T: Add<Output = T> + Default + SquareRoot,
+	for<'a> &'a T: Mul<&'a T, Output = T>,
+{
+	/// Returns the length of the DVector
+	#[inline]
+	pub fn length(&self) -> T {
+		self.length_squared().sqrt()
+	}
+}
+
+impl<T> DVector<T>
+where
+	T: Div<Output = T> + One,
+{
+	/// Returns the reciprocal of the DVector. ie 1/x for each element x of the DVector
+	#[inline]
+	pub fn reciprocal(self) -> Self {
+		Self {
+			vals: self.vals.into_iter().map(|x| T::one() / x).collect(),
+		}
+	}
+}
+
+impl<T> DVector<T>
+where
+	T: Add<Output = T> + Default + SquareRoot + Div<Output = T> + One,
+	for<'a> &'a T: Mul<&'a T, Output = T>,
+{
+	/// Returns the DVector normalised to length 1
+	#[inline]
+	pub fn normalise(self) -> Self {
+		let scale = T::one() / self.length();
+		self * scale
+	}
+}
+
+impl<T> DVector<T>
+where
+	T: Sub<Output = T>,
+	for<'a> &'a T: Mul<&'a T, Output = T>,
+{
+	/// The cross product between 2 DVectors of dimension 3
+	#[inline]
+	pub fn cross(&self, rhs: &Self) -> Result<Self, DimensionError> {
+		DimensionError::check(3, self.len())?;
+		DimensionError::check(3, rhs.len())?;
+		let a = self;
+		let b = rhs;
+		Ok(Self {
+			vals: vec![
+				(&a[1] * &b[2]) - (&a[2] * &b[1]),
+				(&a[2] * &b[0]) - (&a[0] * &b[2]),
+				(&a[0] * &b[1]) - (&a[1] * &b[0]),
+			],
+		})
+	}
+}
+
+impl<T> From<Vec<T>> for DVector<T> {
+	#[inline]
+	fn from(value: Vec<T>) -> Self {
+		Self { vals: value }
+	}
+}
+
+impl<T> FromIterator<T> for DVector<T> {
+	#[inline]
+	fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+		Self {
+			vals: iter.into_iter().collect(),
+		}
+	}
+}
+
+impl<T, const DIMS: usize> From<Vector<T, DIMS>> for DVector<T> {
+	#[inline]
+	fn from(value: Vector<T, DIMS>) -> Self {
+		Self {
+			vals: value.to_array().into(),
+		}
+	}
+}
+
+impl<T, const DIMS: usize> TryFrom<DVector<T>> for Vector<T, DIMS> {
+	type Error = DimensionError;
+
+	#[inline]
+	fn try_from(value: DVector<T>) -> Result<Self, Self::Error> {
+		let found = value.len();
+		value
+			.vals
+			.try_into()
+			.map(Vector::from_array)
+			.map_err(|_| DimensionError {
+				expected: DIMS,
+				found,
+			})
+	}
+}
+
+impl<T> IntoIterator for DVector<T> {
+	type Item = T;
+	type IntoIter = std::vec::IntoIter<T>;
+
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		self.vals.into_iter()
+	}
+}
+
+impl<'a, T> IntoIterator for &'a DVector<T> {
+	type Item = &'a T;
+	type IntoIter = std::slice::Iter<'a, T>;
+
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		self.vals.iter()
+	}
+}
+
+impl<'a, T> IntoIterator for &'a mut DVector<T> {
+	type Item = &'a mut T;
+	type IntoIter = std::slice::IterMut<'a, T>;
+
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		self.vals.iter_mut()
+	}
+}
+
+impl<T> Index<usize> for DVector<T> {
+	type Output = T;
+
+	#[inline]
+	fn index(&self, index: usize) -> &Self::Output {
+		&self.vals[index]
+	}
+}
+
+impl<T> IndexMut<usize> for DVector<T> {
+	#[inline]
+	fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+		&mut self.vals[index]
+	}
+}
+
+impl<T> PartialEq for DVector<T>
+where
+	T: PartialEq,
+{
+	#[inline]
+	fn eq(&self, other: &Self) -> bool {
+		self.vals == other.vals
+	}
+}
+
+impl<T> Eq for DVector<T> where T: Eq {}
+
+/// Orders DVectors by their length^2 like [`Vector`], so the dimensions can differ
+impl<T> PartialOrd for DVector<T>
+where
+	T: Add<Output = T> + Default + PartialOrd,
+	for<'a> &'a T: Mul<&'a T, Output = T>,
+{
+	#[inline]
+	fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+		self.length_squared().partial_cmp(&other.length_squared())
+	}
+}
+
+impl<T> Display for DVector<T>
+where
+	T: Display,
+{
+	#[inline]
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "[")?;
+		for e in &self.vals {
+			write!(f, " {},", e)?;
+		}
+		write!(f, " ]")
+	}
+}
+
+macro_rules! impl_op {
+	($lhs:ty,$rhs:ty,$func:ident,$bound:ident) => {
+		impl<T> $bound<$rhs> for $lhs
+		where
+			for<'a> &'a T: $bound<&'a T, Output = T>,
+		{
+			type Output = Result<DVector<T>, DimensionError>;
+
+			#[inline]
+			fn $func(self, rhs: $rhs) -> Self::Output {
+				DimensionError::check(self.len(), rhs.len())?;
+				Ok(DVector {
+					vals: std::iter::zip(self.vals.iter(), rhs.vals.iter())
+						.map(|(a, b)| $bound::$func(a, b))
+						.collect(),
+				})
+			}
+		}
+	};
+}
+
+macro_rules! impl_op_vers {
+	($tt:ty,$func:ident,$bound:ident) => {
+		impl_op!($tt, $tt, $func, $bound);
+		impl_op!($tt, &$tt, $func, $bound);
+		impl_op!(&$tt, &$tt, $func, $bound);
+		impl_op!(&$tt, $tt, $func, $bound);
+	};
+}
+
+macro_rules! impl_op_t {
+	($lhs:ty,$func:ident,$bound:ident,$func_assign:ident,$bound_assign:ident) => {
+		impl<T> $bound<T> for $lhs
+		where
+			for<'a> &'a T: $bound<&'a T, Output = T>,
+		{
+			type Output = DVector<T>;
+
+			#[inline]
+			fn $func(self, rhs: T) -> Self::Output {
+				DVector {
+					vals: self.vals.iter().map(|e| $bound::$func(e, &rhs)).collect(),
+				}
+			}
+		}
+
+		impl<T> $bound_assign<T> for $lhs
+		where
+			for<'a> &'a T: $bound<&'a T, Output = T>,
+		{
+			#[inline]
+			fn $func_assign(&mut self, rhs: T) {
+				for e in self.vals.iter_mut() {
+					*e = $bound::$func(&*e, &rhs);
+				}
+			}
+		}
+	};
+}
+
+// Add Impl
+impl_op_vers!(DVector<T>, add, Add);
+impl_op_t!(DVector<T>, add, Add, add_assign, AddAssign);
+
+// Sub Impl
+impl_op_vers!(DVector<T>, sub, Sub);
+impl_op_t!(DVector<T>, sub, Sub, sub_assign, SubAssign);
+
+// Mul Impl
+impl_op_vers!(DVector<T>, mul, Mul);
+impl_op_t!(DVector<T>, mul, Mul, mul_assign, MulAssign);
+
+// Div Impl
+impl_op_vers!(DVector<T>, div, Div);
+impl_op_t!(DVector<T>, div, Div, div_assign, DivAssign);
+
+// Neg Implmentation
+impl<T> Neg for DVector<T>
+where
+	for<'a> &'a T: Neg<Output = T>,
+{
+	type Output = Self;
+
+	#[inline]
+	fn neg(self) -> Self::Output {
+		Self {
+			vals: self.vals.iter().map(Neg::neg).collect(),
+		}
+	}
+}
+
+// Not Implmentation
+impl<T> Not for DVector<T>
+where
+	for<'a> &'a T: Not<Output = T>,
+{
+	type Output = Self;
+
+	#[inline]
+	fn not(self) -> Self::Output {
+		Self {
+			vals: self.vals.iter().map(Not::not).collect(),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	// The reference versions of the operators are tested too
+	#![allow(clippy::op_ref)]
+
+	use super::*;
+	use crate::typedefs::Vec3i32;
+
+	#[test]
+	fn new() {
+		let v: DVector<i32> = DVector::new(4);
+		assert_eq!(v, vec![0; 4].into());
+		assert_eq!(v.len(), 4);
+		assert!(DVector::<f64>::default().is_empty());
+		assert_eq!((0..3).collect::<DVector<_>>(), vec![0, 1, 2].into());
+	}
+
+	#[test]
+	fn arithmetic() {
+		let a: DVector<i32> = vec![1, 2, 3].into();
+		let b: DVector<i32> = vec![4, 5, 6].into();
+
+		assert_eq!(&a + &b, Ok(vec![5, 7, 9].into()));
+		assert_eq!(&b - &a, Ok(vec![3, 3, 3].into()));
+		assert_eq!(a.clone() * &b, Ok(vec![4, 10, 18].into()));
+		assert_eq!(&b / a.clone(), Ok(vec![4, 2, 2].into()));
+		assert_eq!(-a.clone(), vec![-1, -2, -3].into());
+		assert_eq!(a.clone() * 2, vec![2, 4, 6].into());
+
+		let mut c = a.clone();
+		c += 1;
+		c *= 3;
+		assert_eq!(c, vec![6, 9, 12].into());
+
+		assert_eq!(a.dot(&b), Ok(32));
+		assert_eq!(a.cross(&b), Ok(vec![-3, 6, -3].into()));
+	}
+
+	#[test]
+	fn mismatched() {
+		let a: DVector<i32> = vec![1, 2, 3].into();
+		let b: DVector<i32> = vec![1, 2].into();
+		let err = DimensionError {
+			expected: 3,
+			found: 2,
+		};
+
+		assert_eq!(&a + &b, Err(err));
+		assert_eq!(a.clone() * b.clone(), Err(err));
+		assert_eq!(a.dot(&b), Err(err));
+		assert_eq!(a.cross(&b), Err(err));
+		assert_eq!(err.to_string(), "expected dimension 3 but found 2");
+	}
+
+	#[test]
+	fn length() {
+		let v: DVector<f64> = vec![3.0, 4.0].into();
+		assert_eq!(v.length(), 5.0);
+		assert_eq!(
+			DVector::from(vec![0.0, 4.0]).normalise(),
+			vec![0.0, 1.0].into()
+		);
+		assert_eq!(v.reciprocal(), vec![1.0 / 3.0, 0.25].into());
+		assert!(DVector::from(vec![1.0, 1.0]) < DVector::from(vec![0.0, 0.0, 2.0]));
+	}
+
+	#[test]
+	fn conversions() {
+		let fixed: Vec3i32 = [1, 2, 3].into();
+		let dynamic = DVector::from(fixed);
+		assert_eq!(dynamic, vec![1, 2, 3].into());
+		assert_eq!(Vec3i32::try_from(dynamic.clone()), Ok(fixed));
+		assert_eq!(
+			Vector::<i32, 2>::try_from(dynamic),
+			Err(DimensionError {
+				expected: 2,
+				found: 3
+			})
+		);
+		assert_eq!(DVector::from(vec![1, 2]).to_string(), "[ 1, 2, ]");
+	}
+}
@@ -7,12 +7,12 @@ use crate::Vector;
 impl<T, const DIMS: usize> Vector<T, DIMS> {
 	/// Returns an iter from the underlying array
 	#[inline]
-	pub fn iter(&self) -> std::slice::Iter<T> {
+	pub fn iter(&self) -> std::slice::Iter<'_, T> {
 		self.vals.iter()
 	}
 	/// Returns a mutable iter from the underlying array
 	#[inline]
-	pub fn iter_mut(&mut self) -> std::slice::IterMut<T> {
+	pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
 		self.vals.iter_mut()
 	}
 }
@@ -61,6 +61,7 @@ mod tests {
 	}
 
 	#[test]
+	#[allow(clippy::manual_slice_fill)]
 	fn iter_mut() {
 		let mut vec: Vec3i32 = [0, 0, 0].into();
 		for e in &mut vec {
@@ -9,6 +9,7 @@
 use std::fmt::{Debug, Display};
 
 pub mod arithmetic;
+pub mod dynamic;
 pub mod index;
 pub mod iter;
 pub mod ordering;
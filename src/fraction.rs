@@ -1,57 +1,381 @@
 //! This module contains generic fractional numbers
+//!
+//! A [`Fraction<T>`] is always kept in its lowest terms with a positive denominator,
+//! so two fractions that represent the same rational number compare equal field by field
 
-use std::ops;
+use std::{fmt::Display, ops::Neg, str::FromStr};
 
-#[derive(Debug, PartialEq)]
-struct Fraction<T> {
+use num_traits::{CheckedNeg, Num, One, Zero};
+
+pub mod arithmetic;
+pub mod checked;
+pub mod continued;
+pub mod float;
+pub mod ordering;
+
+/// A rational number numerator/denominator
+/// Takes in a type T as the underlying integer type of the Fraction
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Fraction<T> {
 	numerator: T,
 	denominator: T,
 }
 
-impl<T> ops::Mul for Fraction<T>
+/// The errors that can occur when creating a Fraction
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum FractionError {
+	/// The denominator of the Fraction was zero
+	ZeroDenominator,
+	/// The string was not of the form "n" or "n/d"
+	Parse,
+	/// The value was NaN or infinite
+	NotFinite,
+	/// The value can't be represented with the underlying integer type
+	Overflow,
+}
+
+impl Display for FractionError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			FractionError::ZeroDenominator => write!(f, "denominator of a fraction cannot be zero"),
+			FractionError::Parse => write!(f, "invalid fraction literal"),
+			FractionError::NotFinite => write!(f, "value is not finite"),
+			FractionError::Overflow => write!(f, "value is out of range of the fraction"),
+		}
+	}
+}
+
+impl std::error::Error for FractionError {}
+
+/// Returns the greatest common divisor of a and b, always non-negative
+pub(crate) fn gcd<T>(a: T, b: T) -> T
 where
-	T: ops::Mul<Output = T>,
+	T: Clone + Num + PartialOrd + Neg<Output = T>,
 {
-	type Output = Self;
+	abs(euclid(a, b))
+}
 
-	fn mul(self, rhs: Self) -> Self::Output {
-		Self {
-			numerator: self.numerator * rhs.numerator,
-			denominator: self.denominator * rhs.denominator,
+/// Returns the greatest common divisor of a and b up to its sign, which is left to the caller
+/// so it can negate it without overflowing
+pub(crate) fn euclid<T>(a: T, b: T) -> T
+where
+	T: Clone + Num + Neg<Output = T>,
+{
+	let mut a = a;
+	let mut b = b;
+	while !b.is_zero() {
+		// MIN % -1 overflows, and any remainder by ±1 is zero anyway
+		if b == -T::one() {
+			return T::one();
+		}
+		let r = a % b.clone();
+		a = b;
+		b = r;
+	}
+	a
+}
+
+/// Divides numerator and denominator by their greatest common divisor, leaving the signs
+///
+/// The divisor keeps the sign from [`euclid`], so dividing never overflows, not even for a
+/// numerator or denominator of MIN
+fn lowest_terms<T>(numerator: T, denominator: T) -> (T, T)
+where
+	T: Clone + Num + Neg<Output = T>,
+{
+	let g = euclid(numerator.clone(), denominator.clone());
+	if g.is_zero() || g.is_one() || g == -T::one() {
+		(numerator, denominator)
+	} else {
+		(numerator / g.clone(), denominator / g)
+	}
+}
+
+/// Returns g, or one if g is zero, so it can always be divided by
+#[inline]
+pub(crate) fn nonzero<T: Num>(g: T) -> T {
+	if g.is_zero() {
+		T::one()
+	} else {
+		g
+	}
+}
+
+#[inline]
+pub(crate) fn abs<T>(a: T) -> T
+where
+	T: Num + PartialOrd + Neg<Output = T>,
+{
+	if a < T::zero() {
+		-a
+	} else {
+		a
+	}
+}
+
+/// Returns the closest p/q to n/d with p no greater than max_p and q no greater than max_q
+///
+/// Walks the continued fraction of n/d and picks between the last convergent that
+/// fits and the best semiconvergent after it
+pub(crate) fn limit_parts(n: u128, d: u128, max_p: u128, max_q: u128) -> (u128, u128) {
+	let (mut p0, mut q0, mut p1, mut q1) = (0u128, 1u128, 1u128, 0u128);
+	let (n0, d0) = (n, d);
+	let (mut n, mut d) = (n, d);
+	while d != 0 {
+		let a = n / d;
+		let p2 = a.checked_mul(p1).and_then(|x| x.checked_add(p0));
+		let q2 = a.checked_mul(q1).and_then(|x| x.checked_add(q0));
+		match (p2, q2) {
+			(Some(p2), Some(q2)) if p2 <= max_p && q2 <= max_q => {
+				(p0, q0, p1, q1) = (p1, q1, p2, q2);
+				(n, d) = (d, n - a * d);
+			}
+			_ => {
+				let tp = (max_p - p0).checked_div(p1).unwrap_or(a);
+				let tq = (max_q - q0).checked_div(q1).unwrap_or(a);
+				let t = tp.min(tq);
+				let (sp, sq) = (p0 + t * p1, q0 + t * q1);
+				// The semiconvergent is closer when it's more than half way to the next convergent
+				if 2 * t > a || (2 * t == a && closer((sp, sq), (p1, q1), (n0, d0))) {
+					return (sp, sq);
+				}
+				return (p1, q1);
+			}
 		}
 	}
+	(p1, q1)
+}
+
+/// Returns true if a is strictly closer to x than b is, false if that can't be decided without overflow
+fn closer(a: (u128, u128), b: (u128, u128), x: (u128, u128)) -> bool {
+	// |f - x| scaled by the other denominator so the two distances share a denominator
+	let dist = |f: (u128, u128), other: u128| {
+		f.0.checked_mul(x.1)?
+			.abs_diff(x.0.checked_mul(f.1)?)
+			.checked_mul(other)
+	};
+	match (dist(a, b.1), dist(b, a.1)) {
+		(Some(da), Some(db)) => da < db,
+		_ => false,
+	}
 }
 
-impl<T> ops::Mul<T> for Fraction<T>
+impl<T> Fraction<T> {
+	/// Returns a reference to the numerator
+	#[inline]
+	pub fn numerator(&self) -> &T {
+		&self.numerator
+	}
+
+	/// Returns a reference to the denominator, which is always positive
+	#[inline]
+	pub fn denominator(&self) -> &T {
+		&self.denominator
+	}
+
+	/// Returns the (numerator, denominator) pair
+	#[inline]
+	pub fn into_parts(self) -> (T, T) {
+		(self.numerator, self.denominator)
+	}
+}
+
+impl<T> Fraction<T>
 where
-	T: ops::Mul<Output = T>,
+	T: Clone + Num + PartialOrd + Neg<Output = T>,
 {
-	type Output = Self;
+	/// Creates a new Fraction n/1
+	#[inline]
+	pub fn from_integer(n: T) -> Self {
+		Self {
+			numerator: n,
+			denominator: T::one(),
+		}
+	}
 
-	fn mul(self, rhs: T) -> Self::Output {
+	/// Reduces numerator/denominator without checking the denominator is non zero
+	///
+	/// # Panics
+	/// If the reduced Fraction can't be represented, like integer arithmetic does
+	#[inline]
+	pub(crate) fn new_unchecked(numerator: T, denominator: T) -> Self {
+		let (n, d) = lowest_terms(numerator, denominator);
+		if d < T::zero() {
+			Self {
+				numerator: -n,
+				denominator: -d,
+			}
+		} else {
+			Self {
+				numerator: n,
+				denominator: d,
+			}
+		}
+	}
+
+	/// Returns true if the Fraction is a whole number
+	#[inline]
+	pub fn is_integer(&self) -> bool {
+		self.denominator.is_one()
+	}
+
+	/// Returns the absolute value of the Fraction
+	#[inline]
+	pub fn abs(&self) -> Self {
 		Self {
-			numerator: self.numerator * rhs,
-			denominator: self.denominator,
+			numerator: abs(self.numerator.clone()),
+			denominator: self.denominator.clone(),
 		}
 	}
+
+	/// Returns the largest whole number less than or equal to the Fraction
+	#[inline]
+	pub fn floor(&self) -> T {
+		let q = self.numerator.clone() / self.denominator.clone();
+		if self.numerator < T::zero() && !(q.clone() * self.denominator.clone() == self.numerator) {
+			q - T::one()
+		} else {
+			q
+		}
+	}
+
+	/// Returns the whole number part of the Fraction, rounding towards zero
+	#[inline]
+	pub fn trunc(&self) -> T {
+		self.numerator.clone() / self.denominator.clone()
+	}
 }
 
-impl<T> From<(T, T)> for Fraction<T> {
+impl<T> Fraction<T>
+where
+	T: Clone + Num + PartialOrd + Neg<Output = T> + CheckedNeg,
+{
+	/// Creates a new Fraction numerator/denominator reduced to its lowest terms
+	///
+	/// Returns [`FractionError::ZeroDenominator`] if the denominator is zero and
+	/// [`FractionError::Overflow`] if the reduced Fraction can't be represented, like
+	/// i64::MIN/-1
+	#[inline]
+	pub fn new(numerator: T, denominator: T) -> Result<Self, FractionError> {
+		if denominator.is_zero() {
+			Err(FractionError::ZeroDenominator)
+		} else {
+			Self::checked_reduce(numerator, denominator).ok_or(FractionError::Overflow)
+		}
+	}
+
+	/// Reduces numerator/denominator returning None if the sign normalisation overflows
+	#[inline]
+	pub(crate) fn checked_reduce(numerator: T, denominator: T) -> Option<Self> {
+		let (n, d) = lowest_terms(numerator, denominator);
+		if d < T::zero() {
+			Some(Self {
+				numerator: n.checked_neg()?,
+				denominator: d.checked_neg()?,
+			})
+		} else {
+			Some(Self {
+				numerator: n,
+				denominator: d,
+			})
+		}
+	}
+
+	/// Returns the reciprocal of the Fraction. ie denominator/numerator
+	///
+	/// Returns [`FractionError::ZeroDenominator`] if the Fraction is zero and
+	/// [`FractionError::Overflow`] if the numerator is MIN
+	#[inline]
+	pub fn recip(&self) -> Result<Self, FractionError> {
+		Self::new(self.denominator.clone(), self.numerator.clone())
+	}
+}
+
+impl<T> From<(T, T)> for Fraction<T>
+where
+	T: Clone + Num + PartialOrd + Neg<Output = T> + CheckedNeg,
+{
+	/// Creates a Fraction from a (numerator, denominator) pair
+	///
+	/// # Panics
+	/// Panics if the denominator is zero or the Fraction can't be represented, use
+	/// [`Fraction::new`] for a fallible version
+	#[inline]
 	fn from(value: (T, T)) -> Self {
+		match Self::new(value.0, value.1) {
+			Ok(f) => f,
+			Err(e) => panic!("{}", e),
+		}
+	}
+}
+
+impl<T> Default for Fraction<T>
+where
+	T: Zero + One,
+{
+	#[inline]
+	fn default() -> Self {
 		Self {
-			numerator: value.0,
-			denominator: value.1,
+			numerator: T::zero(),
+			denominator: T::one(),
 		}
 	}
 }
 
-struct F<T>(T);
-impl<T> From<Fraction<T>> for F<T>
+impl<T> Zero for Fraction<T>
 where
-	T: ops::Div<Output = F<T>>,
+	T: Clone + Num + PartialOrd + Neg<Output = T>,
 {
-	fn from(value: Fraction<T>) -> F<T> {
-		value.numerator / value.denominator
+	#[inline]
+	fn zero() -> Self {
+		Self::default()
+	}
+
+	#[inline]
+	fn is_zero(&self) -> bool {
+		self.numerator.is_zero()
+	}
+}
+
+impl<T> One for Fraction<T>
+where
+	T: Clone + Num + PartialOrd + Neg<Output = T>,
+{
+	#[inline]
+	fn one() -> Self {
+		Self {
+			numerator: T::one(),
+			denominator: T::one(),
+		}
+	}
+}
+
+impl<T> Display for Fraction<T>
+where
+	T: Display + One + PartialEq,
+{
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		if self.denominator.is_one() {
+			write!(f, "{}", self.numerator)
+		} else {
+			write!(f, "{}/{}", self.numerator, self.denominator)
+		}
+	}
+}
+
+impl<T> FromStr for Fraction<T>
+where
+	T: Clone + Num + PartialOrd + Neg<Output = T> + CheckedNeg + FromStr,
+{
+	type Err = FractionError;
+
+	/// Parses a Fraction of the form "n" or "n/d"
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let parse = |s: &str| s.trim().parse::<T>().map_err(|_| FractionError::Parse);
+		match s.split_once('/') {
+			Some((n, d)) => Self::new(parse(n)?, parse(d)?),
+			None => Ok(Self::from_integer(parse(s)?)),
+		}
 	}
 }
 
@@ -59,21 +383,117 @@ where
 mod tests {
 	#![allow(non_camel_case_types)]
 
+	use orml_matrix::Matrix;
+	use orml_vector::Vector;
+
 	use super::*;
 	type iFrac64 = Fraction<i64>;
 
-	//#[test]
-	fn test_mul() {
-		let num = 10_000; //3037000164;
+	#[test]
+	fn new() {
+		let f = iFrac64::new(6, -8).unwrap();
+		assert_eq!(f.into_parts(), (-3, 4));
 
-		for n in -num..num {
-			for d in -num..num {
-				let frac1: iFrac64 = (n, d).into();
-				let frac2: iFrac64 = (d, n).into();
-				let frac3 = frac1 * frac2;
+		let f = iFrac64::new(0, -8).unwrap();
+		assert_eq!(f.into_parts(), (0, 1));
 
-				assert_eq!(frac3, (n * d, n * d).into());
-			}
-		}
+		assert_eq!(iFrac64::new(1, 0), Err(FractionError::ZeroDenominator));
+	}
+
+	#[test]
+	fn new_min() {
+		assert_eq!(iFrac64::new(0, i64::MIN).unwrap().into_parts(), (0, 1));
+		assert_eq!(
+			iFrac64::new(i64::MIN, i64::MIN).unwrap().into_parts(),
+			(1, 1)
+		);
+		assert_eq!(
+			iFrac64::new(2, i64::MIN).unwrap().into_parts(),
+			(-1, 1 << 62)
+		);
+		assert_eq!(
+			iFrac64::new(i64::MIN, 2).unwrap().into_parts(),
+			(i64::MIN / 2, 1)
+		);
+		assert_eq!(iFrac64::new(i64::MIN, -1), Err(FractionError::Overflow));
+		assert_eq!(iFrac64::new(1, i64::MIN), Err(FractionError::Overflow));
+		assert_eq!(
+			iFrac64::from((i64::MIN, 1)).recip(),
+			Err(FractionError::Overflow)
+		);
+	}
+
+	#[test]
+	#[should_panic]
+	fn from_zero_denominator() {
+		let _: iFrac64 = (1, 0).into();
+	}
+
+	#[test]
+	fn display() {
+		let f: iFrac64 = (3, 4).into();
+		assert_eq!(f.to_string(), "3/4");
+
+		let f: iFrac64 = (-10, 5).into();
+		assert_eq!(f.to_string(), "-2");
+	}
+
+	#[test]
+	fn from_str() {
+		assert_eq!("3/4".parse::<iFrac64>(), Ok((3, 4).into()));
+		assert_eq!(" -6 / 8 ".parse::<iFrac64>(), Ok((-3, 4).into()));
+		assert_eq!("7".parse::<iFrac64>(), Ok((7, 1).into()));
+		assert_eq!(
+			"1/0".parse::<iFrac64>(),
+			Err(FractionError::ZeroDenominator)
+		);
+		assert_eq!("1/x".parse::<iFrac64>(), Err(FractionError::Parse));
+		assert_eq!("".parse::<iFrac64>(), Err(FractionError::Parse));
+	}
+
+	#[test]
+	fn floor_trunc() {
+		let f: iFrac64 = (-7, 2).into();
+		assert_eq!(f.floor(), -4);
+		assert_eq!(f.trunc(), -3);
+
+		let f: iFrac64 = (7, 2).into();
+		assert_eq!(f.floor(), 3);
+		assert_eq!(f.trunc(), 3);
+
+		let f: iFrac64 = (-4, 2).into();
+		assert_eq!(f.floor(), -2);
+	}
+
+	#[test]
+	fn recip() {
+		let f: iFrac64 = (-3, 4).into();
+		assert_eq!(f.recip(), Ok((-4, 3).into()));
+		assert_eq!(iFrac64::zero().recip(), Err(FractionError::ZeroDenominator));
+	}
+
+	#[test]
+	fn matrix_inverse() {
+		// The Hilbert matrix is badly conditioned but Fractions invert it exactly
+		let h: Matrix<iFrac64, 4, 4> = Matrix::from_cols(std::array::from_fn(|c| {
+			Vector::from_array(std::array::from_fn(|r| (1, (r + c + 1) as i64).into()))
+		}));
+		let expected: Matrix<iFrac64, 4, 4> = [
+			[16, -120, 240, -140],
+			[-120, 1200, -2700, 1680],
+			[240, -2700, 6480, -4200],
+			[-140, 1680, -4200, 2800],
+		]
+		.map(|row| row.map(iFrac64::from_integer))
+		.into();
+
+		let inv = h.inverse().unwrap();
+		assert_eq!(inv, expected);
+		assert_eq!(h * inv, Matrix::identity());
+		assert_eq!(h.determinant(), (1, 6048000).into());
+		assert_eq!(
+			h.solve(&[1, 0, 0, 0].map(iFrac64::from_integer).into()),
+			Some(*expected.col(0))
+		);
 	}
 }
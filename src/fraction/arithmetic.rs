@@ -0,0 +1,345 @@
+//! This module contains implementations of mathematical operations on Fractions
+
+use std::ops::{
+	Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+
+use num_traits::Num;
+
+use super::{gcd, nonzero, Fraction};
+
+impl<T> Fraction<T>
+where
+	T: Clone + Num + PartialOrd + Neg<Output = T>,
+{
+	#[inline]
+	fn add_ref(&self, rhs: &Self) -> Self {
+		let g = gcd(self.denominator.clone(), rhs.denominator.clone());
+		let l = rhs.denominator.clone() / g.clone();
+		let r = self.denominator.clone() / g;
+		Self::new_unchecked(
+			self.numerator.clone() * l.clone() + rhs.numerator.clone() * r,
+			self.denominator.clone() * l,
+		)
+	}
+
+	#[inline]
+	fn sub_ref(&self, rhs: &Self) -> Self {
+		let g = gcd(self.denominator.clone(), rhs.denominator.clone());
+		let l = rhs.denominator.clone() / g.clone();
+		let r = self.denominator.clone() / g;
+		Self::new_unchecked(
+			self.numerator.clone() * l.clone() - rhs.numerator.clone() * r,
+			self.denominator.clone() * l,
+		)
+	}
+
+	#[inline]
+	fn mul_ref(&self, rhs: &Self) -> Self {
+		// Cross reduce first to keep the intermediate products small
+		let g1 = gcd(self.numerator.clone(), rhs.denominator.clone());
+		let g2 = gcd(rhs.numerator.clone(), self.denominator.clone());
+		let (g1, g2) = (nonzero(g1), nonzero(g2));
+		Self {
+			numerator: (self.numerator.clone() / g1.clone()) * (rhs.numerator.clone() / g2.clone()),
+			denominator: (self.denominator.clone() / g2) * (rhs.denominator.clone() / g1),
+		}
+	}
+
+	#[inline]
+	fn div_ref(&self, rhs: &Self) -> Self {
+		if rhs.numerator.is_zero() {
+			panic!("attempt to divide a fraction by zero");
+		}
+		let recip = Self::new_unchecked(rhs.denominator.clone(), rhs.numerator.clone());
+		self.mul_ref(&recip)
+	}
+
+	#[inline]
+	fn rem_ref(&self, rhs: &Self) -> Self {
+		if rhs.numerator.is_zero() {
+			panic!("attempt to calculate the remainder of a fraction with a divisor of zero");
+		}
+		let g = gcd(self.denominator.clone(), rhs.denominator.clone());
+		let l = rhs.denominator.clone() / g.clone();
+		let r = self.denominator.clone() / g;
+		Self::new_unchecked(
+			(self.numerator.clone() * l.clone()) % (rhs.numerator.clone() * r),
+			self.denominator.clone() * l,
+		)
+	}
+}
+
+macro_rules! impl_op {
+	($lhs:ty,$rhs:ty,$func:ident,$bound:ident,$ref_func:ident) => {
+		impl<T> $bound<$rhs> for $lhs
+		where
+			T: Clone + Num + PartialOrd + Neg<Output = T>,
+		{
+			type Output = Fraction<T>;
+
+			#[inline]
+			fn $func(self, rhs: $rhs) -> Self::Output {
+				Fraction::$ref_func(&self, &rhs)
+			}
+		}
+	};
+}
+
+macro_rules! impl_op_vers {
+	($func:ident,$bound:ident,$ref_func:ident) => {
+		impl_op!(Fraction<T>, Fraction<T>, $func, $bound, $ref_func);
+		impl_op!(Fraction<T>, &Fraction<T>, $func, $bound, $ref_func);
+		impl_op!(&Fraction<T>, &Fraction<T>, $func, $bound, $ref_func);
+		impl_op!(&Fraction<T>, Fraction<T>, $func, $bound, $ref_func);
+	};
+}
+
+macro_rules! impl_op_assign {
+	($rhs:ty,$func:ident,$bound:ident,$ref_func:ident) => {
+		impl<T> $bound<$rhs> for Fraction<T>
+		where
+			T: Clone + Num + PartialOrd + Neg<Output = T>,
+		{
+			#[inline]
+			fn $func(&mut self, rhs: $rhs) {
+				let res = self.$ref_func(&rhs);
+				*self = res;
+			}
+		}
+	};
+}
+
+macro_rules! impl_op_assign_vers {
+	($func:ident,$bound:ident,$ref_func:ident) => {
+		impl_op_assign!(Fraction<T>, $func, $bound, $ref_func);
+		impl_op_assign!(&Fraction<T>, $func, $bound, $ref_func);
+	};
+}
+
+// Add Impl
+impl_op_vers!(add, Add, add_ref);
+impl_op_assign_vers!(add_assign, AddAssign, add_ref);
+
+// Sub Impl
+impl_op_vers!(sub, Sub, sub_ref);
+impl_op_assign_vers!(sub_assign, SubAssign, sub_ref);
+
+// Mul Impl
+impl_op_vers!(mul, Mul, mul_ref);
+impl_op_assign_vers!(mul_assign, MulAssign, mul_ref);
+
+// Div Impl
+impl_op_vers!(div, Div, div_ref);
+impl_op_assign_vers!(div_assign, DivAssign, div_ref);
+
+// Rem Impl
+impl_op_vers!(rem, Rem, rem_ref);
+impl_op_assign_vers!(rem_assign, RemAssign, rem_ref);
+
+// MulT Impl
+impl<T> Mul<T> for Fraction<T>
+where
+	T: Clone + Num + PartialOrd + Neg<Output = T>,
+{
+	type Output = Self;
+
+	#[inline]
+	fn mul(self, rhs: T) -> Self::Output {
+		self.mul_ref(&Fraction::from_integer(rhs))
+	}
+}
+
+// DivT Impl
+impl<T> Div<T> for Fraction<T>
+where
+	T: Clone + Num + PartialOrd + Neg<Output = T>,
+{
+	type Output = Self;
+
+	#[inline]
+	fn div(self, rhs: T) -> Self::Output {
+		self.div_ref(&Fraction::from_integer(rhs))
+	}
+}
+
+// Neg Implmentation
+impl<T> Neg for Fraction<T>
+where
+	T: Neg<Output = T>,
+{
+	type Output = Self;
+
+	#[inline]
+	fn neg(self) -> Self::Output {
+		Self {
+			numerator: -self.numerator,
+			denominator: self.denominator,
+		}
+	}
+}
+
+impl<T> Neg for &Fraction<T>
+where
+	T: Clone + Neg<Output = T>,
+{
+	type Output = Fraction<T>;
+
+	#[inline]
+	fn neg(self) -> Self::Output {
+		Fraction {
+			numerator: -self.numerator.clone(),
+			denominator: self.denominator.clone(),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	#![allow(non_camel_case_types)]
+
+	use crate::fraction::Fraction;
+	type iFrac64 = Fraction<i64>;
+
+	#[test]
+	#[allow(clippy::op_ref)]
+	fn add() {
+		let f1: iFrac64 = (1, 6).into();
+		let f2: iFrac64 = (1, 3).into();
+		let expected: iFrac64 = (1, 2).into();
+
+		assert_eq!(expected, f1 + f2);
+		assert_eq!(expected, &f1 + &f2);
+	}
+
+	#[test]
+	fn add_assign() {
+		let mut f1: iFrac64 = (1, 6).into();
+		f1 += iFrac64::from((-1, 6));
+
+		assert_eq!(iFrac64::from((0, 1)), f1);
+	}
+
+	#[test]
+	fn sub() {
+		let f1: iFrac64 = (1, 6).into();
+		let f2: iFrac64 = (1, 3).into();
+		let expected: iFrac64 = (-1, 6).into();
+
+		assert_eq!(expected, f1 - f2);
+	}
+
+	#[test]
+	fn sub_assign() {
+		let mut f1: iFrac64 = (3, 4).into();
+		f1 -= &iFrac64::from((1, 4));
+
+		assert_eq!(iFrac64::from((1, 2)), f1);
+	}
+
+	#[test]
+	fn mul() {
+		let f1: iFrac64 = (2, 3).into();
+		let f2: iFrac64 = (-9, 4).into();
+		let expected: iFrac64 = (-3, 2).into();
+
+		assert_eq!(expected, f1 * f2);
+	}
+
+	#[test]
+	fn mul_assign() {
+		let mut f1: iFrac64 = (2, 3).into();
+		f1 *= iFrac64::from((3, 2));
+
+		assert_eq!(iFrac64::from((1, 1)), f1);
+	}
+
+	#[test]
+	fn mul_t() {
+		let f1: iFrac64 = (2, 3).into();
+
+		assert_eq!(iFrac64::from((2, 1)), f1 * 3);
+	}
+
+	#[test]
+	fn div() {
+		let f1: iFrac64 = (2, 3).into();
+		let f2: iFrac64 = (-4, 9).into();
+		let expected: iFrac64 = (-3, 2).into();
+
+		assert_eq!(expected, f1 / f2);
+	}
+
+	#[test]
+	fn div_assign() {
+		let mut f1: iFrac64 = (2, 3).into();
+		f1 /= iFrac64::from((2, 3));
+
+		assert_eq!(iFrac64::from((1, 1)), f1);
+	}
+
+	#[test]
+	fn div_t() {
+		let f1: iFrac64 = (2, 3).into();
+
+		assert_eq!(iFrac64::from((1, 3)), f1 / 2);
+	}
+
+	#[test]
+	#[should_panic]
+	fn div_zero() {
+		let f1: iFrac64 = (2, 3).into();
+		let _ = f1 / iFrac64::from((0, 1));
+	}
+
+	#[test]
+	fn rem() {
+		let f1: iFrac64 = (7, 2).into();
+		let f2: iFrac64 = (4, 3).into();
+		let expected: iFrac64 = (5, 6).into();
+
+		assert_eq!(expected, f1 % f2);
+
+		let f1: iFrac64 = (-7, 2).into();
+		assert_eq!(-expected, f1 % f2);
+	}
+
+	#[test]
+	fn rem_assign() {
+		let mut f1: iFrac64 = (7, 2).into();
+		f1 %= iFrac64::from((1, 1));
+
+		assert_eq!(iFrac64::from((1, 2)), f1);
+	}
+
+	#[test]
+	fn neg() {
+		let f1: iFrac64 = (2, 3).into();
+		let expected: iFrac64 = (-2, 3).into();
+
+		assert_eq!(expected, -f1);
+		assert_eq!(expected, -&f1);
+	}
+
+	#[test]
+	fn big_int() {
+		use orml_big_int::BigInt;
+
+		// sum of 1/k^2 for k in 1..=30 has a denominator far beyond i64
+		let mut sum = Fraction::<BigInt>::from_integer(BigInt::from(0));
+		for k in 1..=30 {
+			sum += Fraction::from((BigInt::from(1), BigInt::from(k * k)));
+		}
+		let expected: Fraction<BigInt> = "8745363341445960333910369/5424658191543895143840000"
+			.parse()
+			.unwrap();
+		assert_eq!(sum, expected);
+
+		let third: Fraction<BigInt> = "1/3".parse().unwrap();
+		assert_eq!(
+			&sum - &sum * third.clone() * BigInt::from(3),
+			Fraction::default()
+		);
+		assert!(third < sum);
+	}
+}
@@ -0,0 +1,350 @@
+//! This module contains overflow aware arithmetic for Fractions over fixed width integers
+//!
+//! The checked operations cross reduce by the GCD before multiplying so they only
+//! return None when the reduced result really can't be represented.
+//! The saturating and widening operations do their work in an i128 intermediate
+
+use std::ops::Neg;
+
+use num_traits::{CheckedAdd, CheckedMul, CheckedNeg, CheckedSub, Num};
+
+use super::{gcd, limit_parts, nonzero, Fraction};
+
+impl<T> Fraction<T>
+where
+	T: Clone
+		+ Num
+		+ PartialOrd
+		+ Neg<Output = T>
+		+ CheckedAdd
+		+ CheckedSub
+		+ CheckedMul
+		+ CheckedNeg,
+{
+	/// Checked Fraction addition. Computes self + rhs, returning None if overflow occurred
+	#[inline]
+	pub fn checked_add(&self, rhs: &Self) -> Option<Self> {
+		let g = gcd(self.denominator.clone(), rhs.denominator.clone());
+		let l = rhs.denominator.clone() / g.clone();
+		let r = self.denominator.clone() / g;
+		let n = self
+			.numerator
+			.checked_mul(&l)?
+			.checked_add(&rhs.numerator.checked_mul(&r)?)?;
+		Self::checked_reduce(n, self.denominator.checked_mul(&l)?)
+	}
+
+	/// Checked Fraction subtraction. Computes self - rhs, returning None if overflow occurred
+	#[inline]
+	pub fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+		let g = gcd(self.denominator.clone(), rhs.denominator.clone());
+		let l = rhs.denominator.clone() / g.clone();
+		let r = self.denominator.clone() / g;
+		let n = self
+			.numerator
+			.checked_mul(&l)?
+			.checked_sub(&rhs.numerator.checked_mul(&r)?)?;
+		Self::checked_reduce(n, self.denominator.checked_mul(&l)?)
+	}
+
+	/// Checked Fraction multiplication. Computes self * rhs, returning None if overflow occurred
+	#[inline]
+	pub fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+		// Both inputs are already reduced so cross reducing leaves a reduced result
+		let g1 = nonzero(gcd(self.numerator.clone(), rhs.denominator.clone()));
+		let g2 = nonzero(gcd(rhs.numerator.clone(), self.denominator.clone()));
+		let n1 = self.numerator.clone() / g1.clone();
+		let n2 = rhs.numerator.clone() / g2.clone();
+		let d1 = self.denominator.clone() / g2;
+		let d2 = rhs.denominator.clone() / g1;
+		Some(Self {
+			numerator: n1.checked_mul(&n2)?,
+			denominator: d1.checked_mul(&d2)?,
+		})
+	}
+
+	/// Checked Fraction division. Computes self / rhs, returning None if rhs is zero or overflow occurred
+	#[inline]
+	pub fn checked_div(&self, rhs: &Self) -> Option<Self> {
+		if rhs.numerator.is_zero() {
+			return None;
+		}
+		let recip = Self::checked_reduce(rhs.denominator.clone(), rhs.numerator.clone())?;
+		self.checked_mul(&recip)
+	}
+}
+
+/// Fixed width signed integers that can be promoted to an i128 without loss
+pub trait Widen: Sized + Copy {
+	/// The smallest value of the type
+	const MIN: Self;
+	/// The largest value of the type
+	const MAX: Self;
+	/// Promotes the value to an i128
+	fn widen(self) -> i128;
+	/// Narrows an i128 back to the type, returning None if it doesn't fit
+	fn narrow(value: i128) -> Option<Self>;
+}
+
+macro_rules! impl_widen {
+	($tt:ty) => {
+		impl Widen for $tt {
+			const MIN: Self = <$tt>::MIN;
+			const MAX: Self = <$tt>::MAX;
+
+			#[inline]
+			fn widen(self) -> i128 {
+				self as i128
+			}
+
+			#[inline]
+			fn narrow(value: i128) -> Option<Self> {
+				<$tt>::try_from(value).ok()
+			}
+		}
+	};
+}
+
+impl_widen!(i8);
+impl_widen!(i16);
+impl_widen!(i32);
+impl_widen!(i64);
+impl_widen!(isize);
+
+impl<T> Fraction<T>
+where
+	T: Widen,
+{
+	/// Returns the Fraction promoted to an i128 Fraction
+	#[inline]
+	pub fn widen(&self) -> Fraction<i128> {
+		Fraction {
+			numerator: self.numerator.widen(),
+			denominator: self.denominator.widen(),
+		}
+	}
+
+	/// Widening Fraction addition. Computes self + rhs exactly as an i128 Fraction
+	#[inline]
+	pub fn widening_add(&self, rhs: &Self) -> Fraction<i128> {
+		self.widen() + rhs.widen()
+	}
+
+	/// Widening Fraction subtraction. Computes self - rhs exactly as an i128 Fraction
+	#[inline]
+	pub fn widening_sub(&self, rhs: &Self) -> Fraction<i128> {
+		self.widen() - rhs.widen()
+	}
+
+	/// Widening Fraction multiplication. Computes self * rhs exactly as an i128 Fraction
+	#[inline]
+	pub fn widening_mul(&self, rhs: &Self) -> Fraction<i128> {
+		self.widen() * rhs.widen()
+	}
+
+	/// Widening Fraction division. Computes self / rhs exactly as an i128 Fraction
+	///
+	/// # Panics
+	/// Panics if rhs is zero
+	#[inline]
+	pub fn widening_div(&self, rhs: &Self) -> Fraction<i128> {
+		self.widen() / rhs.widen()
+	}
+
+	/// Saturating Fraction addition. Computes self + rhs, see [`Fraction::saturate`]
+	#[inline]
+	pub fn saturating_add(&self, rhs: &Self) -> Self {
+		Self::saturate(&self.widening_add(rhs))
+	}
+
+	/// Saturating Fraction subtraction. Computes self - rhs, see [`Fraction::saturate`]
+	#[inline]
+	pub fn saturating_sub(&self, rhs: &Self) -> Self {
+		Self::saturate(&self.widening_sub(rhs))
+	}
+
+	/// Saturating Fraction multiplication. Computes self * rhs, see [`Fraction::saturate`]
+	#[inline]
+	pub fn saturating_mul(&self, rhs: &Self) -> Self {
+		Self::saturate(&self.widening_mul(rhs))
+	}
+
+	/// Saturating Fraction division. Computes self / rhs, see [`Fraction::saturate`]
+	///
+	/// # Panics
+	/// Panics if rhs is zero
+	#[inline]
+	pub fn saturating_div(&self, rhs: &Self) -> Self {
+		Self::saturate(&self.widening_div(rhs))
+	}
+
+	/// Narrows an i128 Fraction back to T, returning None if either part doesn't fit
+	#[inline]
+	pub fn checked_narrow(value: &Fraction<i128>) -> Option<Self> {
+		Some(Self {
+			numerator: T::narrow(value.numerator)?,
+			denominator: T::narrow(value.denominator)?,
+		})
+	}
+
+	/// Narrows an i128 Fraction back to T
+	///
+	/// Values above T::MAX or below T::MIN are clamped to those bounds. Values in range
+	/// that don't fit are replaced by the closest Fraction whose parts fit in T
+	pub fn saturate(value: &Fraction<i128>) -> Self {
+		if let Some(f) = Self::checked_narrow(value) {
+			return f;
+		}
+
+		let (n, d) = (value.numerator, value.denominator);
+		let max = T::MAX.widen();
+		let min = T::MIN.widen();
+		if n >= max.saturating_mul(d) {
+			return Self {
+				numerator: T::MAX,
+				denominator: T::narrow(1).unwrap(),
+			};
+		}
+		if n <= min.saturating_mul(d) {
+			return Self {
+				numerator: T::MIN,
+				denominator: T::narrow(1).unwrap(),
+			};
+		}
+
+		let (p, q) = limit_parts(n.unsigned_abs(), d as u128, max as u128, max as u128);
+		let p = if n < 0 { -(p as i128) } else { p as i128 };
+		Self {
+			numerator: T::narrow(p).unwrap(),
+			denominator: T::narrow(q as i128).unwrap(),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	#![allow(non_camel_case_types)]
+
+	use crate::fraction::Fraction;
+	type iFrac64 = Fraction<i64>;
+	type iFrac8 = Fraction<i8>;
+
+	#[test]
+	fn checked_add() {
+		let f1: iFrac64 = (1, 6).into();
+		let f2: iFrac64 = (1, 3).into();
+		assert_eq!(f1.checked_add(&f2), Some((1, 2).into()));
+
+		let big: iFrac64 = (i64::MAX, 1).into();
+		assert_eq!(big.checked_add(&(1, 1).into()), None);
+
+		// The denominators share a factor so this doesn't overflow
+		let f1: iFrac64 = (1, i64::MAX).into();
+		assert_eq!(f1.checked_add(&f1), Some((2, i64::MAX).into()));
+	}
+
+	#[test]
+	fn checked_sub() {
+		let f1: iFrac64 = (1, 6).into();
+		let f2: iFrac64 = (1, 3).into();
+		assert_eq!(f1.checked_sub(&f2), Some((-1, 6).into()));
+
+		let small: iFrac64 = (i64::MIN, 1).into();
+		assert_eq!(small.checked_sub(&(1, 1).into()), None);
+	}
+
+	#[test]
+	fn checked_mul() {
+		let f1: iFrac64 = (i64::MAX, 3).into();
+		let f2: iFrac64 = (3, i64::MAX).into();
+		assert_eq!(f1.checked_mul(&f2), Some((1, 1).into()));
+
+		let f1: iFrac64 = (i64::MAX, 1).into();
+		assert_eq!(f1.checked_mul(&(2, 1).into()), None);
+	}
+
+	#[test]
+	fn checked_div() {
+		let f1: iFrac64 = (i64::MAX, 3).into();
+		assert_eq!(f1.checked_div(&f1), Some((1, 1).into()));
+		assert_eq!(f1.checked_div(&(0, 1).into()), None);
+
+		let f1: iFrac64 = (1, 1).into();
+		let f2: iFrac64 = (i64::MIN, 1).into();
+		assert_eq!(f1.checked_div(&f2), None);
+	}
+
+	#[test]
+	fn widening() {
+		let f1: iFrac64 = (i64::MAX, 1).into();
+		let f2: iFrac64 = (1, i64::MAX - 1).into();
+
+		assert_eq!(
+			f1.widening_add(&f1),
+			Fraction::<i128>::from((2 * i64::MAX as i128, 1))
+		);
+		assert_eq!(
+			f1.widening_mul(&f1),
+			Fraction::<i128>::from((i64::MAX as i128 * i64::MAX as i128, 1))
+		);
+		assert_eq!(
+			f2.widening_div(&f1),
+			Fraction::<i128>::from((1, (i64::MAX as i128 - 1) * i64::MAX as i128))
+		);
+		assert_eq!(f1.widening_sub(&f1), Fraction::<i128>::from((0, 1)));
+	}
+
+	#[test]
+	fn saturating() {
+		let max: iFrac8 = (i8::MAX, 1).into();
+		let min: iFrac8 = (i8::MIN, 1).into();
+		assert_eq!(max.saturating_add(&max), max);
+		assert_eq!(min.saturating_add(&min), min);
+		assert_eq!(min.saturating_mul(&(-1, 1).into()), max);
+		assert_eq!(max.saturating_sub(&(1, 3).into()), max);
+
+		// 1/100 * 1/100 is in range but 1/10000 can't be represented with i8
+		let small: iFrac8 = (1, 100).into();
+		assert_eq!(small.saturating_mul(&small), (0, 1).into());
+
+		// 101/100 * 101/100 = 10201/10000, closest is 1 + 1/49 or 1 + 1/50 within 127
+		let f: iFrac8 = (101, 100).into();
+		let r = f.saturating_mul(&f);
+		let exact = 10201.0 / 10000.0;
+		let got = *r.numerator() as f64 / *r.denominator() as f64;
+		for q in 1..=127i32 {
+			for p in -128..=127i32 {
+				assert!((p as f64 / q as f64 - exact).abs() >= (got - exact).abs() - 1e-12);
+			}
+		}
+	}
+
+	#[test]
+	fn test_mul() {
+		let num = 300;
+
+		for n in -num..num {
+			for d in -num..num {
+				if n == 0 || d == 0 {
+					continue;
+				}
+				let frac1: iFrac64 = (n, d).into();
+				let frac2: iFrac64 = (d, n).into();
+				let frac3 = frac1.checked_mul(&frac2);
+
+				assert_eq!(frac3, Some((n * d, n * d).into()));
+			}
+		}
+
+		let big = 3037000499;
+		for n in big - num..big + num {
+			let frac1: iFrac64 = (n, 7).into();
+			let frac2: iFrac64 = (7, n).into();
+			assert_eq!(frac1.checked_mul(&frac2), Some((1, 1).into()));
+			assert_eq!(
+				frac1.checked_mul(&frac1),
+				iFrac64::checked_narrow(&frac1.widening_mul(&frac1))
+			);
+		}
+	}
+}
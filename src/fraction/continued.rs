@@ -0,0 +1,250 @@
+//! This module contains the simple continued fraction expansion of Fractions
+//!
+//! A Fraction n/d is expanded as [a0; a1, a2, ...] = a0 + 1/(a1 + 1/(a2 + ...)) where a0 is
+//! the floor of n/d and every later term is positive
+
+use std::ops::Neg;
+
+use num_traits::{CheckedNeg, Num};
+
+use super::{Fraction, FractionError};
+
+/// An iterator over the terms of the simple continued fraction of a Fraction
+///
+/// Created by [`Fraction::continued_fraction`]
+#[derive(Debug, Clone)]
+pub struct ContinuedFraction<T> {
+	numerator: T,
+	denominator: T,
+}
+
+impl<T> Iterator for ContinuedFraction<T>
+where
+	T: Clone + Num + PartialOrd + Neg<Output = T>,
+{
+	type Item = T;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.denominator.is_zero() {
+			return None;
+		}
+		// Floor division so the remainder is always non-negative
+		let mut a = self.numerator.clone() / self.denominator.clone();
+		let mut r = self.numerator.clone() - a.clone() * self.denominator.clone();
+		if r < T::zero() {
+			a = a - T::one();
+			r = r + self.denominator.clone();
+		}
+		self.numerator = std::mem::replace(&mut self.denominator, r);
+		Some(a)
+	}
+}
+
+/// An iterator over the convergents of a continued fraction
+///
+/// Created by [`Fraction::convergents`] or [`Convergents::new`]
+#[derive(Debug, Clone)]
+pub struct Convergents<I, T> {
+	terms: I,
+	// (h_{n-1}, k_{n-1}) and (h_{n-2}, k_{n-2}) of the recurrence h_n = a_n h_{n-1} + h_{n-2}
+	prev: (T, T),
+	prev2: (T, T),
+	// Set once a term was not positive, after which there are no more convergents
+	done: bool,
+}
+
+impl<I, T> Convergents<I, T>
+where
+	I: Iterator<Item = T>,
+	T: Clone + Num + PartialOrd + Neg<Output = T>,
+{
+	/// Creates an iterator over the convergents of the continued fraction with the given terms
+	///
+	/// Every term after the first must be positive, as in a simple continued fraction. The
+	/// iterator ends at the first one that isn't, since its convergent could have a zero
+	/// denominator
+	#[inline]
+	pub fn new<J>(terms: J) -> Self
+	where
+		J: IntoIterator<IntoIter = I>,
+	{
+		Self {
+			terms: terms.into_iter(),
+			prev: (T::one(), T::zero()),
+			prev2: (T::zero(), T::one()),
+			done: false,
+		}
+	}
+}
+
+impl<I, T> Iterator for Convergents<I, T>
+where
+	I: Iterator<Item = T>,
+	T: Clone + Num + PartialOrd + Neg<Output = T>,
+{
+	type Item = Fraction<T>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.done {
+			return None;
+		}
+		let a = self.terms.next()?;
+		// Only a0 has k_{n-1} = 0 and may be zero or negative
+		if !self.prev.1.is_zero() && a <= T::zero() {
+			self.done = true;
+			return None;
+		}
+		let h = a.clone() * self.prev.0.clone() + self.prev2.0.clone();
+		let k = a * self.prev.1.clone() + self.prev2.1.clone();
+		self.prev2 = std::mem::replace(&mut self.prev, (h.clone(), k.clone()));
+		// With positive terms k is positive and the convergents are already in lowest terms
+		Some(Fraction::new_unchecked(h, k))
+	}
+}
+
+impl<T> Fraction<T>
+where
+	T: Clone + Num + PartialOrd + Neg<Output = T>,
+{
+	/// Returns an iterator over the terms [a0; a1, a2, ...] of the simple continued fraction
+	#[inline]
+	pub fn continued_fraction(&self) -> ContinuedFraction<T> {
+		ContinuedFraction {
+			numerator: self.numerator.clone(),
+			denominator: self.denominator.clone(),
+		}
+	}
+
+	/// Returns an iterator over the successive convergents of the simple continued fraction,
+	/// the last of which is the Fraction itself
+	#[inline]
+	pub fn convergents(&self) -> Convergents<ContinuedFraction<T>, T> {
+		Convergents::new(self.continued_fraction())
+	}
+}
+
+impl<T> Fraction<T>
+where
+	T: Clone + Num + PartialOrd + Neg<Output = T> + CheckedNeg,
+{
+	/// Creates a Fraction from the terms [a0; a1, a2, ...] of a continued fraction
+	///
+	/// Returns [`FractionError::ZeroDenominator`] if the terms are empty or evaluate to
+	/// a zero denominator
+	pub fn from_continued_fraction(terms: &[T]) -> Result<Self, FractionError> {
+		let mut h = (T::one(), T::zero());
+		let mut k = (T::zero(), T::one());
+		for a in terms {
+			h = (a.clone() * h.0.clone() + h.1, h.0);
+			k = (a.clone() * k.0.clone() + k.1, k.0);
+		}
+		Self::new(h.0, k.0)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	#![allow(non_camel_case_types)]
+
+	use super::Convergents;
+	use crate::fraction::{Fraction, FractionError};
+	type iFrac64 = Fraction<i64>;
+
+	#[test]
+	fn continued_fraction() {
+		let f: iFrac64 = (415, 93).into();
+		assert_eq!(f.continued_fraction().collect::<Vec<_>>(), [4, 2, 6, 7]);
+
+		let f: iFrac64 = (-7, 3).into();
+		assert_eq!(f.continued_fraction().collect::<Vec<_>>(), [-3, 1, 2]);
+
+		let f: iFrac64 = (5, 1).into();
+		assert_eq!(f.continued_fraction().collect::<Vec<_>>(), [5]);
+
+		let f: iFrac64 = (0, 1).into();
+		assert_eq!(f.continued_fraction().collect::<Vec<_>>(), [0]);
+	}
+
+	#[test]
+	fn convergents() {
+		let f: iFrac64 = (103993, 33102).into();
+		let c: Vec<iFrac64> = f.convergents().collect();
+		let expected: [iFrac64; 5] = [
+			(3, 1).into(),
+			(22, 7).into(),
+			(333, 106).into(),
+			(355, 113).into(),
+			(103993, 33102).into(),
+		];
+		assert_eq!(c, expected);
+
+		// Convergents alternate either side of the value and get closer each time
+		for (i, w) in c.windows(2).enumerate() {
+			assert_eq!(w[0] < f, i % 2 == 0);
+			assert!((w[1] - f).abs() < (w[0] - f).abs());
+		}
+	}
+
+	#[test]
+	fn convergents_from_terms() {
+		// The golden ratio [1; 1, 1, ...] has ratios of Fibonacci numbers as convergents
+		let c: Vec<iFrac64> = Convergents::new(std::iter::repeat_n(1i64, 6)).collect();
+		let expected: [iFrac64; 6] = [
+			(1, 1).into(),
+			(2, 1).into(),
+			(3, 2).into(),
+			(5, 3).into(),
+			(8, 5).into(),
+			(13, 8).into(),
+		];
+		assert_eq!(c, expected);
+	}
+
+	#[test]
+	fn convergents_invalid_terms() {
+		// [1; 0] would be 1/0, so the iterator ends before it
+		let c: Vec<iFrac64> = Convergents::new([1i64, 0]).collect();
+		assert_eq!(c, [(1, 1).into()]);
+		let mut c = Convergents::new([2i64, 3, -1, 4]);
+		assert_eq!(c.next(), Some((2, 1).into()));
+		assert_eq!(c.next(), Some((7, 3).into()));
+		assert_eq!(c.next(), None);
+		assert_eq!(c.next(), None);
+		// Only the first term may be zero or negative
+		let c: Vec<iFrac64> = Convergents::new([0i64, 2]).collect();
+		assert_eq!(c, [(0, 1).into(), (1, 2).into()]);
+	}
+
+	#[test]
+	fn from_continued_fraction() {
+		assert_eq!(
+			iFrac64::from_continued_fraction(&[4, 2, 6, 7]),
+			Ok((415, 93).into())
+		);
+		assert_eq!(
+			iFrac64::from_continued_fraction(&[-3, 1, 2]),
+			Ok((-7, 3).into())
+		);
+		assert_eq!(
+			iFrac64::from_continued_fraction(&[]),
+			Err(FractionError::ZeroDenominator)
+		);
+		assert_eq!(
+			iFrac64::from_continued_fraction(&[1, 0]),
+			Err(FractionError::ZeroDenominator)
+		);
+	}
+
+	#[test]
+	fn round_trip() {
+		for n in -100..100 {
+			for d in 1..50 {
+				let f: iFrac64 = (n, d).into();
+				let terms: Vec<i64> = f.continued_fraction().collect();
+				assert_eq!(iFrac64::from_continued_fraction(&terms), Ok(f));
+				assert_eq!(f.convergents().last(), Some(f));
+				assert!(terms[1..].iter().all(|&a| a > 0));
+			}
+		}
+	}
+}
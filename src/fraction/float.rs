@@ -0,0 +1,317 @@
+//! This module contains conversions between Fractions and floating point numbers
+//!
+//! Both directions are exact where possible: [`Fraction::to_f64`] rounds the true
+//! quotient to nearest, ties to even, and [`Fraction::from_f64`] decomposes the
+//! binary mantissa and exponent without any rounding at all
+
+use super::{checked::Widen, limit_parts, Fraction, FractionError};
+
+const MANTISSA_BITS: u32 = 52;
+const EXPONENT_BIAS: i32 = 1023;
+
+/// Returns 2^exp for exp in the normal range of f64
+#[inline]
+fn pow2(exp: i32) -> f64 {
+	f64::from_bits(((exp + EXPONENT_BIAS) as u64) << MANTISSA_BITS)
+}
+
+#[inline]
+fn bit_len(x: u128) -> u32 {
+	128 - x.leading_zeros()
+}
+
+/// Returns n/d correctly rounded to the nearest f64, d must be non zero
+fn div_to_f64(n: u128, d: u128) -> f64 {
+	if n == 0 {
+		return 0.0;
+	}
+
+	// Build a 54 bit quotient (53 bits of mantissa and a guard bit) with a sticky bit
+	// for everything below it. n/d = (q + r/d) * 2^exp
+	let mut q = n / d;
+	let mut r = n % d;
+	let mut exp = 0;
+	let mut sticky = false;
+	if bit_len(q) > MANTISSA_BITS + 2 {
+		let extra = bit_len(q) - (MANTISSA_BITS + 2);
+		sticky = q & ((1 << extra) - 1) != 0;
+		q >>= extra;
+		exp += extra as i32;
+	}
+	while bit_len(q) < MANTISSA_BITS + 2 {
+		// r < d <= 2^127 so this can't overflow
+		r <<= 1;
+		q <<= 1;
+		if r >= d {
+			r -= d;
+			q |= 1;
+		}
+		exp -= 1;
+	}
+	sticky |= r != 0;
+
+	let guard = q & 1 == 1;
+	let mut m = q >> 1;
+	exp += 1;
+	if guard && (sticky || m & 1 == 1) {
+		m += 1;
+		if bit_len(m) > MANTISSA_BITS + 1 {
+			m >>= 1;
+			exp += 1;
+		}
+	}
+
+	// m < 2^53 so the conversion is exact, as is the power of two scaling
+	m as f64 * pow2(exp)
+}
+
+/// Returns (m, e) such that x = m * 2^e with m odd, or (0, 0) if x is zero
+#[inline]
+fn decompose(x: f64) -> (u64, i32) {
+	let bits = x.to_bits();
+	let biased = ((bits >> MANTISSA_BITS) & 0x7ff) as i32;
+	let fraction = bits & ((1 << MANTISSA_BITS) - 1);
+	let (m, e) = if biased == 0 {
+		// Subnormal
+		(fraction, 1 - EXPONENT_BIAS - MANTISSA_BITS as i32)
+	} else {
+		(
+			fraction | (1 << MANTISSA_BITS),
+			biased - EXPONENT_BIAS - MANTISSA_BITS as i32,
+		)
+	};
+	if m == 0 {
+		return (0, 0);
+	}
+	let tz = m.trailing_zeros();
+	(m >> tz, e + tz as i32)
+}
+
+impl<T> Fraction<T>
+where
+	T: Widen,
+{
+	/// Returns the Fraction as the nearest f64, rounding ties to even
+	#[inline]
+	pub fn to_f64(&self) -> f64 {
+		let n = self.numerator.widen();
+		let d = self.denominator.widen();
+		let f = div_to_f64(n.unsigned_abs(), d.unsigned_abs());
+		if (n < 0) != (d < 0) {
+			-f
+		} else {
+			f
+		}
+	}
+
+	/// Creates a Fraction with exactly the same value as x
+	///
+	/// Returns [`FractionError::NotFinite`] for NaN or infinite x and
+	/// [`FractionError::Overflow`] if the exact value doesn't fit in T
+	pub fn from_f64(x: f64) -> Result<Self, FractionError> {
+		if !x.is_finite() {
+			return Err(FractionError::NotFinite);
+		}
+		let (m, e) = decompose(x);
+		let m = if x < 0.0 { -(m as i128) } else { m as i128 };
+		let (n, d) = if e >= 0 {
+			let n = (e < 127).then(|| 1i128 << e).and_then(|p| p.checked_mul(m));
+			(n, Some(1))
+		} else {
+			let d = (-e < 127).then(|| 1i128 << -e);
+			(Some(m), d)
+		};
+		match (n.and_then(T::narrow), d.and_then(T::narrow)) {
+			(Some(numerator), Some(denominator)) => Ok(Self {
+				numerator,
+				denominator,
+			}),
+			_ => Err(FractionError::Overflow),
+		}
+	}
+
+	/// Returns the closest Fraction to x with a denominator no greater than max_denominator
+	///
+	/// Uses the continued fraction expansion of x, choosing between the last convergent
+	/// and the best semiconvergent that fit, so the result is a best rational approximation.
+	/// Returns [`FractionError::NotFinite`] for NaN or infinite x,
+	/// [`FractionError::ZeroDenominator`] if max_denominator is less than 1 and
+	/// [`FractionError::Overflow`] if x is outside the range of T
+	pub fn approximate(x: f64, max_denominator: T) -> Result<Self, FractionError> {
+		if !x.is_finite() {
+			return Err(FractionError::NotFinite);
+		}
+		let max_d = max_denominator.widen();
+		if max_d < 1 {
+			return Err(FractionError::ZeroDenominator);
+		}
+		let max_n = T::MAX.widen();
+		let one = T::narrow(1).unwrap();
+		let zero = T::narrow(0).unwrap();
+		if x.abs() > max_n as f64 {
+			return Err(FractionError::Overflow);
+		}
+		// Anything closer to 0 than to 1/max_denominator rounds to 0
+		if x.abs() * 2.0 * (max_d as f64) < 1.0 {
+			return Ok(Self {
+				numerator: zero,
+				denominator: one,
+			});
+		}
+
+		// x is now within [2^-64, 2^63] so its exact value m/2^-e fits in a u128
+		let (m, e) = decompose(x.abs());
+		let (n, d) = if e >= 0 {
+			((m as u128) << e, 1)
+		} else {
+			(m as u128, 1u128 << -e)
+		};
+		let (p, q) = limit_parts(n, d, max_n as u128, max_d as u128);
+		let p = if x < 0.0 { -(p as i128) } else { p as i128 };
+		Ok(Self {
+			numerator: T::narrow(p).unwrap(),
+			denominator: T::narrow(q as i128).unwrap(),
+		})
+	}
+}
+
+impl<T> TryFrom<f64> for Fraction<T>
+where
+	T: Widen,
+{
+	type Error = FractionError;
+
+	/// See [`Fraction::from_f64`]
+	#[inline]
+	fn try_from(value: f64) -> Result<Self, Self::Error> {
+		Self::from_f64(value)
+	}
+}
+
+macro_rules! impl_into_f64 {
+	($tt:ty) => {
+		impl From<Fraction<$tt>> for f64 {
+			/// See [`Fraction::to_f64`]
+			#[inline]
+			fn from(value: Fraction<$tt>) -> Self {
+				value.to_f64()
+			}
+		}
+	};
+}
+
+impl_into_f64!(i8);
+impl_into_f64!(i16);
+impl_into_f64!(i32);
+impl_into_f64!(i64);
+impl_into_f64!(isize);
+
+#[cfg(test)]
+mod tests {
+	#![allow(non_camel_case_types)]
+
+	use crate::fraction::{Fraction, FractionError};
+	type iFrac64 = Fraction<i64>;
+	type iFrac32 = Fraction<i32>;
+
+	#[test]
+	fn to_f64() {
+		// Both parts are exact in f64 here so IEEE division is correctly rounded
+		for n in -200..200 {
+			for d in 1..200 {
+				let f: iFrac64 = (n, d).into();
+				assert_eq!(f.to_f64(), n as f64 / d as f64, "{}/{}", n, d);
+			}
+		}
+
+		let f: iFrac64 = (i64::MAX, 1).into();
+		assert_eq!(f.to_f64(), 9223372036854775808.0);
+
+		// Exactly half way between 2^53 and 2^53 + 2, ties to even
+		let f: iFrac64 = ((1 << 53) + 1, 1).into();
+		assert_eq!(f.to_f64(), 9007199254740992.0);
+		let f: iFrac64 = ((1 << 53) + 3, 1).into();
+		assert_eq!(f.to_f64(), 9007199254740996.0);
+
+		// Rounding the numerator first then dividing gets this one wrong
+		let f: iFrac64 = (5267993464630784501, 870).into();
+		assert_eq!(f.to_f64(), 6055164901874465.0);
+		assert_eq!(5267993464630784501.0 / 870.0, 6055164901874464.0);
+
+		let f: iFrac64 = (1, i64::MAX).into();
+		assert_eq!(f.to_f64(), 1.0 / 9223372036854775808.0);
+		assert_eq!(f64::from(f), f.to_f64());
+	}
+
+	#[test]
+	fn from_f64() {
+		assert_eq!(iFrac64::from_f64(0.75), Ok((3, 4).into()));
+		assert_eq!(iFrac64::from_f64(-2.5), Ok((-5, 2).into()));
+		assert_eq!(iFrac64::from_f64(0.0), Ok((0, 1).into()));
+		assert_eq!(iFrac64::from_f64(-0.0), Ok((0, 1).into()));
+		assert_eq!(
+			iFrac64::from_f64(0.1),
+			Ok((3602879701896397, 36028797018963968).into())
+		);
+		assert_eq!(iFrac64::from_f64(1e19), Err(FractionError::Overflow));
+		assert_eq!(iFrac64::from_f64(1e-30), Err(FractionError::Overflow));
+		assert_eq!(iFrac64::from_f64(f64::NAN), Err(FractionError::NotFinite));
+		assert_eq!(
+			iFrac64::try_from(f64::NEG_INFINITY),
+			Err(FractionError::NotFinite)
+		);
+		assert_eq!(iFrac32::from_f64(0.1), Err(FractionError::Overflow));
+	}
+
+	#[test]
+	fn round_trip() {
+		let mut x = 1.0e-2f64;
+		while x < 1.0e15 {
+			for v in [x, -x] {
+				let f = iFrac64::from_f64(v).unwrap();
+				assert_eq!(f.to_f64(), v);
+			}
+			x *= 1.37;
+		}
+	}
+
+	#[test]
+	fn approximate() {
+		let pi = std::f64::consts::PI;
+		assert_eq!(iFrac64::approximate(pi, 10), Ok((22, 7).into()));
+		assert_eq!(iFrac64::approximate(pi, 1000), Ok((355, 113).into()));
+		assert_eq!(iFrac64::approximate(-pi, 1000), Ok((-355, 113).into()));
+		assert_eq!(
+			iFrac64::approximate(std::f64::consts::E, 100),
+			Ok((193, 71).into())
+		);
+		assert_eq!(iFrac64::approximate(1.0 / 3.0, 10), Ok((1, 3).into()));
+		assert_eq!(iFrac64::approximate(0.001, 100), Ok((0, 1).into()));
+		assert_eq!(iFrac64::approximate(0.006, 100), Ok((1, 100).into()));
+		assert_eq!(
+			iFrac64::approximate(pi, 0),
+			Err(FractionError::ZeroDenominator)
+		);
+		assert_eq!(iFrac64::approximate(1e30, 10), Err(FractionError::Overflow));
+		assert_eq!(
+			iFrac64::approximate(f64::NAN, 10),
+			Err(FractionError::NotFinite)
+		);
+
+		// An exact binary value comes back exactly with a large enough bound
+		assert_eq!(iFrac64::approximate(0.375, i64::MAX), Ok((3, 8).into()));
+	}
+
+	#[test]
+	fn approximate_is_best() {
+		let x = std::f64::consts::FRAC_1_SQRT_2;
+		let max = 50;
+		let f = iFrac32::approximate(x, max).unwrap();
+		let err = (f.to_f64() - x).abs();
+		for q in 1..=max {
+			for p in 0..=q {
+				assert!((p as f64 / q as f64 - x).abs() >= err);
+			}
+		}
+	}
+}
@@ -0,0 +1,145 @@
+//! This module containts implementations for ordering Fractions
+//! by comparing their continued fraction expansions
+
+use std::cmp::Ordering;
+
+use num_traits::Num;
+
+use super::Fraction;
+
+/// Splits n/d into its floor and a remainder in 0..d, for a positive d
+#[inline]
+fn floor_div_rem<T: Clone + Num + PartialOrd>(n: T, d: T) -> (T, T) {
+	let (q, r) = (n.clone() / d.clone(), n % d.clone());
+	if r < T::zero() {
+		(q - T::one(), r + d)
+	} else {
+		(q, r)
+	}
+}
+
+/// Compares n1/d1 with n2/d2 one continued fraction term at a time
+///
+/// Unlike cross multiplication this never overflows fixed width integers
+fn cmp_parts<T>(mut n1: T, mut d1: T, mut n2: T, mut d2: T) -> Option<Ordering>
+where
+	T: Clone + Num + PartialOrd,
+{
+	// Each step takes reciprocals of the remainders which reverses the ordering
+	let mut reversed = false;
+	loop {
+		let (q1, r1) = floor_div_rem(n1, d1.clone());
+		let (q2, r2) = floor_div_rem(n2, d2.clone());
+		let ordering = match q1.partial_cmp(&q2)? {
+			Ordering::Equal => match (r1.is_zero(), r2.is_zero()) {
+				(true, true) => Ordering::Equal,
+				(true, false) => Ordering::Less,
+				(false, true) => Ordering::Greater,
+				(false, false) => {
+					(n1, d1, n2, d2) = (d1, r1, d2, r2);
+					reversed = !reversed;
+					continue;
+				}
+			},
+			ordering => ordering,
+		};
+		return Some(if reversed {
+			ordering.reverse()
+		} else {
+			ordering
+		});
+	}
+}
+
+impl<T> PartialOrd for Fraction<T>
+where
+	T: Clone + Num + PartialOrd,
+{
+	#[inline]
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		cmp_parts(
+			self.numerator.clone(),
+			self.denominator.clone(),
+			other.numerator.clone(),
+			other.denominator.clone(),
+		)
+	}
+}
+
+impl<T> Ord for Fraction<T>
+where
+	T: Clone + Num + Ord,
+{
+	#[inline]
+	fn cmp(&self, other: &Self) -> Ordering {
+		self.partial_cmp(other)
+			.expect("Ord types are totally ordered")
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	#![allow(non_camel_case_types)]
+
+	use crate::fraction::Fraction;
+	type iFrac64 = Fraction<i64>;
+
+	#[test]
+	fn partial_eq() {
+		let f1: iFrac64 = (2, 4).into();
+		let f2: iFrac64 = (-1, -2).into();
+		let f3: iFrac64 = (1, 3).into();
+
+		assert_eq!(f1, f2);
+		assert_ne!(f1, f3);
+	}
+
+	#[test]
+	fn partial_ord() {
+		let combos: [iFrac64; 6] = [
+			(-3, 2).into(),
+			(-1, 3).into(),
+			(0, 1).into(),
+			(1, 3).into(),
+			(1, 2).into(),
+			(7, 3).into(),
+		];
+
+		for i in 0..combos.len() {
+			for j in 0..combos.len() {
+				assert_eq!(
+					combos[i] < combos[j],
+					i < j,
+					"\n\ti: {:?} < j: {:?}",
+					combos[i],
+					combos[j]
+				);
+				assert_eq!(
+					combos[i] >= combos[j],
+					i >= j,
+					"\n\ti: {:?} >= j: {:?}",
+					combos[i],
+					combos[j]
+				);
+				assert_eq!(combos[i].cmp(&combos[j]), i.cmp(&j));
+			}
+		}
+	}
+
+	#[test]
+	fn extremes() {
+		let max: iFrac64 = (i64::MAX, 1).into();
+		let min: iFrac64 = (i64::MIN, 1).into();
+		let half: iFrac64 = (1, 2).into();
+		assert!(half < max);
+		assert!(min < half);
+		assert!(min < max);
+
+		// Neighbours that differ by 1/(MAX * (MAX - 1))
+		let a: iFrac64 = (i64::MAX - 2, i64::MAX - 1).into();
+		let b: iFrac64 = (i64::MAX - 1, i64::MAX).into();
+		assert!(a < b);
+		assert_eq!((-b).cmp(&-a), std::cmp::Ordering::Less);
+		assert_eq!(b.cmp(&b), std::cmp::Ordering::Equal);
+	}
+}
@@ -7,12 +7,16 @@
 //! - fractions
 //! - vectors
 //! - quaternions
+//! - matrices
 //! - easy big floats
+//! - big integers
 //! - and many more to come
 
 //#[cfg(feature = "vector")]
 pub use orml_vector as vector;
 
+pub use orml_matrix as matrix;
+
 //#[cfg(feature = "traits")]
 pub use orml_traits as traits;
 
@@ -22,3 +26,9 @@ pub mod fraction;
 pub use orml_quaternion as quaternion;
 
 pub use orml_easy_big_float as easy_big_float;
+
+pub use orml_big_int as big_int;
+
+pub use orml_derive as derive;
+
+pub use orml_double_double as double_double;